@@ -0,0 +1,158 @@
+//! The known-correct answers, kept next to the inputs as
+//! `<data root>/<year>/answers.toml`:
+//!
+//! ```toml
+//! [day01.example]
+//! part1 = 24000
+//! part2 = 45000
+//!
+//! [day10.final]
+//! part1 = 15880
+//! part2 = "PLGFKAZG"
+//! ```
+//!
+//! Answers compare after trimming surrounding whitespace.  An input with
+//! `skip = "<reason>"` in place of its answers is left out of `verify` and
+//! `bench`.
+
+use std::{collections::BTreeMap, convert::Infallible, fmt::Display, path::Path, str::FromStr};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::Part;
+
+/// One recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    pub fn matches(&self, actual: &str) -> bool {
+        self.to_string().trim() == actual.trim()
+    }
+
+    /// The answer as a TOML value, for writing into an answers file.
+    pub fn to_toml(&self) -> String {
+        match self {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => toml::Value::String(s.clone()).to_string(),
+        }
+    }
+}
+
+/// A number if it looks like one, otherwise text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        Ok(match s.parse() {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// What one day should produce for one input.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    /// Why this input isn't run when verifying (a solution too slow or too
+    /// memory hungry to check routinely).
+    pub skip: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::Part1 => self.part1.as_ref(),
+            Part::Part2 => self.part2.as_ref(),
+        }
+    }
+}
+
+/// A year's answers file: day (`day01`) to input name (`final`) to answers.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Expected>>,
+}
+
+impl Answers {
+    /// Reads an answers file.  A missing file is the same as an empty one.
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("Failed to parse answers {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, input: &str) -> Option<&Expected> {
+        self.days.get(&format!("day{day:02}"))?.get(input)
+    }
+
+    /// The names of the inputs with recorded answers for `day`.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.days
+            .get(&format!("day{day:02}"))
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let answers: Answers = toml::from_str(
+            r#"
+            [day01.example]
+            part1 = 24000
+            part2 = "MCD"
+
+            [day10.final]
+            part1 = 15880
+            part2 = "PLGFKAZG"
+
+            [day05.stress]
+            skip = "too slow"
+            "#,
+        )?;
+        let example = answers.get(1, "example").unwrap();
+        assert!(example.part(Part::Part1).unwrap().matches("24000"));
+        assert!(example.part(Part::Part2).unwrap().matches("MCD"));
+        assert!(!example.part(Part::Part2).unwrap().matches("MZD"));
+        assert!(answers.get(1, "final").is_none());
+        let day10 = answers.get(10, "final").unwrap();
+        assert!(day10.part(Part::Part2).unwrap().matches("PLGFKAZG\n"));
+        assert!(day10.skip.is_none());
+        assert!(answers.get(5, "stress").unwrap().skip.is_some());
+        assert_eq!(answers.inputs(1).collect::<Vec<_>>(), ["example"]);
+        Ok(())
+    }
+    #[test]
+    fn test_from_str_and_to_toml() {
+        assert_eq!("-12".parse(), Ok(Answer::Number(-12)));
+        assert_eq!("MCD".parse(), Ok(Answer::Text("MCD".to_string())));
+        assert_eq!(Answer::Number(7).to_toml(), "7");
+        assert_eq!(Answer::Text("a\"b".to_string()).to_toml(), r#"'a"b'"#);
+    }
+}
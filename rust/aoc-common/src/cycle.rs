@@ -0,0 +1,168 @@
+//! Finding where a long-running simulation starts repeating itself, so the
+//! state (or a running total) at step one-billion can be read off a few
+//! hundred steps.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// A sequence of states `s0, s1, ...` that, from step `start` on, repeats
+/// every `period` steps: `s[n] == s[n + period]` for every `n >= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// How many steps come before the first repeating state.
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    pub const fn new(start: usize, period: usize) -> Cycle {
+        Cycle { start, period }
+    }
+
+    /// The earliest step whose state is the same as step `n`'s.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// The value at step `n` of a quantity that follows the states but may
+    /// grow by the same amount every period (a height, a running count).
+    /// `values` must cover at least one whole period, i.e. steps `0` to
+    /// `start + period` inclusive.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> i64 {
+        assert!(
+            values.len() > self.start + self.period,
+            "need {} values to extrapolate, got {}",
+            self.start + self.period + 1,
+            values.len()
+        );
+        if n < values.len() {
+            return values[n];
+        }
+        let per_period = values[self.start + self.period] - values[self.start];
+        let step = self.equivalent_step(n);
+        let periods = ((n - step) / self.period) as i64;
+        values[step] + periods * per_period
+    }
+}
+
+/// Runs `items` until one repeats, returning the cycle and every item up to
+/// and including the first repeat.  Returns `None` if the iterator ends
+/// first.
+pub fn find_cycle<T>(items: impl IntoIterator<Item = T>) -> Option<(Cycle, Vec<T>)>
+where
+    T: Clone + Eq + Hash,
+{
+    find_cycle_by_key(items, T::clone)
+}
+
+/// [`find_cycle`], comparing items by `key`.  The key needs to capture
+/// everything that determines the following items; anything else the items
+/// carry (a step counter, a running total) can vary between repeats.
+pub fn find_cycle_by_key<T, K>(
+    items: impl IntoIterator<Item = T>,
+    mut key: impl FnMut(&T) -> K,
+) -> Option<(Cycle, Vec<T>)>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    for (step, item) in items.into_iter().enumerate() {
+        let first = *seen.entry(key(&item)).or_insert(step);
+        history.push(item);
+        if first != step {
+            return Some((Cycle::new(first, step - first), history));
+        }
+    }
+    None
+}
+
+/// Extended Euclid: `(g, x)` with `g = gcd(a, b)` and `a * x ≡ g (mod b)`.
+fn gcd_inverse(a: i128, b: i128) -> (i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    (old_r, old_s)
+}
+
+/// The first step at which every one of `cycles` is back in the same phase
+/// as its start, no earlier than any of those starts: the least `n` with
+/// `n >= start` and `(n - start) % period == 0` for every cycle.  `None` if
+/// the phases never line up.
+///
+/// For cycles that start at their own period (an event that happens every
+/// `period` steps from step `period`), this is the lowest common multiple.
+pub fn coincide(cycles: &[Cycle]) -> Option<usize> {
+    let (mut residue, mut modulus) = (0_i128, 1_i128);
+    for cycle in cycles {
+        let (a, m) = (cycle.start as i128, cycle.period as i128);
+        let (g, inverse) = gcd_inverse(modulus, m);
+        if (a - residue) % g != 0 {
+            return None;
+        }
+        let m_g = m / g;
+        let k = ((a - residue) / g % m_g * inverse % m_g).rem_euclid(m_g);
+        residue += modulus * k;
+        modulus *= m_g;
+        residue = residue.rem_euclid(modulus);
+    }
+    let latest_start = cycles.iter().map(|cycle| cycle.start).max().unwrap_or(0) as i128;
+    if residue < latest_start {
+        residue += (latest_start - residue + modulus - 1) / modulus * modulus;
+    }
+    residue.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 3, 10, 5, 16, 8, 4, 2, 1, 4, ...
+        let collatz = std::iter::successors(Some(3_u64), |&n| {
+            Some(if n % 2 == 0 { n / 2 } else { 3 * n + 1 })
+        });
+        let (cycle, history) = find_cycle(collatz).unwrap();
+        assert_eq!(cycle, Cycle::new(5, 3));
+        assert_eq!(history, [3, 10, 5, 16, 8, 4, 2, 1, 4]);
+        assert_eq!(cycle.equivalent_step(4), 4);
+        assert_eq!(cycle.equivalent_step(1_000_000), 7);
+        assert_eq!(find_cycle(0..10), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A walk A -> B -> C -> B -> C ..., scoring 1 for each B and 2 for
+        // each C; the key ignores the running total.
+        let items = std::iter::successors(Some(('A', 0)), |&(state, total)| {
+            Some(match state {
+                'A' | 'C' => ('B', total + 1),
+                _ => ('C', total + 2),
+            })
+        });
+        let (cycle, history) = find_cycle_by_key(items, |&(state, _)| state).unwrap();
+        assert_eq!(cycle, Cycle::new(1, 2));
+        let totals: Vec<i64> = history.iter().map(|&(_, total)| total).collect();
+        assert_eq!(totals, [0, 1, 3, 4]);
+        assert_eq!(cycle.extrapolate(&totals, 2), 3);
+        assert_eq!(cycle.extrapolate(&totals, 19), 28);
+        assert_eq!(cycle.extrapolate(&totals, 20), 30);
+    }
+
+    #[test]
+    fn test_coincide() {
+        let every = |period| Cycle::new(period, period);
+        assert_eq!(coincide(&[every(4), every(6), every(10)]), Some(60));
+        assert_eq!(coincide(&[Cycle::new(2, 3), Cycle::new(3, 5)]), Some(8));
+        assert_eq!(coincide(&[Cycle::new(0, 4), Cycle::new(1, 6)]), None);
+        assert_eq!(coincide(&[Cycle::new(7, 2)]), Some(7));
+    }
+}
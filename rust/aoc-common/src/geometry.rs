@@ -0,0 +1,452 @@
+//! Points, directions and bounding boxes on the integer lattice.
+//!
+//! 2D coordinates follow the puzzle maps: `x` increases to the right and `y`
+//! increases *downwards*, so [`Direction::Up`] is `(0, -1)` and turning right
+//! is clockwise on screen.  Days whose `y` axis points up can negate `y` when
+//! parsing, or use [`Point2::rotate_left`]/[`Point2::rotate_right`] with that
+//! in mind.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use anyhow::{bail, Result};
+
+use crate::grid;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ZERO: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate's sign: the unit step (diagonals included) towards
+    /// this vector's direction.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// This vector turned a quarter turn to the left (anticlockwise on
+    /// screen).
+    pub fn rotate_left(self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// This vector turned a quarter turn to the right (clockwise on screen).
+    pub fn rotate_right(self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |d| self + d.delta())
+    }
+
+    /// All eight neighbours, clockwise from up-left.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        grid::NEIGHBOURS8
+            .into_iter()
+            .map(move |delta| self + Point2::from(delta))
+    }
+
+    /// This point as a grid position, if it's not negative.
+    pub fn to_pos(self) -> Option<grid::Pos> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl Point3 {
+    pub const ZERO: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |delta| self + delta)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl From<grid::Pos> for Point2 {
+    fn from((x, y): grid::Pos) -> Point2 {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl From<Point2> for (i64, i64) {
+    fn from(p: Point2) -> (i64, i64) {
+        (p.x, p.y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Component-wise arithmetic for both point types.
+macro_rules! impl_vector_ops {
+    ($t:ident { $($c:ident),+ }) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, other: $t) -> $t {
+                $t { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, other: $t) -> $t {
+                $t { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t { $($c: -self.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = $t;
+
+            fn mul(self, n: i64) -> $t {
+                $t { $($c: self.$c * n),+ }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+/// One of the four orthogonal directions on a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step taken moving one square this way.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// The map arrow for this direction: `^`, `>`, `v` or `<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// Accepts map arrows (`^>v<`), `U`/`R`/`D`/`L` and compass points
+/// (`N`/`E`/`S`/`W`).
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Direction> {
+        Ok(match c {
+            '^' | 'U' | 'N' => Direction::Up,
+            '>' | 'R' | 'E' => Direction::Right,
+            'v' | 'D' | 'S' => Direction::Down,
+            '<' | 'L' | 'W' => Direction::Left,
+            _ => bail!("Not a direction: {c:?}"),
+        })
+    }
+}
+
+/// The smallest axis-aligned rectangle containing some points, inclusive at
+/// both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds2 {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds2 {
+    /// Just `p`.
+    pub fn new(p: Point2) -> Bounds2 {
+        Bounds2 { min: p, max: p }
+    }
+
+    /// The bounds of `points`, or `None` if there aren't any.
+    pub fn from_points(points: impl IntoIterator<Item = Point2>) -> Option<Bounds2> {
+        let mut points = points.into_iter();
+        let mut bounds = Bounds2::new(points.next()?);
+        for p in points {
+            bounds.extend(p);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds to include `p`.
+    pub fn extend(&mut self, p: Point2) {
+        self.min = Point2::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point2::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    /// Every point inside, in row order.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let Bounds2 { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+/// The smallest axis-aligned box containing some points, inclusive at both
+/// ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Bounds3 {
+    /// Just `p`.
+    pub fn new(p: Point3) -> Bounds3 {
+        Bounds3 { min: p, max: p }
+    }
+
+    /// The bounds of `points`, or `None` if there aren't any.
+    pub fn from_points(points: impl IntoIterator<Item = Point3>) -> Option<Bounds3> {
+        let mut points = points.into_iter();
+        let mut bounds = Bounds3::new(points.next()?);
+        for p in points {
+            bounds.extend(p);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the bounds to include `p`.
+    pub fn extend(&mut self, p: Point3) {
+        self.min = Point3::new(
+            self.min.x.min(p.x),
+            self.min.y.min(p.y),
+            self.min.z.min(p.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(p.x),
+            self.max.y.max(p.y),
+            self.max.z.max(p.z),
+        );
+    }
+
+    /// The bounds grown by `n` in every direction.
+    pub fn expand(&self, n: i64) -> Bounds3 {
+        let n = Point3::new(n, n, n);
+        Bounds3 {
+            min: self.min - n,
+            max: self.max + n,
+        }
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_and_distance() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a + b, Point2::new(-2, 7));
+        assert_eq!(a - b, Point2::new(4, -3));
+        assert_eq!(-a, Point2::new(-1, -2));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+        assert_eq!(c.manhattan(Point3::ZERO), 6);
+        assert_eq!(c.chebyshev(Point3::ZERO), 3);
+        assert_eq!(c.neighbours6().count(), 6);
+    }
+
+    #[test]
+    fn test_directions() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            assert_eq!(d.delta().rotate_right(), d.turn_right().delta());
+            assert_eq!(d.delta().rotate_left(), d.turn_left().delta());
+            assert_eq!(-d.delta(), d.opposite().delta());
+            assert_eq!(Direction::try_from(d.arrow()).unwrap(), d);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::try_from('W').unwrap(), Direction::Left);
+        assert!(Direction::try_from('x').is_err());
+        assert_eq!(Point2::ZERO.neighbours4().count(), 4);
+        assert_eq!(Point2::ZERO.neighbours8().count(), 8);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds =
+            Bounds2::from_points([Point2::new(1, 5), Point2::new(-2, 3), Point2::new(0, 0)])
+                .unwrap();
+        assert_eq!(bounds.min, Point2::new(-2, 0));
+        assert_eq!(bounds.max, Point2::new(1, 5));
+        assert_eq!((bounds.width(), bounds.height()), (4, 6));
+        assert_eq!(bounds.points().count(), 24);
+        assert!(bounds.contains(Point2::new(-2, 5)));
+        assert!(!bounds.contains(Point2::new(2, 5)));
+        assert!(Bounds2::from_points([]).is_none());
+
+        let bounds = Bounds3::from_points([Point3::new(1, 2, 3), Point3::ZERO]).unwrap();
+        assert!(bounds.contains(Point3::new(1, 1, 1)));
+        assert!(!bounds.contains(Point3::new(1, 1, -1)));
+        assert!(bounds.expand(1).contains(Point3::new(1, 1, -1)));
+    }
+}
@@ -0,0 +1,359 @@
+//! A dense, rectangular 2D grid, as drawn by most puzzle inputs.
+//!
+//! Positions are `(x, y)` with `(0, 0)` the top-left character of the input,
+//! `x` increasing to the right and `y` increasing downwards.  Indexing with
+//! `grid[(x, y)]` panics off the grid; [`Grid::get`] takes signed coordinates
+//! and returns `None` instead, so neighbours can be looked up without
+//! checking first.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+
+pub type Pos = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours: up, right, down, left.
+pub const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight neighbours, clockwise from up-left.
+pub const NEIGHBOURS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its cells in row order.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>> {
+        if cells.len() != width * height {
+            bail!("{} cells don't make a {width}x{height} grid", cells.len());
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a character map, one row per line, converting each character
+    /// with `f`.  Blank lines at either end are ignored; every other line
+    /// must be the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.trim_matches(['\r', '\n']).lines() {
+            let line = line.trim_end();
+            let len = line.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    bail!("Row {height} has {len} cells, expected {width}")
+                }
+                Some(_) => {}
+            }
+            for ch in line.chars() {
+                cells.push(f(ch)?);
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): (i64, i64)) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// Converts signed coordinates to a position, if they're on the grid.
+    pub fn pos(&self, (x, y): (i64, i64)) -> Option<Pos> {
+        self.in_bounds((x, y)).then_some((x as usize, y as usize))
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> Option<&T> {
+        let (x, y) = self.pos(pos)?;
+        Some(&self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, pos: (i64, i64)) -> Option<&mut T> {
+        let (x, y) = self.pos(pos)?;
+        Some(&mut self.cells[y * self.width + x])
+    }
+
+    /// `pos` moved by `delta`, if that's still on the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Option<Pos> {
+        self.pos((x as i64 + dx, y as i64 + dy))
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The up to eight neighbours of `pos`, diagonals included, that are on
+    /// the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// Every position, in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    /// The position of the first cell (in row order) matching `f`.
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, value)| f(value)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` would panic on a zero-width grid.
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of range");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a `width` x `height` grid whose cell at `(x, y)` is `self[f(x, y)]`.
+    fn remap(&self, width: usize, height: usize, f: impl Fn(usize, usize) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[f(x, y)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Swaps rows and columns (reflects in the leading diagonal).
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse(s, Ok)
+    }
+}
+
+/// Draws the grid the way it was parsed: each cell's `Display`, one row per
+/// line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let g = grid("\nab.\n#cd\n");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[(1, 0)], 'b');
+        assert_eq!(g[(0, 1)], '#');
+        assert_eq!(g.to_string(), "ab.\n#cd\n");
+        assert!("ab\nabc".parse::<Grid<char>>().is_err());
+
+        let digits = Grid::parse("12\n34", |ch| {
+            ch.to_digit(10)
+                .ok_or_else(|| anyhow::anyhow!("bad digit {ch}"))
+        })
+        .unwrap();
+        assert_eq!(digits.row(1), [3, 4]);
+        assert!(Grid::parse("1x", |ch| ch
+            .to_digit(10)
+            .ok_or_else(|| anyhow::anyhow!("")))
+        .is_err());
+    }
+
+    #[test]
+    fn test_bounds_and_neighbours() {
+        let g = grid("abc\ndef\nghi");
+        assert_eq!(g.get((2, 2)), Some(&'i'));
+        assert_eq!(g.get((-1, 0)), None);
+        assert_eq!(g.get((3, 0)), None);
+        assert_eq!(g.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(g.neighbours4((1, 1)).count(), 4);
+        assert_eq!(g.neighbours8((1, 1)).count(), 8);
+        assert_eq!(g.neighbours8((2, 2)).count(), 3);
+        assert_eq!(g.find(|&ch| ch == 'f'), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let g = grid("abc\ndef");
+        let rows: Vec<String> = g.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let columns: Vec<String> = g.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_cw(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_ccw(), grid("cf\nbe\nad"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc"));
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+    }
+}
@@ -0,0 +1,175 @@
+//! Finding and reading puzzle inputs at runtime.
+//!
+//! Inputs live under a data root laid out as
+//!
+//! ```text
+//! <root>/<year>/day<NN>/input
+//! <root>/<year>/day<NN>/example_input
+//! <root>/<year>/day<NN>/example_input2
+//! ```
+//!
+//! The root defaults to the `data/` directory of this checkout and can be
+//! overridden with the [`DATA_DIR_ENV`] environment variable.
+
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use thiserror::Error;
+
+/// Environment variable naming the data root.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// The data root shipped with the repository.
+pub const DEFAULT_DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../data");
+
+/// The input file a name refers to doesn't exist.
+#[derive(Error, Debug)]
+#[error("no {name} input for {year} day {day:02}: {} does not exist", path.display())]
+pub struct MissingInput {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Maps `(year, day, input name)` to a file under a data root.
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    root: PathBuf,
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> InputResolver {
+        InputResolver { root: root.into() }
+    }
+
+    /// Uses `root` if given, then [`DATA_DIR_ENV`], then [`DEFAULT_DATA_DIR`].
+    pub fn from_env(root: Option<PathBuf>) -> InputResolver {
+        let root = root
+            .or_else(|| std::env::var_os(DATA_DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR));
+        InputResolver::new(root)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Where the input called `name` lives.  `final` is the puzzle input,
+    /// `example`, `example2`, ... are the examples from the puzzle text.
+    pub fn path(&self, year: u16, day: u8, name: &str) -> PathBuf {
+        let file_name = match name {
+            "final" => "input".to_string(),
+            _ => match name.strip_prefix("example") {
+                Some(suffix) => format!("example_input{suffix}"),
+                None => name.to_string(),
+            },
+        };
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day:02}"))
+            .join(file_name)
+    }
+
+    /// The names of the inputs present for a day, examples first.
+    pub fn inputs(&self, year: u16, day: u8) -> Result<Vec<String>> {
+        let dir = self
+            .root
+            .join(year.to_string())
+            .join(format!("day{day:02}"));
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+        let mut names = vec![];
+        for entry in std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to list inputs in {}", dir.display()))?
+        {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else {
+                continue;
+            };
+            if file_name == "input" {
+                names.push("final".to_string());
+            } else if let Some(suffix) = file_name.strip_prefix("example_input") {
+                names.push(format!("example{suffix}"));
+            }
+        }
+        names.sort_by_key(|name| input_order(name));
+        Ok(names)
+    }
+
+    /// Where a year's recorded answers live.
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string()).join("answers.toml")
+    }
+
+    pub fn load(&self, year: u16, day: u8, name: &str) -> Result<String> {
+        let path = self.path(year, day, name);
+        if !path.is_file() {
+            return Err(MissingInput {
+                year,
+                day,
+                name: name.to_string(),
+                path,
+            }
+            .into());
+        }
+        read_input_file(&path)
+    }
+}
+
+/// Sorts `example`, `example2`, ... before `final`, and anything else after.
+pub fn input_order(name: &str) -> (u8, u32, String) {
+    match name {
+        "final" => (1, 0, String::new()),
+        _ => match name.strip_prefix("example").map(|n| n.parse().unwrap_or(1)) {
+            Some(n) => (0, n, String::new()),
+            None => (2, 0, name.to_string()),
+        },
+    }
+}
+
+/// Reads an input from an explicit path.
+pub fn read_input_file(path: &Path) -> Result<String> {
+    read_to_string(path).with_context(|| format!("Failed to read input {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let resolver = InputResolver::new("/data");
+        assert_eq!(
+            resolver.path(2022, 6, "final"),
+            PathBuf::from("/data/2022/day06/input")
+        );
+        assert_eq!(
+            resolver.path(2021, 23, "example"),
+            PathBuf::from("/data/2021/day23/example_input")
+        );
+        assert_eq!(
+            resolver.path(2023, 10, "example4"),
+            PathBuf::from("/data/2023/day10/example_input4")
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let resolver = InputResolver::new("/nonexistent");
+        let err = resolver.load(2022, 1, "final").unwrap_err();
+        assert!(err.is::<MissingInput>());
+        assert_eq!(
+            err.to_string(),
+            "no final input for 2022 day 01: /nonexistent/2022/day01/input does not exist"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Pieces shared between the per-year solution crates and the `aoc` runner.
+
+pub mod answers;
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod random;
+pub mod search;
+pub mod solution;
+
+pub use answers::{Answer, Answers, Expected};
+pub use geometry::{Bounds2, Bounds3, Direction, Point2, Point3};
+pub use grid::Grid;
+pub use input::{InputResolver, MissingInput};
+pub use solution::{DynSolution, Solution, Timed, Unimplemented};
+
+/// Which half of a day's puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumString, strum::Display, strum::EnumIter)]
+pub enum Part {
+    #[strum(to_string = "part1", serialize = "1")]
+    Part1,
+    #[strum(to_string = "part2", serialize = "2")]
+    Part2,
+}
+
+/// An entry in a year's registry of solutions.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub const fn new(day: u8, solution: &'static dyn DynSolution) -> Day {
+        Day { day, solution }
+    }
+}
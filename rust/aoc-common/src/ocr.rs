@@ -0,0 +1,247 @@
+//! Reading the block capitals that some puzzles draw instead of printing
+//! an answer.
+//!
+//! The drawings come in two fonts: a small one, 4 pixels wide and 6 tall
+//! with a blank column between letters (a CRT, folded transparent paper),
+//! and a large one, 6 wide and 10 tall with two blank columns (stars that
+//! line up into a message).  Letters sit at a fixed pitch from the left
+//! edge, so a drawing is read by cutting it into letter-sized cells.
+
+use anyhow::{bail, Result};
+
+use crate::Grid;
+
+/// A block-letter font: every glyph is `width` x `height` pixels, and
+/// glyphs are `pitch` columns apart.
+#[derive(Debug, Clone, Copy)]
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    pub pitch: usize,
+    /// Each letter with its pixels, drawn with `#` and `.`, one row per
+    /// line.
+    glyphs: &'static [(char, &'static str)],
+}
+
+impl Font {
+    /// The letter drawn in columns `x..x + width` of `pixels`, or `None`.
+    fn glyph(&self, pixels: &Grid<bool>, x: usize) -> Option<char> {
+        let drawing = self.drawing(pixels, x);
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| glyph.lines().eq(drawing.lines()))
+            .map(|&(letter, _)| letter)
+    }
+
+    /// The cell at column `x`, drawn the same way as the glyphs.  Columns
+    /// past the right edge count as blank, since drawings often stop at the
+    /// last lit pixel.
+    fn drawing(&self, pixels: &Grid<bool>, x: usize) -> String {
+        (0..self.height)
+            .map(|y| {
+                (x..x + self.width)
+                    .map(|x| match pixels.get((x as i64, y as i64)) {
+                        Some(true) => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+        ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+        ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+        ('E', "####\n#...\n###.\n#...\n#...\n####"),
+        ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+        ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+        ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+        ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+        ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+        ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+        ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+        ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+        ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+        ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+        ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('Y', "#...\n#...\n.#.#\n..#.\n..#.\n..#."),
+        ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+    ],
+};
+
+pub const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'B',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+        ),
+        (
+            'C',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+        ),
+        (
+            'E',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'F',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'G',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+        ),
+        (
+            'H',
+            "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'J',
+            "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n#...#.\n.###..",
+        ),
+        (
+            'K',
+            "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+        ),
+        (
+            'L',
+            "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'N',
+            "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+        ),
+        (
+            'P',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'R',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+        ),
+        (
+            'X',
+            "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+        ),
+        (
+            'Z',
+            "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+        ),
+    ],
+};
+
+/// Parses a drawing made of `#` (lit) and `.` (dark) pixels.
+pub fn pixels(drawing: &str) -> Result<Grid<bool>> {
+    Grid::parse(drawing, |ch| match ch {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => bail!("Unexpected pixel {ch:?}"),
+    })
+}
+
+/// Reads the letters in `pixels`, choosing the font by the drawing's
+/// height.
+pub fn read(pixels: &Grid<bool>) -> Result<String> {
+    match [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == pixels.height())
+    {
+        Some(font) => read_with(font, pixels),
+        None => bail!("No font is {} pixels tall", pixels.height()),
+    }
+}
+
+/// Reads the letters in `pixels` in the given font.
+pub fn read_with(font: Font, pixels: &Grid<bool>) -> Result<String> {
+    if pixels.height() != font.height {
+        bail!(
+            "The drawing is {} pixels tall, but the font is {}",
+            pixels.height(),
+            font.height
+        );
+    }
+    (0..pixels.width())
+        .step_by(font.pitch)
+        .map(|x| match font.glyph(pixels, x) {
+            Some(letter) => Ok(letter),
+            None => bail!("Unknown glyph at column {x}:\n{}", font.drawing(pixels, x)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_small() -> Result<()> {
+        let drawing = pixels(
+            "
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.
+",
+        )?;
+        assert_eq!(read(&drawing)?, "HELLO");
+        // The trailing gap is often left off.
+        let trimmed = pixels(".##.\n#..#\n#..#\n####\n#..#\n#..#")?;
+        assert_eq!(read(&trimmed)?, "A");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_large() -> Result<()> {
+        let drawing = pixels(
+            "
+#....#..###...
+#....#...#....
+#....#...#....
+#....#...#....
+######...#....
+#....#...#....
+#....#...#....
+#....#...#....
+#....#...#....
+#....#..###...
+",
+        )?;
+        let err = read(&drawing).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown glyph at column 8:\n###...\n.#....\n.#....\n.#....\n.#....\n.#....\n.#....\n.#....\n.#....\n###..."
+        );
+
+        let padded: Vec<String> = LARGE.glyphs[0]
+            .1
+            .lines()
+            .map(|row| format!("{row}.."))
+            .collect();
+        assert_eq!(read(&pixels(&padded.join("\n"))?)?, "A");
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_size() {
+        assert!(read(&Grid::new(5, 7, false)).is_err());
+        assert!(read_with(LARGE, &Grid::new(5, 6, false)).is_err());
+        assert!(pixels("#.x").is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! A small seeded pseudo-random number generator, for when the same seed
+//! has to give the same numbers every run: balancing a tree the same way
+//! each time, or generating test cases.
+
+/// Used in place of a seed of 0.
+const FALLBACK_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// xorshift64: fast, tiny, and plenty random for this, though not for
+/// anything that needs to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// A generator seeded with `seed`.  xorshift never leaves 0, so a seed
+    /// of 0 is swapped for a fixed non-zero one.
+    pub fn new(seed: u64) -> Rng {
+        Rng(if seed == 0 { FALLBACK_SEED } else { seed })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number from `0` to `n - 1`.  Not quite uniform unless `n` is a
+    /// power of two, which doesn't matter for small `n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(0x5eed), numbers(0x5eed));
+        assert_ne!(numbers(0x5eed), numbers(0x5eee));
+        assert!(numbers(0).iter().all(|&n| n != 0));
+
+        let mut rng = Rng::new(0x5eed);
+        let mut seen = [false; 6];
+        for _ in 0..100 {
+            seen[rng.below(6) as usize] = true;
+        }
+        assert_eq!(seen, [true; 6]);
+    }
+}
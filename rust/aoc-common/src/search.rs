@@ -0,0 +1,363 @@
+//! Shortest-path and reachability searches over implicit graphs.
+//!
+//! A graph is described by a start state and a `successors` function giving
+//! the states one step away (with the cost of the step, for the weighted
+//! searches).  The searches stop at the first state matching `goal` and
+//! return the path that reached it.
+//!
+//! States are deduplicated by equality, or by a key (the `_by_key`
+//! variants) when several states should count as the same node: a key
+//! might drop bookkeeping that doesn't affect what happens next, or
+//! canonicalise equivalent arrangements.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The result of a successful search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// The total cost of the steps taken.
+    pub cost: C,
+    /// Every state along the way, from the start to the goal inclusive.
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("a path always contains its start")
+    }
+}
+
+/// A state the search has reached, and how it got there.
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+}
+
+/// Walks the parent links back from `index` to the start.
+fn reconstruct<S, C: Copy>(mut nodes: Vec<Node<S, C>>, index: usize) -> Path<S, C> {
+    let cost = nodes[index].cost;
+    let mut indices = vec![index];
+    while let Some(parent) = nodes[*indices.last().unwrap()].parent {
+        indices.push(parent);
+    }
+    let mut states: Vec<Option<S>> = nodes.drain(..).map(|node| Some(node.state)).collect();
+    let states = indices
+        .into_iter()
+        .rev()
+        .map(|i| states[i].take().unwrap())
+        .collect();
+    Path { cost, states }
+}
+
+/// The cheapest path from `start` to a state matching `goal`, where
+/// `successors` yields each next state with the (non-negative) cost of
+/// getting there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(start, S::clone, successors, |_| C::default(), goal)
+}
+
+/// [`dijkstra`], treating states with the same `key` as one node.
+pub fn dijkstra_by_key<S, K, C, I>(
+    start: S,
+    key: impl FnMut(&S) -> K,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    K: Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(start, key, successors, |_| C::default(), goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the remaining cost
+/// from a state to the goal.  The path is only guaranteed cheapest if the
+/// heuristic never overestimates and never drops by more than the cost of a
+/// step.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_by_key(start, S::clone, successors, heuristic, goal)
+}
+
+/// [`astar`], treating states with the same `key` as one node.
+pub fn astar_by_key<S, K, C, I>(
+    start: S,
+    mut key: impl FnMut(&S) -> K,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    K: Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // The cheapest cost seen so far for each key, and whether it's final.
+    let mut best: HashMap<K, (C, bool)> = HashMap::new();
+    let mut nodes = vec![];
+    let mut queue = BinaryHeap::new();
+
+    best.insert(key(&start), (C::default(), false));
+    queue.push(Reverse((heuristic(&start), 0)));
+    nodes.push(Node {
+        state: start,
+        cost: C::default(),
+        parent: None,
+    });
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let node = &nodes[index];
+        let cost = node.cost;
+        match best.get_mut(&key(&node.state)) {
+            Some((best_cost, done)) if !*done && *best_cost == cost => *done = true,
+            _ => continue,
+        }
+        if goal(&node.state) {
+            return Some(reconstruct(nodes, index));
+        }
+        for (next, step) in successors(&node.state) {
+            let next_cost = cost + step;
+            match best.entry(key(&next)) {
+                Entry::Occupied(entry) if entry.get().1 || entry.get().0 <= next_cost => continue,
+                Entry::Occupied(mut entry) => *entry.get_mut() = (next_cost, false),
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, false));
+                }
+            }
+            queue.push(Reverse((next_cost + heuristic(&next), nodes.len())));
+            nodes.push(Node {
+                state: next,
+                cost: next_cost,
+                parent: Some(index),
+            });
+        }
+    }
+    None
+}
+
+/// The path with the fewest steps from `start` to a state matching `goal`.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_by_key(start, S::clone, successors, goal)
+}
+
+/// [`bfs`], treating states with the same `key` as one node.
+pub fn bfs_by_key<S, K, I>(
+    start: S,
+    mut key: impl FnMut(&S) -> K,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut nodes = vec![];
+    let mut queue = VecDeque::new();
+
+    seen.insert(key(&start));
+    queue.push_back(0);
+    nodes.push(Node {
+        state: start,
+        cost: 0,
+        parent: None,
+    });
+
+    while let Some(index) = queue.pop_front() {
+        if goal(&nodes[index].state) {
+            return Some(reconstruct(nodes, index));
+        }
+        let cost = nodes[index].cost;
+        for next in successors(&nodes[index].state) {
+            if seen.insert(key(&next)) {
+                queue.push_back(nodes.len());
+                nodes.push(Node {
+                    state: next,
+                    cost: cost + 1,
+                    parent: Some(index),
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Some path (not necessarily the shortest) from `start` to a state
+/// matching `goal`, exploring as deep as possible first.  The cost is the
+/// number of steps.
+pub fn dfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    dfs_by_key(start, S::clone, successors, goal)
+}
+
+/// [`dfs`], treating states with the same `key` as one node.
+pub fn dfs_by_key<S, K, I>(
+    start: S,
+    mut key: impl FnMut(&S) -> K,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut nodes = vec![Node {
+        state: start,
+        cost: 0,
+        parent: None,
+    }];
+    let mut stack = vec![0];
+
+    while let Some(index) = stack.pop() {
+        if !seen.insert(key(&nodes[index].state)) {
+            continue;
+        }
+        if goal(&nodes[index].state) {
+            return Some(reconstruct(nodes, index));
+        }
+        let cost = nodes[index].cost;
+        let next: Vec<S> = successors(&nodes[index].state)
+            .into_iter()
+            .filter(|next| !seen.contains(&key(next)))
+            .collect();
+        // Push in reverse so the first successor is explored first.
+        for next in next.into_iter().rev() {
+            stack.push(nodes.len());
+            nodes.push(Node {
+                state: next,
+                cost: cost + 1,
+                parent: Some(index),
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph where the direct edge is dearer than the detour.
+    fn edges(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(0, edges, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, [0, 1, 2, 3]);
+        assert_eq!(*path.goal(), 3);
+        assert_eq!(dijkstra(0, edges, |&n| n == 4), None);
+        assert_eq!(dijkstra(3, edges, |&n| n == 3).unwrap().states, [3]);
+    }
+
+    #[test]
+    fn test_astar_and_keys() {
+        // Walking along a line from 0 to 10, one or two at a time; the key
+        // ignores the step counter in the state.
+        let successors = |&(x, n): &(i64, u32)| [((x + 1, n + 1), 1), ((x + 2, n + 1), 3)];
+        let path = astar_by_key(
+            (0, 0),
+            |&(x, _)| x,
+            successors,
+            |&(x, _)| 10 - x,
+            |&(x, _)| x == 10,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(*path.goal(), (10, 10));
+
+        let path = astar(
+            0_i64,
+            |&x| [(x + 1, 1), (x - 1, 1)],
+            |&x| (7 - x).abs(),
+            |&x| x == 7,
+        );
+        assert_eq!(path.unwrap().states, (0..=7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_bfs_and_dfs() {
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= 3 && y <= 3)
+        };
+        let path = bfs((0, 0), successors, |&p| p == (3, 3)).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+
+        let path = dfs((0, 0), successors, |&p| p == (0, 3)).unwrap();
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(*path.goal(), (0, 3));
+        assert_eq!(path.cost, path.states.len() - 1);
+        assert!(dfs((0, 0), successors, |&p| p == (4, 0)).is_none());
+
+        // Keyed on x alone, stepping up never reaches a new node, so the
+        // search goes straight along the bottom row.
+        let mut visited = vec![];
+        let path = dfs_by_key(
+            (0, 0),
+            |&(x, _)| x,
+            |&(x, y): &(i32, i32)| {
+                visited.push((x, y));
+                [(x, y + 1), (x + 1, y)]
+                    .into_iter()
+                    .filter(|&(x, y)| x <= 3 && y <= 3)
+            },
+            |&(x, _)| x == 3,
+        )
+        .unwrap();
+        assert_eq!(path.states, [(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(visited.len(), 3);
+    }
+}
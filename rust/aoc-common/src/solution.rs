@@ -0,0 +1,159 @@
+//! The [`Solution`] trait every day implements, and the type-erased
+//! [`DynSolution`] view of it that the registries hold.
+
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::Part;
+
+/// Returned (through `bail!`) from a part that hasn't been solved yet.
+#[derive(Error, Debug)]
+#[error("unimplemented")]
+pub struct Unimplemented;
+
+/// One day's puzzle.
+///
+/// The input is parsed once and then handed to each part, which returns its
+/// answer rather than printing it.
+pub trait Solution {
+    /// The parsed puzzle input.  It may borrow from the raw input text.
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Whether [`Solution::parse`] does any work worth timing.  A day whose
+    /// input is just the raw text sets this to `false`.
+    const PARSES: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2>;
+}
+
+/// Object-safe view of a [`Solution`], with the answers already formatted.
+pub trait DynSolution: Sync {
+    /// Parses `input` once and runs each of `parts` against it.
+    ///
+    /// The outer error is a parse failure; each part then succeeds or fails
+    /// on its own.
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<Result<String>>> {
+        Ok(self
+            .timed(input, parts)?
+            .parts
+            .into_iter()
+            .map(|(answer, _)| answer)
+            .collect())
+    }
+
+    /// Like [`DynSolution::run`], but also measures how long parsing and each
+    /// part took.  Formatting the answers isn't counted.
+    fn timed(&self, input: &str, parts: &[Part]) -> Result<Timed>;
+}
+
+/// The outcome of one [`DynSolution::timed`] run.
+#[derive(Debug)]
+pub struct Timed {
+    /// How long parsing took, or `None` if the day doesn't parse (see
+    /// [`Solution::PARSES`]).
+    pub parse: Option<Duration>,
+    /// The answer and time taken for each requested part, in order.
+    pub parts: Vec<(Result<String>, Duration)>,
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn timed(&self, input: &str, parts: &[Part]) -> Result<Timed> {
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse = S::PARSES.then(|| start.elapsed());
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                match part {
+                    Part::Part1 => {
+                        let answer = S::part1(&input);
+                        let elapsed = start.elapsed();
+                        (answer.map(|answer| answer.to_string()), elapsed)
+                    }
+                    Part::Part2 => {
+                        let answer = S::part2(&input);
+                        let elapsed = start.elapsed();
+                        (answer.map(|answer| answer.to_string()), elapsed)
+                    }
+                }
+            })
+            .collect();
+        Ok(Timed { parse, parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Raw;
+
+    impl Solution for Raw {
+        type Input<'a> = &'a str;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        const PARSES: bool = false;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+            Ok(input.len())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+            Ok(input.lines().count())
+        }
+    }
+
+    struct Parsed;
+
+    impl Solution for Parsed {
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+            Ok(input.iter().map(|l| l.len()).sum())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_parse_timing() -> Result<()> {
+        let parts = [Part::Part1, Part::Part2];
+        let raw = Raw.timed("ab\ncd", &parts)?;
+        assert_eq!(raw.parse, None);
+        let parsed = Parsed.timed("ab\ncd", &parts)?;
+        assert!(parsed.parse.is_some());
+        let answers = |timed: Timed| {
+            timed
+                .parts
+                .into_iter()
+                .map(|(answer, _)| answer.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(answers(raw), ["5", "2"]);
+        assert_eq!(answers(parsed), ["4", "2"]);
+        Ok(())
+    }
+}
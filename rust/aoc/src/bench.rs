@@ -0,0 +1,243 @@
+//! `aoc bench`: time parsing and each part separately over repeated runs, and
+//! report the median and minimum as a table, CSV or JSON.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fs::File,
+    io::{stdout, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Answers, Day, InputResolver, Part, Unimplemented};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::selected_days;
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct BenchArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: Option<u8>,
+    #[arg(long)]
+    part: Option<Part>,
+    #[arg(long, default_value = "final")]
+    input: String,
+    /// Read inputs from this directory rather than `AOC_DATA_DIR` or the
+    /// default data root.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+    /// How many times to run each day.
+    #[arg(long, short = 'n', default_value_t = 10)]
+    iterations: usize,
+    /// Stop repeating a day once it has used this many seconds, even if it
+    /// hasn't reached `--iterations`.  Every day runs at least once.
+    #[arg(long, default_value_t = 10.0)]
+    max_secs: f64,
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Write the report here instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// One row of the report: the timings of one stage of one day.
+#[derive(Debug, Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    input: String,
+    /// `parse`, `part1` or `part2`.
+    stage: String,
+    /// `ok`, `unimplemented`, `error`, or `n/a` for the parse stage of a day
+    /// that works on the raw text.
+    status: &'static str,
+    iterations: usize,
+    median_ns: u64,
+    min_ns: u64,
+}
+
+impl Record {
+    fn new(year: u16, day: u8, input: &str, stage: &str, status: &'static str) -> Record {
+        Record {
+            year,
+            day,
+            input: input.to_string(),
+            stage: stage.to_string(),
+            status,
+            iterations: 0,
+            median_ns: 0,
+            min_ns: 0,
+        }
+    }
+
+    fn with_samples(mut self, samples: &mut [Duration]) -> Record {
+        samples.sort();
+        let n = samples.len();
+        self.iterations = n;
+        if n > 0 {
+            let median = if n % 2 == 1 {
+                samples[n / 2]
+            } else {
+                (samples[n / 2 - 1] + samples[n / 2]) / 2
+            };
+            self.median_ns = median.as_nanos() as u64;
+            self.min_ns = samples[0].as_nanos() as u64;
+        }
+        self
+    }
+}
+
+fn format_duration(ns: u64) -> String {
+    let d = Duration::from_nanos(ns);
+    if d < Duration::from_millis(1) {
+        format!("{:.1}µs", d.as_secs_f64() * 1e6)
+    } else if d < Duration::from_secs(1) {
+        format!("{:.3}ms", d.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.3}s", d.as_secs_f64())
+    }
+}
+
+fn print_row(record: &Record) {
+    let (median, min) = match record.status {
+        "ok" => (
+            format_duration(record.median_ns),
+            format_duration(record.min_ns),
+        ),
+        status => (status.to_string(), String::new()),
+    };
+    println!(
+        "{:<4}  {:02}   {:<8}  {:<5}  {:>5}  {:>10}  {:>10}",
+        record.year, record.day, record.input, record.stage, record.iterations, median, min
+    );
+}
+
+/// Times one day: `iterations` runs (or fewer, if it's slow) of parsing and
+/// each of `parts`.
+fn bench_day(
+    year: u16,
+    day: &Day,
+    name: &str,
+    input: &str,
+    parts: &[Part],
+    args: &BenchArgs,
+) -> Vec<Record> {
+    let budget = Duration::from_secs_f64(args.max_secs);
+    let start = Instant::now();
+    let mut parse = vec![];
+    let mut samples: Vec<Vec<Duration>> = vec![vec![]; parts.len()];
+    let mut statuses = vec!["ok"; parts.len()];
+    for _ in 0..args.iterations.max(1) {
+        let timed = match day.solution.timed(input, parts) {
+            Ok(timed) => timed,
+            Err(_) => return vec![Record::new(year, day.day, name, "parse", "error")],
+        };
+        parse.extend(timed.parse);
+        for (i, (answer, elapsed)) in timed.parts.into_iter().enumerate() {
+            match answer {
+                Ok(_) => samples[i].push(elapsed),
+                Err(err) if err.is::<Unimplemented>() => statuses[i] = "unimplemented",
+                Err(_) => statuses[i] = "error",
+            }
+        }
+        if start.elapsed() > budget {
+            break;
+        }
+    }
+    let mut records = vec![if parse.is_empty() {
+        Record::new(year, day.day, name, "parse", "n/a")
+    } else {
+        Record::new(year, day.day, name, "parse", "ok").with_samples(&mut parse)
+    }];
+    for ((part, status), samples) in parts.iter().zip(statuses).zip(&mut samples) {
+        let record = Record::new(year, day.day, name, &part.to_string(), status);
+        records.push(match status {
+            "ok" => record.with_samples(samples),
+            _ => record,
+        });
+    }
+    records
+}
+
+pub fn bench(args: BenchArgs) -> Result<()> {
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::iter().collect(),
+    };
+    let days = selected_days(args.year, args.day)?;
+    let resolver = InputResolver::from_env(args.data_dir.clone());
+    let mut answers: BTreeMap<u16, Answers> = BTreeMap::new();
+    let table = matches!(args.format, Format::Table);
+    if table && args.output.is_some() {
+        bail!("--output needs --format csv or json");
+    }
+
+    if table {
+        println!(
+            "{:<4}  {:<3}  {:<8}  {:<5}  {:>5}  {:>10}  {:>10}",
+            "year", "day", "input", "stage", "runs", "median", "min"
+        );
+    }
+    let mut records = vec![];
+    for (year, day) in days {
+        let answers = match answers.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Answers::load(&resolver.answers_path(year))?),
+        };
+        // Days the answers file marks as too slow or too hungry to run.
+        if answers
+            .get(day.day, &args.input)
+            .is_some_and(|expected| expected.skip.is_some())
+        {
+            continue;
+        }
+        let Ok(input) = resolver.load(year, day.day, &args.input) else {
+            continue;
+        };
+        for record in bench_day(year, &day, &args.input, &input, &parts, &args) {
+            if table {
+                print_row(&record);
+            }
+            records.push(record);
+        }
+    }
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?,
+        ),
+        None => Box::new(stdout()),
+    };
+    match args.format {
+        Format::Table => {}
+        Format::Csv => {
+            writeln!(
+                out,
+                "year,day,input,stage,status,iterations,median_ns,min_ns"
+            )?;
+            for r in &records {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{}",
+                    r.year, r.day, r.input, r.stage, r.status, r.iterations, r.median_ns, r.min_ns
+                )?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, &records)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
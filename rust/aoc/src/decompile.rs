@@ -0,0 +1,30 @@
+//! `aoc decompile`: print a 2021 day 24 MONAD program as a Rust function,
+//! one statement per instruction, for reading what it does.
+
+use std::path::PathBuf;
+
+use adventofcode2021::day24::{analysis, Day24};
+use anyhow::Result;
+use aoc_common::{input::read_input_file, InputResolver, Solution};
+
+#[derive(Debug, clap::Args)]
+pub struct DecompileArgs {
+    #[arg(long, default_value = "final")]
+    input: String,
+    /// Read inputs from this directory rather than `AOC_DATA_DIR` or the
+    /// default data root.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+    /// Decompile this file rather than a named input.
+    #[arg(long, conflicts_with = "input")]
+    input_file: Option<PathBuf>,
+}
+
+pub fn decompile(args: DecompileArgs) -> Result<()> {
+    let input = match &args.input_file {
+        Some(path) => read_input_file(path)?,
+        None => InputResolver::from_env(args.data_dir).load(2021, 24, &args.input)?,
+    };
+    print!("{}", analysis::pseudocode(&Day24::parse(&input)?));
+    Ok(())
+}
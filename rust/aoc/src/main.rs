@@ -0,0 +1,151 @@
+//! One binary for every year's solutions.
+//!
+//!     $ cargo run -p aoc -- run --year 2022 --day 16 --part 2 --input final
+//!
+//! Leave out `--day` to run a whole year, `--year` to run everything, and
+//! `--part` to run both parts.
+//!
+//! `aoc verify` runs the same selection against every input and compares the
+//! answers with the ones recorded in `<data root>/<year>/answers.toml`, and
+//! `aoc bench` times parsing and each part (`--format csv` or `json` for a
+//! report to compare across commits).
+//!
+//! `aoc new --year 2024 --day 3 --part1 161` starts a day from the template,
+//! with empty input files and the example answer recorded for its test and
+//! for `verify`.
+//!
+//! `aoc decompile` prints 2021 day 24's MONAD program as Rust.
+//!
+//! Inputs are read at runtime from the data directory (`rust/data` unless
+//! `--data-dir` or `AOC_DATA_DIR` says otherwise), or from `--input-file`.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use aoc_common::{input::read_input_file, Day, InputResolver, Part, Unimplemented};
+use clap::{Parser, Subcommand};
+use strum::IntoEnumIterator;
+
+mod bench;
+mod decompile;
+mod scaffold;
+mod verify;
+
+const YEARS: &[(u16, &[Day])] = &[
+    (2021, adventofcode2021::DAYS),
+    (2022, adventofcode2022::DAYS),
+    (2023, adventofcode2023::DAYS),
+];
+
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run one day, a whole year, or everything.
+    Run(RunArgs),
+    /// Check solutions against the recorded answers.
+    Verify(verify::VerifyArgs),
+    /// Time parsing and each part over repeated runs.
+    Bench(bench::BenchArgs),
+    /// Start a new day from the template.
+    New(scaffold::NewArgs),
+    /// Print 2021 day 24's MONAD program as Rust.
+    Decompile(decompile::DecompileArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: Option<u8>,
+    #[arg(long)]
+    part: Option<Part>,
+    #[arg(long, default_value = "final")]
+    input: String,
+    /// Read inputs from this directory rather than `AOC_DATA_DIR` or the
+    /// default data root.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+    /// Run a single day against this file rather than a named input.
+    #[arg(long, conflicts_with = "input")]
+    input_file: Option<PathBuf>,
+}
+
+fn selected_days(year: Option<u16>, day: Option<u8>) -> Result<Vec<(u16, Day)>> {
+    if day.is_some() && year.is_none() {
+        bail!("--day needs --year");
+    }
+    let days: Vec<_> = YEARS
+        .iter()
+        .filter(|(y, _)| year.is_none_or(|year| year == *y))
+        .flat_map(|(y, days)| days.iter().map(move |d| (*y, *d)))
+        .filter(|(_, d)| day.is_none_or(|day| day == d.day))
+        .collect();
+    if days.is_empty() {
+        bail!("No solutions registered for the requested year/day");
+    }
+    Ok(days)
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::iter().collect(),
+    };
+    let days = selected_days(args.year, args.day)?;
+    if args.input_file.is_some() && days.len() != 1 {
+        bail!("--input-file needs a single --year and --day");
+    }
+    let resolver = InputResolver::from_env(args.data_dir);
+    let mut failures = 0;
+    for (year, day) in days {
+        let (label, input) = match &args.input_file {
+            Some(path) => (path.display().to_string(), read_input_file(path)),
+            None => (
+                args.input.clone(),
+                resolver.load(year, day.day, &args.input),
+            ),
+        };
+        println!("== {year} day {:02} ({label}) ==", day.day);
+        let answers = input.and_then(|input| day.solution.run(&input, &parts));
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(err) => {
+                println!("error: {err:#}");
+                failures += 1;
+                continue;
+            }
+        };
+        for (part, answer) in parts.iter().zip(answers) {
+            match answer {
+                Ok(answer) => println!("{part}: {answer}"),
+                Err(err) if err.is::<Unimplemented>() => println!("{part}: unimplemented"),
+                Err(err) => {
+                    println!("{part}: error: {err:#}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} run(s) failed");
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::Run(run_args) => run(run_args),
+        Command::Verify(verify_args) => verify::verify(verify_args),
+        Command::Bench(bench_args) => bench::bench(bench_args),
+        Command::New(new_args) => scaffold::new(new_args),
+        Command::Decompile(decompile_args) => decompile::decompile(decompile_args),
+    }
+}
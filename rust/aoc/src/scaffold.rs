@@ -0,0 +1,459 @@
+//! `aoc new`: start a day from the template in `aoc2023/template`, with
+//! empty input files, the example answers recorded, and the day registered
+//! with its year's crate (which is created, and added to the runner, for a
+//! new year).
+
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Answer, InputResolver};
+
+/// The Cargo workspace this binary was built from.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const TEMPLATE: &str = include_str!("../../aoc2023/template/mod.rs");
+
+#[derive(Debug, clap::Args)]
+pub struct NewArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The answer to part 1 for the example input, if known.
+    #[arg(long)]
+    part1: Option<Answer>,
+    /// The answer to part 2 for the example input, if known.
+    #[arg(long)]
+    part2: Option<Answer>,
+    /// Create the inputs and answers in this directory rather than
+    /// `AOC_DATA_DIR` or the default data root.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+    /// The Cargo workspace to add the day to.
+    #[arg(long, default_value = WORKSPACE_DIR)]
+    workspace_dir: PathBuf,
+}
+
+/// An answer as a Rust literal, for the generated tests.
+fn literal(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => format!("{s:?}"),
+    }
+}
+
+/// The Rust type for a part whose example answer is `answer`: text is a
+/// `String`, and a number is unsigned unless it's negative.  An unknown
+/// answer gets `u64`, the most common kind.
+fn answer_type(answer: Option<&Answer>) -> &'static str {
+    match answer {
+        Some(Answer::Text(_)) => "String",
+        Some(Answer::Number(n)) if *n < 0 => "i64",
+        Some(Answer::Number(_)) | None => "u64",
+    }
+}
+
+/// The template's test for `part` (`"part1"` or `"part2"`), with its
+/// trailing blank line.
+fn template_test(template: &str, part: &str) -> Result<(usize, usize)> {
+    let start = template
+        .find(&format!("    #[test]\n    fn test_{part}()"))
+        .with_context(|| format!("The template has no test_{part}"))?;
+    let end = start
+        + template[start..]
+            .find("\n    }\n")
+            .context("Unterminated test in the template")?
+        + "\n    }\n".len();
+    let end = if template[end..].starts_with('\n') {
+        end + 1
+    } else {
+        end
+    };
+    Ok((start, end))
+}
+
+/// The new day's `mod.rs`: the template with the day filled in, and tests
+/// for whichever example answers are known.
+fn render_day(
+    year: u16,
+    day: u8,
+    part1: Option<&Answer>,
+    part2: Option<&Answer>,
+) -> Result<String> {
+    let mut text = TEMPLATE
+        .replace("PART1_TYPE", answer_type(part1))
+        .replace("PART2_TYPE", answer_type(part2));
+    if part1.is_none() && part2.is_none() {
+        let tests = text
+            .find("#[cfg(test)]")
+            .context("The template has no tests")?;
+        text.truncate(tests);
+        text.truncate(text.trim_end().len() + 1);
+    } else {
+        // Remove from the end first so the earlier offsets stay put.
+        for (part, answer, placeholder) in [("part2", part2, "PART2"), ("part1", part1, "PART1")] {
+            match answer {
+                Some(answer) => text = text.replace(placeholder, &literal(answer)),
+                None => {
+                    let (start, end) = template_test(&text, part)?;
+                    text.replace_range(start..end, "");
+                }
+            }
+        }
+        text = text.replace("    }\n\n}\n", "    }\n}\n");
+    }
+    Ok(text
+        .replace("DayNN", &format!("Day{day:02}"))
+        .replace("dayNN", &format!("day{day:02}"))
+        .replace("YYYY", &year.to_string()))
+}
+
+/// Inserts `line` among the lines that `key` recognises, keeping them
+/// sorted by key.  Fails if no line is recognised, or one already has
+/// `new_key`.
+fn insert_sorted(
+    text: &str,
+    line: &str,
+    new_key: u32,
+    key: impl Fn(&str) -> Option<u32>,
+) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key(l)?)))
+        .collect();
+    if keyed.iter().any(|&(_, k)| k == new_key) {
+        bail!("{} is already there", line.trim());
+    }
+    let at = match keyed.iter().find(|&&(_, k)| k > new_key) {
+        Some(&(i, _)) => i,
+        None => match keyed.last() {
+            Some(&(i, _)) => i + 1,
+            None => bail!("Nowhere to put {}", line.trim()),
+        },
+    };
+    let mut out = String::new();
+    for (i, l) in lines.iter().enumerate() {
+        if i == at {
+            writeln!(out, "{line}")?;
+        }
+        writeln!(out, "{l}")?;
+    }
+    if at == lines.len() {
+        writeln!(out, "{line}")?;
+    }
+    Ok(out)
+}
+
+/// The number in `line` between `prefix` and `suffix`, if it has that shape.
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .split(suffix)
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Adds `day` to a year crate's `lib.rs`: its module and its `DAYS` entry.
+fn register_day(lib_rs: &str, day: u8) -> Result<String> {
+    let text = insert_sorted(lib_rs, &format!("pub mod day{day:02};"), day.into(), |l| {
+        number_between(l, "pub mod day", ";")
+    })?;
+    insert_sorted(
+        &text,
+        &format!("    Day::new({day}, &day{day:02}::Day{day:02}),"),
+        day.into(),
+        |l| number_between(l, "    Day::new(", ","),
+    )
+}
+
+/// A `lib.rs` for a year with just `day` in it.
+fn new_year_lib(year: u16, day: u8) -> String {
+    format!(
+        "//! Solutions for Advent of Code {year}.\n\
+         \n\
+         use aoc_common::Day;\n\
+         \n\
+         pub mod day{day:02};\n\
+         \n\
+         pub const DAYS: &[Day] = &[\n    Day::new({day}, &day{day:02}::Day{day:02}),\n];\n"
+    )
+}
+
+fn new_year_manifest(year: u16) -> String {
+    format!(
+        "[package]\n\
+         name = \"adventofcode{year}\"\n\
+         version = \"0.1.0\"\n\
+         edition = \"2021\"\n\
+         \n\
+         [dependencies]\n\
+         anyhow = {{ version = \"1.0.66\", features = [\"backtrace\"] }}\n\
+         aoc-common = {{ path = \"../aoc-common\" }}\n"
+    )
+}
+
+/// Adds `aoc<year>` to the workspace's `members`.
+fn add_workspace_member(manifest: &str, year: u16) -> Result<String> {
+    let start = manifest
+        .find("members = [")
+        .context("No workspace members")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .context("Unterminated members")?;
+    let member = format!("\"aoc{year}\"");
+    if manifest[start..end].contains(&member) {
+        bail!("aoc{year} is already a workspace member");
+    }
+    Ok(format!(
+        "{}, {member}{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Adds the year's crate to the runner's dependencies and its `YEARS`.
+fn add_runner_year(manifest: &str, main_rs: &str, year: u16) -> Result<(String, String)> {
+    let manifest = insert_sorted(
+        manifest,
+        &format!("adventofcode{year} = {{ path = \"../aoc{year}\" }}"),
+        year.into(),
+        |l| number_between(l, "adventofcode", " "),
+    )?;
+    let main_rs = insert_sorted(
+        main_rs,
+        &format!("    ({year}, adventofcode{year}::DAYS),"),
+        year.into(),
+        |l| number_between(l, "    (", ", adventofcode"),
+    )?;
+    Ok((manifest, main_rs))
+}
+
+/// Adds the example answers for `day` to a year's answers file, in day
+/// order.
+fn add_answers(
+    answers: &str,
+    day: u8,
+    part1: Option<&Answer>,
+    part2: Option<&Answer>,
+) -> Result<String> {
+    let header = format!("[day{day:02}.example]");
+    if answers.lines().any(|l| l.trim() == header) {
+        bail!("There are already example answers for day {day:02}");
+    }
+    let mut section = format!("{header}\n");
+    for (name, answer) in [("part1", part1), ("part2", part2)] {
+        if let Some(answer) = answer {
+            writeln!(section, "{name} = {}", answer.to_toml())?;
+        }
+    }
+    let later = answers
+        .lines()
+        .position(|l| number_between(l, "[day", ".").is_some_and(|d| d > day.into()));
+    Ok(match later {
+        Some(i) => {
+            let mut lines: Vec<&str> = answers.lines().collect();
+            let rest = lines.split_off(i);
+            format!("{}\n{section}\n{}\n", lines.join("\n"), rest.join("\n"))
+                .replace("\n\n\n", "\n\n")
+                .trim_start()
+                .to_string()
+        }
+        None if answers.trim().is_empty() => section,
+        None => format!("{}\n\n{section}", answers.trim_end()),
+    })
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+pub fn new(args: NewArgs) -> Result<()> {
+    let NewArgs {
+        year,
+        day,
+        part1,
+        part2,
+        data_dir,
+        workspace_dir,
+    } = args;
+    let resolver = InputResolver::from_env(data_dir);
+    let crate_dir = workspace_dir.join(format!("aoc{year}"));
+    let day_path = crate_dir.join(format!("src/day{day:02}/mod.rs"));
+    let lib_path = crate_dir.join("src/lib.rs");
+    let inputs = [
+        resolver.path(year, day, "example"),
+        resolver.path(year, day, "final"),
+    ];
+    let answers_path = resolver.answers_path(year);
+
+    // Work everything out before writing anything, so a clash leaves the
+    // tree untouched.
+    if day_path.parent().is_some_and(Path::exists) {
+        bail!("{} already exists", day_path.parent().unwrap().display());
+    }
+    if let Some(input) = inputs.iter().find(|path| path.exists()) {
+        bail!("{} already exists", input.display());
+    }
+    let mut files = vec![(
+        day_path,
+        render_day(year, day, part1.as_ref(), part2.as_ref())?,
+    )];
+    for input in inputs {
+        files.push((input, String::new()));
+    }
+    if part1.is_some() || part2.is_some() {
+        let answers = if answers_path.exists() {
+            read(&answers_path)?
+        } else {
+            String::new()
+        };
+        files.push((
+            answers_path.clone(),
+            add_answers(&answers, day, part1.as_ref(), part2.as_ref())?,
+        ));
+    }
+    let new_year = !crate_dir.exists();
+    if new_year {
+        let workspace_manifest = workspace_dir.join("Cargo.toml");
+        let runner_manifest = workspace_dir.join("aoc/Cargo.toml");
+        let runner_main = workspace_dir.join("aoc/src/main.rs");
+        let (manifest, main_rs) =
+            add_runner_year(&read(&runner_manifest)?, &read(&runner_main)?, year)?;
+        files.push((crate_dir.join("Cargo.toml"), new_year_manifest(year)));
+        files.push((lib_path, new_year_lib(year, day)));
+        files.push((
+            workspace_manifest.clone(),
+            add_workspace_member(&read(&workspace_manifest)?, year)?,
+        ));
+        files.push((runner_manifest, manifest));
+        files.push((runner_main, main_rs));
+    } else {
+        let lib_rs = register_day(&read(&lib_path)?, day)
+            .with_context(|| format!("Failed to register day {day} in {}", lib_path.display()))?;
+        files.push((lib_path, lib_rs));
+    }
+
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    if new_year {
+        println!("Rebuild the runner to pick up the new {year} crate.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_day() -> Result<()> {
+        let text = render_day(2024, 3, Some(&Answer::Number(161)), None)?;
+        assert!(text.contains("pub struct Day03;"));
+        assert!(text.contains("include_str!(\"../../../data/2024/day03/example_input\")"));
+        assert!(text.contains("let expected = 161;"));
+        assert!(text.contains("type Part1 = u64;") && text.contains("type Part2 = u64;"));
+        assert!(!text.contains("test_part2"));
+        assert!(!text.contains("NN") && !text.contains("YYYY") && !text.contains("PART"));
+        assert!(text.ends_with("    }\n}\n"));
+
+        let text = render_day(2024, 3, None, Some(&Answer::Text("ABC".to_string())))?;
+        assert!(!text.contains("test_part1"));
+        assert!(text.contains("let expected = \"ABC\";"));
+        assert!(text.contains("type Part1 = u64;") && text.contains("type Part2 = String;"));
+
+        let text = render_day(2024, 3, Some(&Answer::Number(-7)), None)?;
+        assert!(text.contains("let expected = -7;"));
+        assert!(text.contains("type Part1 = i64;"));
+
+        let text = render_day(2024, 3, None, None)?;
+        assert!(!text.contains("#[cfg(test)]"));
+        assert!(text.ends_with("}\n") && !text.ends_with("\n\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_day() -> Result<()> {
+        let lib_rs = new_year_lib(2024, 3);
+        let lib_rs = register_day(&lib_rs, 1)?;
+        let lib_rs = register_day(&lib_rs, 10)?;
+        assert_eq!(
+            lib_rs,
+            "//! Solutions for Advent of Code 2024.\n\
+             \n\
+             use aoc_common::Day;\n\
+             \n\
+             pub mod day01;\n\
+             pub mod day03;\n\
+             pub mod day10;\n\
+             \n\
+             pub const DAYS: &[Day] = &[\n\
+             \x20   Day::new(1, &day01::Day01),\n\
+             \x20   Day::new(3, &day03::Day03),\n\
+             \x20   Day::new(10, &day10::Day10),\n\
+             ];\n"
+        );
+        assert!(register_day(&lib_rs, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_year() -> Result<()> {
+        let workspace = "[workspace]\nmembers = [\"aoc\", \"aoc2023\"]\n";
+        assert_eq!(
+            add_workspace_member(workspace, 2024)?,
+            "[workspace]\nmembers = [\"aoc\", \"aoc2023\", \"aoc2024\"]\n"
+        );
+        assert!(add_workspace_member(workspace, 2023).is_err());
+
+        let manifest = "[dependencies]\n\
+                        adventofcode2023 = { path = \"../aoc2023\" }\n\
+                        serde = \"1.0\"\n";
+        let main_rs =
+            "const YEARS: &[(u16, &[Day])] = &[\n    (2023, adventofcode2023::DAYS),\n];\n";
+        let (manifest, main_rs) = add_runner_year(manifest, main_rs, 2024)?;
+        assert!(manifest.contains(
+            "adventofcode2023 = { path = \"../aoc2023\" }\n\
+             adventofcode2024 = { path = \"../aoc2024\" }\n\
+             serde"
+        ));
+        assert!(main_rs.contains("    (2024, adventofcode2024::DAYS),\n];"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_answers() -> Result<()> {
+        let answers = "[day01.example]\npart1 = 1\n\n[day05.final]\npart1 = 5\n";
+        let thirteen = Answer::Number(13);
+        assert_eq!(
+            add_answers(answers, 3, Some(&thirteen), None)?,
+            "[day01.example]\npart1 = 1\n\n[day03.example]\npart1 = 13\n\n[day05.final]\npart1 = 5\n"
+        );
+        assert_eq!(
+            add_answers(answers, 7, None, Some(&thirteen))?,
+            "[day01.example]\npart1 = 1\n\n[day05.final]\npart1 = 5\n\n[day07.example]\npart2 = 13\n"
+        );
+        assert_eq!(
+            add_answers("", 7, Some(&thirteen), None)?,
+            "[day07.example]\npart1 = 13\n"
+        );
+        assert!(add_answers(answers, 1, Some(&thirteen), None).is_err());
+        Ok(())
+    }
+}
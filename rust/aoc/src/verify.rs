@@ -0,0 +1,155 @@
+//! `aoc verify`: run solutions against the recorded answers and report what
+//! passed, failed or has nothing to compare against.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    path::PathBuf,
+};
+
+use anyhow::{bail, Result};
+use aoc_common::{input::input_order, Answers, InputResolver, MissingInput, Part, Unimplemented};
+use strum::IntoEnumIterator;
+
+use crate::selected_days;
+
+#[derive(Debug, clap::Args)]
+pub struct VerifyArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: Option<u8>,
+    /// Only check this input (e.g. `example` or `final`).
+    #[arg(long)]
+    input: Option<String>,
+    /// Read inputs and answers from this directory rather than
+    /// `AOC_DATA_DIR` or the default data root.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, strum::Display)]
+enum Status {
+    #[strum(to_string = "pass")]
+    Pass,
+    #[strum(to_string = "FAIL")]
+    Fail,
+    /// No recorded answer, or no input to run.
+    #[strum(to_string = "missing")]
+    Missing,
+    #[strum(to_string = "skip")]
+    Skip,
+}
+
+fn print_row(year: &str, day: &str, input: &str, part: &str, status: &str, detail: &str) {
+    println!("{year:<4}  {day:<3}  {input:<8}  {part:<5}  {status:<7}  {detail}");
+}
+
+/// Answers can span lines (rendered letters); keep each row on one line.
+fn one_line(answer: &str) -> String {
+    answer.trim().lines().collect::<Vec<_>>().join(" / ")
+}
+
+pub fn verify(args: VerifyArgs) -> Result<()> {
+    let days = selected_days(args.year, args.day)?;
+    let resolver = InputResolver::from_env(args.data_dir);
+    let mut answers: BTreeMap<u16, Answers> = BTreeMap::new();
+    let mut counts: BTreeMap<Status, usize> = BTreeMap::new();
+
+    print_row("year", "day", "input", "part", "status", "detail");
+    for (year, day) in days {
+        let answers = match answers.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Answers::load(&resolver.answers_path(year))?),
+        };
+
+        let mut inputs = resolver.inputs(year, day.day)?;
+        for name in answers.inputs(day.day) {
+            if !inputs.iter().any(|n| n == name) {
+                inputs.push(name.to_string());
+            }
+        }
+        inputs.sort_by_key(|name| input_order(name));
+        inputs.retain(|name| args.input.as_ref().is_none_or(|input| input == name));
+
+        for name in inputs {
+            let expected = answers.get(day.day, &name).cloned().unwrap_or_default();
+            let results: Vec<(Status, String)> = if let Some(reason) = &expected.skip {
+                Part::iter()
+                    .map(|_| (Status::Skip, reason.clone()))
+                    .collect()
+            } else {
+                match resolver
+                    .load(year, day.day, &name)
+                    .and_then(|input| day.solution.run(&input, &Part::iter().collect::<Vec<_>>()))
+                {
+                    Err(err) if err.is::<MissingInput>() => Part::iter()
+                        .map(|_| (Status::Missing, "no input file".to_string()))
+                        .collect(),
+                    Err(err) => Part::iter()
+                        .map(|_| {
+                            (
+                                Status::Fail,
+                                format!("error: {}", one_line(&format!("{err:#}"))),
+                            )
+                        })
+                        .collect(),
+                    Ok(actual) => Part::iter()
+                        .zip(actual)
+                        .map(|(part, actual)| match (expected.part(part), actual) {
+                            (Some(expected), Ok(actual)) if expected.matches(&actual) => {
+                                (Status::Pass, String::new())
+                            }
+                            (Some(expected), Ok(actual)) => (
+                                Status::Fail,
+                                format!(
+                                    "expected {}, got {}",
+                                    one_line(&expected.to_string()),
+                                    one_line(&actual)
+                                ),
+                            ),
+                            (Some(_), Err(err)) => (
+                                Status::Fail,
+                                format!("error: {}", one_line(&format!("{err:#}"))),
+                            ),
+                            (None, Ok(actual)) => {
+                                (Status::Missing, format!("got {}", one_line(&actual)))
+                            }
+                            (None, Err(err)) if err.is::<Unimplemented>() => {
+                                (Status::Skip, "unimplemented".to_string())
+                            }
+                            (None, Err(err)) => (
+                                Status::Missing,
+                                format!("error: {}", one_line(&format!("{err:#}"))),
+                            ),
+                        })
+                        .collect(),
+                }
+            };
+            for (part, (status, detail)) in Part::iter().zip(results) {
+                *counts.entry(status).or_default() += 1;
+                print_row(
+                    &year.to_string(),
+                    &format!("{:02}", day.day),
+                    &name,
+                    &part.to_string(),
+                    &status.to_string(),
+                    &detail,
+                );
+            }
+        }
+    }
+
+    let count = |status| counts.get(&status).copied().unwrap_or_default();
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing, {} skipped",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::Skip)
+    );
+    if count(Status::Fail) > 0 {
+        bail!("{} check(s) failed", count(Status::Fail));
+    }
+    Ok(())
+}
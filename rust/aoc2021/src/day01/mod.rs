@@ -0,0 +1,55 @@
+
+use anyhow::Result;
+use aoc_common::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input
+            .lines()
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(depths: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut previous: Option<u32> = None;
+        let mut increased_measurements = 0u32;
+        for &current in depths {
+            if let Some(previous) = previous {
+                if current > previous {
+                    increased_measurements += 1;
+                }
+            }
+            previous = Some(current);
+        }
+        Ok(increased_measurements)
+    }
+
+    fn part2(depths: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut windows: Vec<u32> = Vec::new();
+        for (i, &current) in depths.iter().enumerate() {
+            windows.push(current);
+            if i > 0 {
+                windows[i - 1] += current;
+            }
+            if i > 1 {
+                windows[i - 2] += current;
+            }
+        }
+
+        let mut increased_measurements = 0u32;
+        let mut previous = 0u32;
+        for (i, v) in windows[0..(windows.len() - 2)].iter().enumerate() {
+            if i > 0 && *v > previous {
+                increased_measurements += 1;
+            }
+            previous = *v;
+        }
+        Ok(increased_measurements)
+    }
+}
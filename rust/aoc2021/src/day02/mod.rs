@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+#[derive(Default, Debug)]
+struct Position {
+    horizontal: i32,
+    depth: i32,
+}
+
+#[derive(Default, Debug)]
+struct Transform {
+    position: Position,
+    aim: i32,
+}
+
+#[derive(Debug, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum Command {
+    Forward,
+    Up,
+    Down,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(Command, i32)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                let (command, val) = line.split_once(' ').context("Unable to parse command")?;
+                Ok((Command::from_str(command)?, val.parse::<i32>()?))
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut position = Position::default();
+        for (command, val) in commands {
+            match command {
+                Command::Forward => position.horizontal += val,
+                Command::Up => position.depth -= val,
+                Command::Down => position.depth += val,
+            }
+        }
+        Ok(position.horizontal * position.depth)
+    }
+
+    fn part2(commands: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut transform = Transform::default();
+        for (command, val) in commands {
+            match command {
+                Command::Forward => {
+                    transform.position.horizontal += val;
+                    transform.position.depth += val * transform.aim;
+                }
+                Command::Up => transform.aim -= val,
+                Command::Down => transform.aim += val,
+            }
+        }
+        Ok(transform.position.horizontal * transform.position.depth)
+    }
+}
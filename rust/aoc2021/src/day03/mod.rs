@@ -0,0 +1,135 @@
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+#[derive(Debug, Default)]
+pub struct Bits {
+    bits: Vec<Frequency>,
+}
+
+impl Bits {
+    pub fn new(num_bits: u32) -> Self {
+        let bits: Vec<Frequency> = vec![Frequency::default(); num_bits as usize];
+        Self { bits }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Frequency {
+    num_0: u32,
+    num_1: u32,
+}
+
+fn count_bits(lines: &[&str]) -> Result<Bits> {
+    let num_bits = lines.first().context("Empty input")?.len() as u32;
+    let mut bits = Bits::new(num_bits);
+    for line in lines {
+        for (i, char) in line.chars().enumerate() {
+            match char {
+                '0' => bits.bits[i].num_0 += 1,
+                '1' => bits.bits[i].num_1 += 1,
+                _ => {
+                    panic!("Unknown value")
+                }
+            }
+        }
+    }
+    Ok(bits)
+}
+
+fn to_number(s: &str) -> u32 {
+    let mut n = 0u32;
+    for (i, v) in s.chars().rev().enumerate() {
+        let v = match v {
+            '0' => 0,
+            '1' => 1,
+            _ => {
+                panic!("Unknown value")
+            }
+        };
+        n += 2u32.pow(i as u32) * v;
+    }
+    n
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Result<Self::Part1> {
+        let bits = count_bits(lines)?;
+        let result: Vec<_> = bits
+            .bits
+            .iter()
+            .map(|f| if f.num_0 > f.num_1 { 0 } else { 1 })
+            .collect();
+        let mut gamma = 0u32;
+        let mut epsilon = 0u32;
+        for (i, v) in result.iter().rev().enumerate() {
+            gamma += 2u32.pow(i as u32) * *v;
+            epsilon += 2u32.pow(i as u32) * (1 - *v);
+        }
+        Ok(gamma * epsilon)
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Result<Self::Part2> {
+        let bits = count_bits(lines)?;
+
+        let oxygen_string = {
+            let mut lines = lines.clone();
+            for i in 0..bits.bits.len() {
+                let mut f = Frequency::default();
+                for line in lines.iter() {
+                    let char = line.as_bytes()[i] as char;
+                    match char {
+                        '0' => f.num_0 += 1,
+                        '1' => f.num_1 += 1,
+                        _ => {
+                            panic!("Unknown value")
+                        }
+                    }
+                }
+
+                let target = if f.num_0 > f.num_1 { "0" } else { "1" };
+                lines.retain(|x| x.as_bytes()[i] == target.as_bytes()[0]);
+                if lines.len() == 1 {
+                    break;
+                }
+            }
+            lines[0]
+        };
+
+        let scrubber_string = {
+            let mut lines = lines.clone();
+            for i in 0..bits.bits.len() {
+                let mut f = Frequency::default();
+                for line in lines.iter() {
+                    let char = line.as_bytes()[i] as char;
+                    match char {
+                        '0' => f.num_0 += 1,
+                        '1' => f.num_1 += 1,
+                        _ => {
+                            panic!("Unknown value")
+                        }
+                    }
+                }
+
+                let target = if f.num_0 <= f.num_1 { "0" } else { "1" };
+                lines.retain(|x| x.as_bytes()[i] == target.as_bytes()[0]);
+                if lines.len() == 1 {
+                    break;
+                }
+            }
+            lines[0]
+        };
+
+        Ok(to_number(oxygen_string) * to_number(scrubber_string))
+    }
+}
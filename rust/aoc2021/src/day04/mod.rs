@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+#[derive(Debug)]
+struct Board {
+    values: [u32; 25],
+    winning_combos: Vec<HashSet<u32>>,
+}
+
+#[derive(Debug)]
+pub struct Bingo {
+    numbers_drawn: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+/// Plays every board to completion, returning the final scores of the first
+/// and last boards to win.
+fn play(bingo: &Bingo) -> Result<(u32, u32)> {
+    let boards = &bingo.boards;
+    let mut winning_board: Option<&Board> = None;
+    let mut losing_board: Option<&Board> = None;
+    let mut winning_number: Option<u32> = None;
+    let mut losing_number: Option<u32> = None;
+    let mut winning_numbers_drawn_set: HashSet<u32> = HashSet::new();
+    let mut numbers_drawn_set: HashSet<u32> = HashSet::new();
+    let mut winning_boards: HashSet<usize> = HashSet::new();
+    let all_boards: HashSet<usize> = HashSet::from_iter(0..boards.len());
+    for &number in &bingo.numbers_drawn {
+        losing_number = Some(number);
+        if winning_board.is_none() {
+            winning_numbers_drawn_set.insert(number);
+        }
+        numbers_drawn_set.insert(number);
+        for (i, board) in boards.iter().enumerate() {
+            if winning_boards.contains(&i) {
+                continue;
+            }
+            for winning_combo in &board.winning_combos {
+                if winning_combo.is_subset(&numbers_drawn_set) {
+                    if winning_board.is_none() {
+                        winning_board = Some(board);
+                        winning_number = Some(number);
+                    }
+                    winning_boards.insert(i);
+                }
+            }
+        }
+        let losing_boards: Vec<_> = all_boards.difference(&winning_boards).collect();
+        if losing_boards.len() == 1 {
+            losing_board = Some(&boards[*losing_boards[0]]);
+        }
+        if losing_boards.is_empty() {
+            break;
+        }
+    }
+
+    let winning_board = winning_board.context("Could not find winning board")?;
+    let winning_number = winning_number.context("Could not find winning number")?;
+    let losing_board = losing_board.context("Could not find losing board")?;
+    let losing_number = losing_number.context("Could not find losing number")?;
+
+    let winning_total = {
+        let board_values_set = HashSet::from(winning_board.values);
+        let unmarked_values = board_values_set.difference(&winning_numbers_drawn_set);
+        let unmarked_sum = unmarked_values.fold(0, |acc, v| acc + *v);
+        unmarked_sum * winning_number
+    };
+
+    let losing_total = {
+        let board_values_set = HashSet::from(losing_board.values);
+        let unmarked_values = board_values_set.difference(&numbers_drawn_set);
+        let unmarked_sum = unmarked_values.fold(0, |acc, v| acc + *v);
+        unmarked_sum * losing_number
+    };
+
+    Ok((winning_total, losing_total))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut lines = input.lines();
+
+        let numbers_drawn = lines
+            .next()
+            .context("Missing initial numbers")?
+            .split(',')
+            .map(|number| number.parse::<u32>())
+            .collect::<Result<_, _>>()?;
+
+        let mut boards: Vec<Board> = Vec::new();
+        while let Some(_blank) = lines.next() {
+            let mut values: [u32; 25] = [0; 25];
+            let mut winning_combos: Vec<HashSet<u32>> = Vec::new();
+            for li in 0..5 {
+                let line = lines.next().context("Partial board")?;
+                let mut line_values: [u32; 5] = [0; 5];
+                for (wi, sub) in line.split_whitespace().enumerate() {
+                    line_values[wi] = sub.parse::<u32>()?;
+                }
+                {
+                    let (_, right) = values.split_at_mut(li * 5);
+                    right[..5].copy_from_slice(&line_values);
+                }
+                winning_combos.push(HashSet::from(line_values));
+            }
+
+            for ci in 0..5 {
+                let c_values_vec = values.iter().skip(ci).step_by(5).map(|x| x.to_owned());
+                winning_combos.push(HashSet::from_iter(c_values_vec));
+            }
+            boards.push(Board {
+                values,
+                winning_combos,
+            });
+        }
+
+        Ok(Bingo {
+            numbers_drawn,
+            boards,
+        })
+    }
+
+    fn part1(bingo: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(play(bingo)?.0)
+    }
+
+    fn part2(bingo: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(play(bingo)?.1)
+    }
+}
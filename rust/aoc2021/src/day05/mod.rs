@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+#[derive(Debug, PartialEq)]
+pub struct Position(i32, i32);
+impl Position {
+    fn from_string(s: &str) -> Result<Self> {
+        let (x, y) = s.split_once(",").context("Couldn't parse coord")?;
+        Ok(Self(x.parse::<i32>()?, y.parse::<i32>()?))
+    }
+}
+
+fn count_overlaps(lines: &[(Position, Position)], diagonals: bool) -> usize {
+    let mut grid: HashMap<(i32, i32), u32> = HashMap::new();
+
+    for (start, end) in lines {
+        let x_delta: i32 = (end.0 - start.0).signum();
+        let y_delta: i32 = (end.1 - start.1).signum();
+
+        if !diagonals && x_delta != 0 && y_delta != 0 {
+            continue;
+        }
+
+        let mut current = Position(start.0, start.1);
+        loop {
+            *grid.entry((current.0, current.1)).or_default() += 1;
+            if current == *end {
+                break;
+            }
+            current = Position(current.0 + x_delta, current.1 + y_delta);
+        }
+    }
+
+    grid.values().filter(|x| **x > 1).count()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<(Position, Position)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                let (left, right) = line.split_once(" -> ").context("Couldn't parse line")?;
+                Ok((Position::from_string(left)?, Position::from_string(right)?))
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Result<Self::Part1> {
+        // Skip diagonals for part 1
+        Ok(count_overlaps(lines, false))
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(count_overlaps(lines, true))
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_common::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input
+            .trim()
+            .split(',')
+            .map(|x| x.parse())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(initial_state: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut state: Vec<i32> = initial_state.clone();
+        for _ in 0..80 {
+            let mut new: Vec<i32> = Vec::new();
+            for i in state.iter_mut() {
+                *i -= 1;
+                if *i == -1 {
+                    *i = 6;
+                    new.push(8);
+                }
+            }
+            state.append(&mut new);
+        }
+
+        Ok(state.len())
+    }
+
+    fn part2(initial_state: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut num_fish_by_timer: HashMap<i32, i64> = HashMap::new();
+        for &i in initial_state {
+            num_fish_by_timer
+                .entry(i)
+                .and_modify(|e| *e += 1)
+                .or_insert(1);
+        }
+
+        for _ in 0..256 {
+            let mut new_state: HashMap<i32, i64> = HashMap::new();
+            for (k, v) in num_fish_by_timer {
+                if k == 0 {
+                    *new_state.entry(6).or_default() += v;
+                    *new_state.entry(8).or_default() += v;
+                } else {
+                    *new_state.entry(k - 1).or_default() += v;
+                }
+            }
+            num_fish_by_timer = new_state;
+        }
+
+        Ok(num_fish_by_timer.values().sum::<i64>())
+    }
+}
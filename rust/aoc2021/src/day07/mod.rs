@@ -0,0 +1,52 @@
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+/// The cheapest total fuel to line every crab up on one position, given the
+/// fuel a single crab spends moving `distance`.
+fn best_fuel(crabs: &[i32], fuel_for: impl Fn(i32) -> i32) -> Result<i32> {
+    let max = *crabs.iter().max().context("No crabs")?;
+    let min = *crabs.iter().min().context("No crabs")?;
+
+    let mut best_fuel: Option<i32> = None;
+    for option in min..=max {
+        let mut sum_option = 0;
+        for crab in crabs.iter() {
+            let distance = (*crab - option).abs();
+            sum_option += fuel_for(distance);
+        }
+        match best_fuel {
+            None => best_fuel = Some(sum_option),
+            Some(current) => {
+                if current > sum_option {
+                    best_fuel = Some(sum_option);
+                }
+            }
+        }
+    }
+    best_fuel.context("No positions to try")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input
+            .trim()
+            .split(',')
+            .map(|x| x.parse())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(crabs: &Self::Input<'_>) -> Result<Self::Part1> {
+        best_fuel(crabs, |distance| distance)
+    }
+
+    fn part2(crabs: &Self::Input<'_>) -> Result<Self::Part2> {
+        best_fuel(crabs, |distance| (distance * (distance + 1)) / 2)
+    }
+}
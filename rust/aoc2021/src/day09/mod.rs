@@ -0,0 +1,77 @@
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use aoc_common::{Grid, Solution};
+
+fn part1(height_map: &Grid<usize>) -> usize {
+    let mut risk = 0;
+    for (pos, &i) in height_map.iter() {
+        let low_point = height_map
+            .neighbours4(pos)
+            .all(|neighbour| i < height_map[neighbour]);
+        if low_point {
+            risk += i + 1;
+        }
+    }
+    risk
+}
+
+fn part2(height_map: &Grid<usize>) -> Result<usize> {
+    let mut assigned = HashSet::new();
+    let mut basin_sizes = BinaryHeap::new();
+    for (n, &i) in height_map.iter() {
+        if assigned.contains(&n) {
+            continue;
+        }
+        if i == 9 {
+            // not actually assigned, but ignore it in future
+            assigned.insert(n);
+            continue;
+        }
+        let mut basin = HashSet::new();
+        let mut unexplored = HashSet::new();
+        unexplored.insert(n);
+        while let Some(n) = unexplored.iter().next().copied() {
+            basin.insert(n);
+            assigned.insert(n);
+            unexplored.remove(&n);
+
+            for option in height_map.neighbours4(n) {
+                if assigned.contains(&option) {
+                    continue;
+                }
+                if height_map[option] == 9 {
+                    assigned.insert(option);
+                    continue;
+                }
+                unexplored.insert(option);
+            }
+        }
+        basin_sizes.push(basin.len());
+    }
+    let mut top = || basin_sizes.pop().context("Fewer than three basins");
+    Ok(top()? * top()? * top()?)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |x| {
+            Ok(x.to_digit(10).context("Not a digit")? as usize)
+        })
+    }
+
+    fn part1(height_map: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(height_map))
+    }
+
+    fn part2(height_map: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(height_map)
+    }
+}
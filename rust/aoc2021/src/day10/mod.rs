@@ -1,44 +1,36 @@
-// cargo run --example day10 -- (part1|part2) (example_input|final_input)
-
 use std::collections::HashMap;
-use std::fs::read_to_string;
-
-use anyhow::Result;
-use structopt::StructOpt;
 
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
 
-#[derive(Debug, StructOpt)]
-#[structopt(name = "day10", about = "Advent of Code 2021 Day 9")]
-pub struct Args {
-    part: Part,
-    input: Input,
+/// Reads each line of the navigation subsystem as its brackets.
+fn parse(contents: &str) -> Result<Vec<Vec<char>>> {
+    contents
+        .lines()
+        .map(|line| {
+            line.trim()
+                .chars()
+                .map(|c| match c {
+                    '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Ok(c),
+                    _ => bail!("Unexpected {c:?} in line {line}"),
+                })
+                .collect()
+        })
+        .collect()
 }
 
-fn solve(file_path: &str) -> Result<()> {
-    let contents = read_to_string(file_path)?;
+/// Returns the total syntax error score and the middle completion score.
+fn solve(lines: &[Vec<char>]) -> Result<(i32, i64)> {
     let mut ascore = 0;
     let mut bscores = Vec::new();
     let matching_map = HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
     let score_map = HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
     let completion_score_map = HashMap::from([('(', 1), ('[', 2), ('{', 3), ('<', 4)]);
-    for line in contents.lines() {
+    for line in lines {
         let mut line_score = 0;
 
         let mut stack = Vec::new();
-        'a: for char in line.trim().chars() {
+        'a: for &char in line {
             if matching_map.contains_key(&char) {
                 stack.push(char);
             } else {
@@ -70,21 +62,28 @@ fn solve(file_path: &str) -> Result<()> {
         ascore += line_score;
     }
 
-    println!("Part1: {}", ascore);
     bscores.sort_unstable();
-    println!("Part 2: {}", bscores[bscores.len() / 2]);
+    let bscore = *bscores.get(bscores.len() / 2).context("No incomplete lines")?;
 
-    Ok(())
+    Ok((ascore, bscore))
 }
 
-fn main() -> Result<()> {
-    let args = Args::from_args_safe()?;
-    println!("Args: {:#?}", args);
+pub struct Day10;
 
-    let file_path = match args.input {
-        Input::ExampleInput => "data/day10/example_input",
-        Input::FinalInput => "data/day10/input",
-    };
+impl Solution for Day10 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = i32;
+    type Part2 = i64;
 
-    solve(file_path)
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(solve(input)?.0)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(solve(input)?.1)
+    }
 }
@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use aoc_common::{Grid, Solution};
+
+/// Returns the number of flashes in the first 100 steps and the first step
+/// on which every octopus flashes.
+fn solve(grid: &Grid<u32>) -> Result<(usize, i32)> {
+    let mut grid = grid.clone();
+
+    let mut total_flashes = 0;
+    let mut explosions = Vec::new();
+    let mut n = 0;
+    loop {
+        n += 1;
+        let mut flashes = 0;
+
+        for (pos, v) in grid.iter_mut() {
+            *v += 1;
+            if *v > 9 {
+                explosions.push(pos);
+            }
+        }
+
+        let mut exploded = HashSet::new();
+        while let Some(ep) = explosions.pop() {
+            if exploded.contains(&ep) {
+                continue;
+            }
+            flashes += 1;
+            exploded.insert(ep);
+            for pos in grid.neighbours8(ep).collect::<Vec<_>>() {
+                if exploded.contains(&pos) {
+                    continue;
+                }
+
+                let v = &mut grid[pos];
+                *v += 1;
+                if *v > 9 {
+                    explosions.push(pos);
+                    *v = 0;
+                }
+            }
+        }
+
+        for e in exploded {
+            grid[e] = 0;
+        }
+
+        if n <= 100 {
+            total_flashes += flashes;
+        }
+
+        if flashes == grid.width() * grid.height() {
+            break;
+        }
+    }
+
+    Ok((total_flashes, n))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| c.to_digit(10).context("Not a digit"))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(solve(input)?.0)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(solve(input)?.1)
+    }
+}
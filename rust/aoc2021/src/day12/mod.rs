@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+fn is_big(s: &str) -> bool {
+    s.chars().next().unwrap().is_uppercase()
+}
+
+struct StackEntry<'a> {
+    loc: &'a str,
+    path: Vec<&'a str>,
+    visited: HashSet<&'a str>,
+    double_visit: bool,
+}
+
+type Adjacency<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn count_paths(adjacency: &Adjacency, allow_double_visit: bool) -> usize {
+    let mut stack = Vec::from([StackEntry {
+        loc: "start",
+        path: Vec::from(["start"]),
+        visited: HashSet::from(["start"]),
+        double_visit: allow_double_visit,
+    }]);
+
+    let mut paths: Vec<Vec<&str>> = Vec::new();
+    while let Some(entry) = stack.pop() {
+        for &option in &adjacency[entry.loc] {
+            if option == "start" {
+                continue;
+            }
+            if option == "end" {
+                paths.push(entry.path.clone());
+                continue;
+            }
+            let mut visited = entry.visited.clone();
+            let mut double_visit = entry.double_visit;
+            if !is_big(option) {
+                if visited.contains(option) {
+                    if double_visit {
+                        double_visit = false;
+                    } else {
+                        continue;
+                    }
+                }
+                visited.insert(option);
+            }
+            let mut path = entry.path.clone();
+            path.push(option);
+            stack.push(StackEntry {
+                loc: option,
+                path,
+                visited,
+                double_visit,
+            })
+        }
+    }
+
+    paths.len()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Adjacency<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut adjacency: Adjacency = HashMap::new();
+        for line in input.lines() {
+            let (left, right) = line.split_once('-').context("Missing -")?;
+            adjacency.entry(left).or_default().push(right);
+            adjacency.entry(right).or_default().push(left);
+        }
+        Ok(adjacency)
+    }
+
+    fn part1(adjacency: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(count_paths(adjacency, false))
+    }
+
+    fn part2(adjacency: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(count_paths(adjacency, true))
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
+
+pub enum Axis {
+    X,
+    Y,
+}
+
+fn do_fold(pos: &(i32, i32), fold: &(Axis, i32)) -> (i32, i32) {
+    match fold.0 {
+        Axis::X => {
+            if pos.0 > fold.1 {
+                (fold.1 - (pos.0 - fold.1), pos.1)
+            } else {
+                *pos
+            }
+        }
+        Axis::Y => {
+            if pos.1 > fold.1 {
+                (pos.0, fold.1 - (pos.1 - fold.1))
+            } else {
+                *pos
+            }
+        }
+    }
+}
+
+pub struct Manual {
+    dots: HashSet<(i32, i32)>,
+    folds: Vec<(Axis, i32)>,
+}
+
+fn fold_all(dots: &mut HashSet<(i32, i32)>, fold: &(Axis, i32)) {
+    for pos in dots.clone() {
+        let new_pos = do_fold(&pos, fold);
+        if new_pos != pos {
+            dots.remove(&pos);
+            dots.insert(new_pos);
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Manual;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut dots = HashSet::new();
+        let mut in_fold = false;
+        let mut folds = Vec::new();
+        for line in input.lines() {
+            if line.is_empty() {
+                in_fold = true;
+                continue;
+            }
+            if !in_fold {
+                let (x, y) = line.split_once(',').context("Missing ,")?;
+                let x = x.parse::<i32>()?;
+                let y = y.parse::<i32>()?;
+                dots.insert((x, y));
+            } else {
+                let instr = line.split(' ').nth(2).context("Missing fold")?;
+                let (axis, number) = instr.split_once('=').context("Missing =")?;
+                let number = number.parse::<i32>()?;
+                let axis = match axis {
+                    "x" => Axis::X,
+                    "y" => Axis::Y,
+                    _ => bail!("No such axis {axis}"),
+                };
+                folds.push((axis, number));
+            }
+        }
+        Ok(Manual { dots, folds })
+    }
+
+    fn part1(manual: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut dots = manual.dots.clone();
+        fold_all(&mut dots, manual.folds.first().context("No folds")?);
+        Ok(dots.len())
+    }
+
+    fn part2(manual: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut dots = manual.dots.clone();
+        for fold in &manual.folds {
+            fold_all(&mut dots, fold);
+        }
+
+        let (max_x, max_y) = dots
+            .iter()
+            .copied()
+            .reduce(|a, b| (a.0.max(b.0), a.1.max(b.1)))
+            .context("No dots")?;
+
+        let mut output = String::new();
+        for y in 0..=max_y {
+            output.push('\n');
+            for x in 0..=max_x {
+                let c = match dots.get(&(x, y)) {
+                    Some(_) => '#',
+                    None => '.',
+                };
+                output.push(c);
+            }
+        }
+        Ok(output)
+    }
+}
@@ -1,31 +1,7 @@
-// cargo run --example day14 -- (part1|part2) (example_input|final_input)
-
 use std::collections::HashMap;
-use std::fs::read_to_string;
-
-use anyhow::Result;
-use structopt::StructOpt;
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
 
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
-
-#[derive(Debug, StructOpt)]
-#[structopt(name = "day14", about = "Advent of Code 2021 Day 9")]
-pub struct Args {
-    part: Part,
-    input: Input,
-}
+use anyhow::{Context, Result};
+use aoc_common::Solution;
 
 fn str_to_pair_counts(s: &str) -> HashMap<String, i64> {
     let mut prev: Option<char> = None;
@@ -63,37 +39,28 @@ fn result_from_pair_counts(h: &HashMap<String, i64>, last_char: char) -> i64 {
     let mut elements_with_counts: Vec<_> = element_count.iter().map(|x| (*x.0, *x.1)).collect();
     elements_with_counts.sort_unstable_by_key(|x| x.1);
     let biggest = elements_with_counts.iter().last().unwrap();
-    let smallest = elements_with_counts.get(0).unwrap();
+    let smallest = elements_with_counts.first().unwrap();
 
     biggest.1 - smallest.1
 }
 
-fn solve(file_path: &str) -> Result<()> {
-    let contents = read_to_string(file_path)?;
+pub struct Polymer<'a> {
+    start: &'a str,
+    pair_insertion: HashMap<String, String>,
+}
 
-    let mut past_break = false;
-    let start = contents.lines().next().unwrap();
-    let mut pair_insertion = HashMap::new();
-    for line in contents.lines() {
-        if line.trim() == "" {
-            past_break = true;
-            continue;
-        }
+fn polymerise(polymer: &Polymer, steps: usize) -> Result<i64> {
+    let last_char = polymer.start.chars().last().context("Empty template")?;
 
-        if past_break {
-            let (pair, element) = line.split_once("->").unwrap();
-            pair_insertion.insert(pair.trim().to_string(), element.trim().to_string());
-        }
-    }
-    let last_char = start.chars().last().unwrap();
+    let mut pair_numbers = str_to_pair_counts(polymer.start);
 
-    let mut pair_numbers = str_to_pair_counts(start);
-    let mut part1_numbers = HashMap::new();
-
-    for step in 1..=40 {
+    for _ in 0..steps {
         let mut new_pair_numbers = HashMap::new();
         for (pair, number) in pair_numbers {
-            let inserted_char = pair_insertion.get(&pair).unwrap();
+            let inserted_char = polymer
+                .pair_insertion
+                .get(&pair)
+                .context("No insertion rule")?;
 
             {
                 let char1 = pair.chars().next().unwrap();
@@ -115,25 +82,44 @@ fn solve(file_path: &str) -> Result<()> {
             }
         }
         pair_numbers = new_pair_numbers;
-        if step == 10 {
-            part1_numbers = pair_numbers.clone();
-        }
     }
 
-    println!("Part 1: {}", result_from_pair_counts(&part1_numbers, last_char));
-    println!("Part 2: {}", result_from_pair_counts(&pair_numbers, last_char));
-
-    Ok(())
+    Ok(result_from_pair_counts(&pair_numbers, last_char))
 }
 
-fn main() -> Result<()> {
-    let args = Args::from_args_safe()?;
-    println!("Args: {:#?}", args);
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Polymer<'a>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut past_break = false;
+        let start = input.lines().next().context("Empty input")?;
+        let mut pair_insertion = HashMap::new();
+        for line in input.lines() {
+            if line.trim() == "" {
+                past_break = true;
+                continue;
+            }
+
+            if past_break {
+                let (pair, element) = line.split_once("->").context("Missing ->")?;
+                pair_insertion.insert(pair.trim().to_string(), element.trim().to_string());
+            }
+        }
+        Ok(Polymer {
+            start,
+            pair_insertion,
+        })
+    }
 
-    let file_path = match args.input {
-        Input::ExampleInput => "data/day14/example_input",
-        Input::FinalInput => "data/day14/input",
-    };
+    fn part1(polymer: &Self::Input<'_>) -> Result<Self::Part1> {
+        polymerise(polymer, 10)
+    }
 
-    solve(file_path)
+    fn part2(polymer: &Self::Input<'_>) -> Result<Self::Part2> {
+        polymerise(polymer, 40)
+    }
 }
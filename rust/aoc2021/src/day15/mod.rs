@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{search::dijkstra, Grid, Solution};
+
+/// The lowest total risk from the top left to the bottom right, with the
+/// cave tiled `multiple` times in each direction.
+fn solve(cave: &Grid<i32>, multiple: i32) -> Result<usize> {
+    if cave.width() == 0 {
+        bail!("Empty input");
+    }
+    let input_width = cave.width() as i32;
+    let input_height = cave.height() as i32;
+    let width = input_width * multiple;
+    let height = input_height * multiple;
+
+    let mut g = HashMap::new();
+
+    for ((x, y), &orig_cost) in cave.iter() {
+        for rx in 0..multiple {
+            for ry in 0..multiple {
+                let mut rcost = (orig_cost + rx + ry) % 9;
+                if rcost == 0 {
+                    rcost = 9;
+                }
+                let pos = (x as i32 + rx * input_width, y as i32 + ry * input_height);
+                g.insert(pos, rcost as usize);
+            }
+        }
+    }
+
+    let start = (0, 0);
+    let end = (width - 1, height - 1);
+
+    let path = dijkstra(
+        start,
+        |&(x, y)| {
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter_map(|n_pos| Some((n_pos, *g.get(&n_pos)?)))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == end,
+    )
+    .context("No path to the bottom right")?;
+    Ok(path.cost)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Grid<i32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| Ok(c.to_digit(10).context("Not a digit")? as i32))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        solve(input, 1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        solve(input, 5)
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+pub mod packet;
+
+use packet::Packet;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Packet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().next().context("Empty input")?.parse()
+    }
+
+    fn part1(packet: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(packet.version_sum())
+    }
+
+    fn part2(packet: &Self::Input<'_>) -> Result<Self::Part2> {
+        packet.evaluate()
+    }
+}
@@ -0,0 +1,403 @@
+//! BITS transmissions: decoding hex to a packet tree, encoding it back, and
+//! evaluating the expression it describes.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+
+const LITERAL_TYPE_ID: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    Greater,
+    Less,
+    Equal,
+}
+
+impl Operator {
+    fn from_type_id(type_id: u8) -> Option<Operator> {
+        Some(match type_id {
+            0 => Operator::Sum,
+            1 => Operator::Product,
+            2 => Operator::Minimum,
+            3 => Operator::Maximum,
+            5 => Operator::Greater,
+            6 => Operator::Less,
+            7 => Operator::Equal,
+            _ => return None,
+        })
+    }
+
+    fn type_id(self) -> u8 {
+        match self {
+            Operator::Sum => 0,
+            Operator::Product => 1,
+            Operator::Minimum => 2,
+            Operator::Maximum => 3,
+            Operator::Greater => 5,
+            Operator::Less => 6,
+            Operator::Equal => 7,
+        }
+    }
+
+    /// The infix symbol, for the operators written between their operands.
+    fn symbol(self) -> Option<&'static str> {
+        match self {
+            Operator::Sum => Some("+"),
+            Operator::Product => Some("*"),
+            Operator::Greater => Some(">"),
+            Operator::Less => Some("<"),
+            Operator::Equal => Some("=="),
+            Operator::Minimum | Operator::Maximum => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Operator::Sum => "sum",
+            Operator::Product => "product",
+            Operator::Minimum => "min",
+            Operator::Maximum => "max",
+            Operator::Greater => "gt",
+            Operator::Less => "lt",
+            Operator::Equal => "eq",
+        }
+    }
+
+    fn apply(self, values: &[u64]) -> Result<u64> {
+        let compare = |f: fn(&u64, &u64) -> bool| match values {
+            [a, b] => Ok(f(a, b) as u64),
+            _ => bail!("{} takes two operands, got {}", self.name(), values.len()),
+        };
+        match self {
+            Operator::Sum => values
+                .iter()
+                .try_fold(0_u64, |acc, &v| acc.checked_add(v))
+                .context("Sum overflowed"),
+            Operator::Product => values
+                .iter()
+                .try_fold(1_u64, |acc, &v| acc.checked_mul(v))
+                .context("Product overflowed"),
+            Operator::Minimum => values.iter().copied().min().context("min of nothing"),
+            Operator::Maximum => values.iter().copied().max().context("max of nothing"),
+            Operator::Greater => compare(u64::gt),
+            Operator::Less => compare(u64::lt),
+            Operator::Equal => compare(u64::eq),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketKind {
+    Literal(u64),
+    Operator(Operator, Vec<Packet>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub version: u8,
+    pub kind: PacketKind,
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: u64) -> Packet {
+        Packet {
+            version,
+            kind: PacketKind::Literal(value),
+        }
+    }
+
+    pub fn operator(version: u8, operator: Operator, operands: Vec<Packet>) -> Packet {
+        Packet {
+            version,
+            kind: PacketKind::Operator(operator, operands),
+        }
+    }
+
+    /// The versions of this packet and every packet inside it, added up.
+    pub fn version_sum(&self) -> u64 {
+        self.version as u64
+            + match &self.kind {
+                PacketKind::Literal(_) => 0,
+                PacketKind::Operator(_, operands) => operands.iter().map(Packet::version_sum).sum(),
+            }
+    }
+
+    pub fn evaluate(&self) -> Result<u64> {
+        match &self.kind {
+            PacketKind::Literal(value) => Ok(*value),
+            PacketKind::Operator(operator, operands) => {
+                let values = operands
+                    .iter()
+                    .map(Packet::evaluate)
+                    .collect::<Result<Vec<_>>>()?;
+                operator.apply(&values)
+            }
+        }
+    }
+
+    /// The transmission for this packet, in hex, padded with zero bits to a
+    /// whole number of bytes.  Fails if a version doesn't fit in its 3 bits,
+    /// or an operator has too many operands to count in 11 bits.
+    pub fn to_hex(&self) -> Result<String> {
+        let mut writer = BitWriter::default();
+        self.write(&mut writer)?;
+        while writer.bits.len() % 8 != 0 {
+            writer.bits.push(false);
+        }
+        Ok(writer
+            .bits
+            .chunks(4)
+            .map(|nibble| {
+                let digit = nibble.iter().fold(0, |acc, &bit| acc << 1 | bit as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect())
+    }
+
+    fn write(&self, writer: &mut BitWriter) -> Result<()> {
+        writer
+            .push(self.version as u64, 3)
+            .context("Version too big")?;
+        match &self.kind {
+            PacketKind::Literal(value) => {
+                writer.push(LITERAL_TYPE_ID as u64, 3)?;
+                let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    writer.push((group > 0) as u64, 1)?;
+                    writer.push(value >> (4 * group) & 0xF, 4)?;
+                }
+            }
+            PacketKind::Operator(operator, operands) => {
+                writer
+                    .push(operator.type_id() as u64, 3)
+                    .context("Type ID too big")?;
+                let mut contents = BitWriter::default();
+                for operand in operands {
+                    operand.write(&mut contents)?;
+                }
+                // Give the length in bits when it fits, and the number of
+                // operands otherwise.
+                if contents.bits.len() < 1 << 15 {
+                    writer.push(0, 1)?;
+                    writer.push(contents.bits.len() as u64, 15)?;
+                } else {
+                    writer.push(1, 1)?;
+                    writer
+                        .push(operands.len() as u64, 11)
+                        .context("Too many operands")?;
+                }
+                writer.bits.extend(contents.bits);
+            }
+        }
+        Ok(())
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Packet> {
+        let version = reader.take(3)? as u8;
+        let type_id = reader.take(3)? as u8;
+        if type_id == LITERAL_TYPE_ID {
+            let mut value = 0_u64;
+            loop {
+                let more = reader.take(1)? == 1;
+                if value.leading_zeros() < 4 {
+                    bail!("Literal doesn't fit in 64 bits");
+                }
+                value = value << 4 | reader.take(4)?;
+                if !more {
+                    return Ok(Packet::literal(version, value));
+                }
+            }
+        }
+        let operator = Operator::from_type_id(type_id).unwrap();
+        let mut operands = vec![];
+        if reader.take(1)? == 0 {
+            let length = reader.take(15)? as usize;
+            let end = reader.position + length;
+            while reader.position < end {
+                operands.push(Packet::read(reader)?);
+            }
+            if reader.position != end {
+                bail!("Operands overran their length of {length} bits");
+            }
+        } else {
+            for _ in 0..reader.take(11)? {
+                operands.push(Packet::read(reader)?);
+            }
+        }
+        Ok(Packet::operator(version, operator, operands))
+    }
+}
+
+/// Decodes a transmission holding a single packet.  Anything after the
+/// packet must be zero padding.
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(hex: &str) -> Result<Packet> {
+        let mut bits = vec![];
+        for ch in hex.trim().chars() {
+            let digit = ch
+                .to_digit(16)
+                .with_context(|| format!("Not a hex digit: {ch:?}"))?;
+            bits.extend((0..4).rev().map(|i| digit >> i & 1 == 1));
+        }
+        let mut reader = BitReader { bits, position: 0 };
+        let packet = Packet::read(&mut reader)?;
+        if reader.bits[reader.position..].iter().any(|&bit| bit) {
+            bail!("Unexpected bits after the packet");
+        }
+        Ok(packet)
+    }
+}
+
+/// Writes the expression the packet stands for, e.g. `(1 + min(2, 3))`.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            PacketKind::Literal(value) => write!(f, "{value}"),
+            PacketKind::Operator(operator, operands) => {
+                let separator = match operator.symbol() {
+                    Some(symbol) if operands.len() >= 2 => {
+                        write!(f, "(")?;
+                        format!(" {symbol} ")
+                    }
+                    _ => {
+                        write!(f, "{}(", operator.name())?;
+                        ", ".to_string()
+                    }
+                };
+                for (i, operand) in operands.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{separator}")?;
+                    }
+                    write!(f, "{operand}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+struct BitReader {
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl BitReader {
+    fn take(&mut self, n: usize) -> Result<u64> {
+        let Some(bits) = self.bits.get(self.position..self.position + n) else {
+            bail!("Transmission ended in the middle of a packet");
+        };
+        self.position += n;
+        Ok(bits.iter().fold(0, |acc, &bit| acc << 1 | bit as u64))
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    /// Writes the low `n` bits of `value`, as long as that's all of it.
+    fn push(&mut self, value: u64, n: usize) -> Result<()> {
+        if value >> n != 0 {
+            bail!("{value} doesn't fit in {n} bits");
+        }
+        self.bits.extend((0..n).rev().map(|i| value >> i & 1 == 1));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> Result<()> {
+        assert_eq!("D2FE28".parse::<Packet>()?, Packet::literal(6, 2021));
+        let packet: Packet = "38006F45291200".parse()?;
+        assert_eq!(
+            packet,
+            Packet::operator(
+                1,
+                Operator::Less,
+                vec![Packet::literal(6, 10), Packet::literal(2, 20)]
+            )
+        );
+        assert_eq!(packet.to_string(), "(10 < 20)");
+        assert_eq!("8A004A801A8002F478".parse::<Packet>()?.version_sum(), 16);
+        assert!("D2FE2".parse::<Packet>().is_err());
+        assert!("D2FE29".parse::<Packet>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate() -> Result<()> {
+        for (hex, expression, value) in [
+            ("C200B40A82", "(1 + 2)", 3),
+            ("04005AC33890", "(6 * 9)", 54),
+            ("880086C3E88112", "min(7, 8, 9)", 7),
+            ("CE00C43D881120", "max(7, 8, 9)", 9),
+            ("D8005AC2A8F0", "(5 < 15)", 1),
+            ("F600BC2D8F", "(5 > 15)", 0),
+            ("9C005AC2F8F0", "(5 == 15)", 0),
+            ("9C0141080250320F1802104A08", "((1 + 3) == (2 * 2))", 1),
+        ] {
+            let packet: Packet = hex.parse()?;
+            assert_eq!(packet.to_string(), expression);
+            assert_eq!(packet.evaluate()?, value, "{hex}");
+        }
+        let lonely = Packet::operator(0, Operator::Equal, vec![Packet::literal(0, 1)]);
+        assert_eq!(lonely.to_string(), "eq(1)");
+        assert!(lonely.evaluate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let examples = include_str!("../../../data/2021/day16/example_input");
+        for hex in examples.lines().filter(|line| !line.is_empty()) {
+            let packet: Packet = hex.parse()?;
+            assert_eq!(packet.to_hex()?.parse::<Packet>()?, packet, "{hex}");
+        }
+        // A transmission built from scratch.
+        let packet = Packet::operator(
+            3,
+            Operator::Product,
+            vec![
+                Packet::literal(1, 0),
+                Packet::literal(2, u64::MAX),
+                Packet::operator(0, Operator::Sum, vec![]),
+            ],
+        );
+        let decoded: Packet = packet.to_hex()?.parse()?;
+        assert_eq!(decoded, packet);
+        assert_eq!(decoded.to_string(), "(0 * 18446744073709551615 * sum())");
+        assert_eq!(decoded.evaluate()?, 0);
+        assert_eq!(decoded.version_sum(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_overflow() -> Result<()> {
+        assert!(Packet::literal(7, 1).to_hex().is_ok());
+        assert!(Packet::literal(8, 1).to_hex().is_err());
+        let nested = Packet::operator(0, Operator::Sum, vec![Packet::literal(8, 1)]);
+        assert!(nested.to_hex().is_err());
+
+        // Too long to give the length in bits, so it counts the operands.
+        let operands = |n| vec![Packet::literal(0, u64::MAX); n];
+        let packet = Packet::operator(0, Operator::Sum, operands(2047));
+        assert_eq!(packet.to_hex()?.parse::<Packet>()?, packet);
+        assert!(Packet::operator(0, Operator::Sum, operands(2048))
+            .to_hex()
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+fn determine_hit(vel: (i32, i32), target: &(RangeInclusive<i32>, RangeInclusive<i32>)) -> Option<i32> {
+    let mut vel = vel;
+    let mut pos = (0, 0);
+    let mut max_y = 0;
+    while &pos.1 > target.1.start() {
+        pos.0 += vel.0;
+        pos.1 += vel.1;
+        max_y = max_y.max(pos.1);
+        vel.0 -= vel.0.signum();
+        vel.1 -= 1;
+
+        if target.0.contains(&pos.0) && target.1.contains(&pos.1) {
+            return Some(max_y);
+        }
+    }
+    None
+}
+
+type Target = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+/// Returns the highest point any hit reaches and the number of initial
+/// velocities that hit.
+fn solve(target: &Target) -> (i32, usize) {
+    let mut min_steps = 0;
+    while (min_steps * (min_steps + 1)) / 2 < *target.0.start() {
+        min_steps += 1;
+    }
+
+    // Start roughly in the right place (min x, just below neutral y)
+    let x = min_steps;
+    let mut y = *target.1.start() / min_steps;
+
+    let mut total_max_y = i32::MIN;
+    loop {
+        // Find neutral y
+        if let Some(max_y) = determine_hit((x, y), target) {
+            total_max_y = total_max_y.max(max_y);
+            break;
+        }
+
+        y += 1;
+    }
+
+    let mut stack = Vec::from([(x, y)]);
+    let mut tried = HashSet::new();
+    let mut hits = HashSet::new();
+    while let Some(vel) = stack.pop() {
+        if tried.contains(&vel) {
+            continue;
+        }
+        tried.insert(vel);
+        
+        if  let Some(max_y) = determine_hit(vel, target) {
+            // println!("Hit at {:?} with max_y {}", vel, max_y);
+            total_max_y = total_max_y.max(max_y);    
+        } else {
+            continue;    
+        }
+        hits.insert(vel);
+        for dy in -10..10 {
+            for dx in 0..400 {
+                stack.push((vel.0 + dx, vel.1+dy));    
+            }
+        }
+    }
+
+    (total_max_y, hits.len())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Target;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // target area: x=257..286, y=-101..-57
+        let (_, input) = input.trim().split_once(": ").context("Missing :")?;
+        let (x, y) = input.split_once(", ").context("Missing ,")?;
+        let (_, x) = x.split_once('=').context("Missing x=")?;
+        let (xstart, xstop) = x.split_once("..").context("Missing x range")?;
+        let (_, y) = y.split_once('=').context("Missing y=")?;
+        let (ystart, ystop) = y.split_once("..").context("Missing y range")?;
+        let xstart = xstart.parse::<i32>()?;
+        let xstop = xstop.parse::<i32>()?;
+        let ystart = ystart.parse::<i32>()?;
+        let ystop = ystop.parse::<i32>()?;
+
+        Ok((xstart..=xstop, ystart..=ystop))
+    }
+
+    fn part1(target: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(solve(target).0)
+    }
+
+    fn part2(target: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(solve(target).1)
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+pub mod number;
+
+use number::SnailfishNumber;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<SnailfishNumber>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        let sum = numbers
+            .iter()
+            .cloned()
+            .reduce(|a, b| a + b)
+            .context("No numbers")?;
+        Ok(sum.magnitude())
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut largest = None;
+        for (i, a) in numbers.iter().enumerate() {
+            for (j, b) in numbers.iter().enumerate() {
+                if i != j {
+                    largest = largest.max(Some((a + b).magnitude()));
+                }
+            }
+        }
+        largest.context("Need at least two numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let numbers = Day18::parse(include_str!("../../../data/2021/day18/example_input"))?;
+        let sum = numbers.iter().cloned().reduce(|a, b| a + b).unwrap();
+        assert_eq!(
+            sum.to_string(),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+        assert_eq!(Day18::part1(&numbers)?, 4140);
+        assert_eq!(Day18::part2(&numbers)?, 3993);
+        Ok(())
+    }
+}
@@ -0,0 +1,396 @@
+//! Snailfish numbers, kept as their regular numbers in order with how deeply
+//! each is nested.  Exploding and splitting only ever touch a number and its
+//! neighbours to the left and right, which are just the adjacent entries.
+
+use std::{fmt, ops::Add, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+
+/// Pairs nested inside this many pairs explode.
+const MAX_DEPTH: u32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Leaf {
+    value: u32,
+    /// How many pairs this regular number is inside.
+    depth: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnailfishNumber {
+    leaves: Vec<Leaf>,
+}
+
+/// A single reduction action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Explode,
+    Split,
+}
+
+impl SnailfishNumber {
+    /// `[self,other]`, without reducing it.
+    pub fn join(&self, other: &SnailfishNumber) -> SnailfishNumber {
+        let leaves = self
+            .leaves
+            .iter()
+            .chain(&other.leaves)
+            .map(|leaf| Leaf {
+                depth: leaf.depth + 1,
+                ..*leaf
+            })
+            .collect();
+        SnailfishNumber { leaves }
+    }
+
+    pub fn is_reduced(&self) -> bool {
+        self.leaves
+            .iter()
+            .all(|leaf| leaf.depth <= MAX_DEPTH && leaf.value < 10)
+    }
+
+    /// Applies the first action that applies, if any: the leftmost explosion,
+    /// or failing that the leftmost split.
+    pub fn reduce_once(&mut self) -> Option<Step> {
+        // The first two neighbours at the same depth are always a pair.
+        let explodes = self
+            .leaves
+            .windows(2)
+            .position(|w| w[0].depth > MAX_DEPTH && w[0].depth == w[1].depth);
+        if let Some(i) = explodes {
+            let (left, right) = (self.leaves[i], self.leaves[i + 1]);
+            if i > 0 {
+                self.leaves[i - 1].value += left.value;
+            }
+            if let Some(next) = self.leaves.get_mut(i + 2) {
+                next.value += right.value;
+            }
+            self.leaves.splice(
+                i..i + 2,
+                [Leaf {
+                    value: 0,
+                    depth: left.depth - 1,
+                }],
+            );
+            return Some(Step::Explode);
+        }
+        let i = self.leaves.iter().position(|leaf| leaf.value >= 10)?;
+        let Leaf { value, depth } = self.leaves[i];
+        self.leaves.splice(
+            i..=i,
+            [
+                Leaf {
+                    value: value / 2,
+                    depth: depth + 1,
+                },
+                Leaf {
+                    value: value - value / 2,
+                    depth: depth + 1,
+                },
+            ],
+        );
+        Some(Step::Split)
+    }
+
+    /// Each action taken while reducing, with the number just after it.
+    pub fn reduce_steps(mut self) -> impl Iterator<Item = (Step, SnailfishNumber)> {
+        std::iter::from_fn(move || {
+            let step = self.reduce_once()?;
+            Some((step, self.clone()))
+        })
+    }
+
+    pub fn reduce(&mut self) {
+        while self.reduce_once().is_some() {}
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        // Combine the top two entries whenever they're the two halves of a
+        // pair, which is when they're at the same depth.
+        let mut stack: Vec<(u64, u32)> = vec![];
+        for leaf in &self.leaves {
+            let mut top = (leaf.value as u64, leaf.depth);
+            while let Some(&(left, depth)) = stack.last() {
+                if depth != top.1 {
+                    break;
+                }
+                stack.pop();
+                top = (3 * left + 2 * top.0, depth - 1);
+            }
+            stack.push(top);
+        }
+        stack[0].0
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, next: &mut usize, depth: u32) -> fmt::Result {
+        let leaf = self.leaves[*next];
+        if leaf.depth == depth {
+            *next += 1;
+            return write!(f, "{}", leaf.value);
+        }
+        write!(f, "[")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, ",")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, "]")
+    }
+}
+
+/// The sum, reduced.
+impl Add for &SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: &SnailfishNumber) -> SnailfishNumber {
+        let mut sum = self.join(other);
+        sum.reduce();
+        sum
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> SnailfishNumber {
+        &self + &other
+    }
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &mut 0, 0)
+    }
+}
+
+/// Parses a pair, or a regular number, at the start of `s`.
+fn parse_element(s: &str, depth: u32, leaves: &mut Vec<Leaf>) -> Result<usize> {
+    if let Some(rest) = s.strip_prefix('[') {
+        let mut len = 1 + parse_element(rest, depth + 1, leaves)?;
+        if !s[len..].starts_with(',') {
+            bail!("Expected ',' at {:?}", &s[len..]);
+        }
+        len += 1 + parse_element(&s[len + 1..], depth + 1, leaves)?;
+        if !s[len..].starts_with(']') {
+            bail!("Expected ']' at {:?}", &s[len..]);
+        }
+        return Ok(len + 1);
+    }
+    let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let value = s[..len]
+        .parse()
+        .with_context(|| format!("Expected a number or pair at {s:?}"))?;
+    leaves.push(Leaf { value, depth });
+    Ok(len)
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<SnailfishNumber> {
+        let s = s.trim();
+        if !s.starts_with('[') {
+            bail!("A snailfish number is a pair: {s:?}");
+        }
+        let mut leaves = vec![];
+        let len = parse_element(s, 0, &mut leaves)?;
+        if len != s.len() {
+            bail!("Unexpected {:?} after the number", &s[len..]);
+        }
+        Ok(SnailfishNumber { leaves })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::random::Rng;
+
+    fn number(s: &str) -> SnailfishNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        let s = "[[1,2],[[3,4],15]]";
+        assert_eq!(number(s).to_string(), s);
+        for bad in ["7", "[1,2", "[1,2]]", "[1;2]", "[,2]", "[[1,2]]"] {
+            assert!(bad.parse::<SnailfishNumber>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_reduce_once() {
+        for (start, expected) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let mut n = number(start);
+            assert_eq!(n.reduce_once(), Some(Step::Explode));
+            assert_eq!(n.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_reduce_steps() {
+        let sum = number("[[[[4,3],4],4],[7,[[8,4],9]]]").join(&number("[1,1]"));
+        assert_eq!(sum.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        let steps: Vec<_> = sum
+            .reduce_steps()
+            .map(|(step, n)| (step, n.to_string()))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                (
+                    Step::Explode,
+                    "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]".to_string()
+                ),
+                (Step::Explode, "[[[[0,7],4],[15,[0,13]]],[1,1]]".to_string()),
+                (
+                    Step::Split,
+                    "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]".to_string()
+                ),
+                (
+                    Step::Split,
+                    "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]".to_string()
+                ),
+                (
+                    Step::Explode,
+                    "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sums() {
+        for (lines, expected) in [
+            ("[1,1] [2,2] [3,3] [4,4]", "[[[[1,1],[2,2]],[3,3]],[4,4]]"),
+            (
+                "[1,1] [2,2] [3,3] [4,4] [5,5]",
+                "[[[[3,0],[5,3]],[4,4]],[5,5]]",
+            ),
+            (
+                "[1,1] [2,2] [3,3] [4,4] [5,5] [6,6]",
+                "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+            ),
+        ] {
+            let sum = lines.split(' ').map(number).reduce(|a, b| a + b).unwrap();
+            assert_eq!(sum.to_string(), expected);
+        }
+        assert_eq!(
+            (number("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]")
+                + number("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]"))
+            .to_string(),
+            "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"
+        );
+    }
+
+    #[test]
+    fn test_running_sum() {
+        let lines = "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+            [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+            [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+            [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+            [7,[5,[[3,8],[1,4]]]]
+            [[2,[2,2]],[8,[8,1]]]
+            [2,9]
+            [1,[[[9,3],9],[[9,0],[0,7]]]]
+            [[[5,[7,4]],7],1]
+            [[[[4,2],2],6],[8,7]]";
+        let expected = [
+            "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
+            "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]",
+            "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]",
+            "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]",
+            "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]",
+            "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]",
+            "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]",
+            "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]",
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+        ];
+        let mut numbers = lines.lines().map(number);
+        let mut sum = numbers.next().unwrap();
+        for (n, expected) in numbers.zip(expected) {
+            sum = sum + n;
+            assert_eq!(sum.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_magnitude() {
+        for (s, magnitude) in [
+            ("[9,1]", 29),
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(number(s).magnitude(), magnitude, "{s}");
+        }
+    }
+
+    /// A small deterministic generator of random snailfish numbers, for
+    /// checking properties over many of them.
+    struct Random(Rng);
+
+    impl Random {
+        fn next(&mut self, n: u64) -> u64 {
+            self.0.below(n)
+        }
+
+        fn element(&mut self, depth: u32, max_depth: u32, max_value: u64) -> String {
+            if depth > 0 && (depth == max_depth || self.next(3) == 0) {
+                return self.next(max_value + 1).to_string();
+            }
+            let left = self.element(depth + 1, max_depth, max_value);
+            let right = self.element(depth + 1, max_depth, max_value);
+            format!("[{left},{right}]")
+        }
+
+        fn number(&mut self, max_depth: u32, max_value: u64) -> SnailfishNumber {
+            number(&self.element(0, max_depth, max_value))
+        }
+    }
+
+    #[test]
+    fn test_properties() {
+        let mut random = Random(Rng::new(0x5eed));
+        for _ in 0..500 {
+            // Anything parsed formats back the same, even unreduced.
+            let text = random.element(0, 7, 30);
+            assert_eq!(number(&text).to_string(), text);
+
+            let mut unreduced = random.number(6, 25);
+            unreduced.reduce();
+            assert!(unreduced.is_reduced(), "{unreduced}");
+            // Reducing is idempotent.
+            let mut again = unreduced.clone();
+            assert_eq!(again.reduce_once(), None);
+            again.reduce();
+            assert_eq!(again, unreduced);
+
+            // The sum of reduced numbers is reduced.
+            let (a, b) = (random.number(4, 9), random.number(4, 9));
+            let sum = &a + &b;
+            assert!(sum.is_reduced(), "{a} + {b} = {sum}");
+            assert!(sum.reduce_steps().next().is_none());
+
+            // Before reducing, magnitudes combine like a pair's halves.
+            assert_eq!(
+                a.join(&b).magnitude(),
+                3 * a.magnitude() + 2 * b.magnitude()
+            );
+        }
+    }
+}
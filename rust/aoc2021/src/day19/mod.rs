@@ -1,35 +1,11 @@
-// cargo run --example day19 -- (part1|part2) (example_input|final_input)
-
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::read_to_string;
-
-use anyhow::Result;
-use structopt::StructOpt;
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
 
-#[derive(Debug, StructOpt)]
-#[structopt(name = "day19", about = "Advent of Code 2021 Day 19")]
-pub struct Args {
-    part: Part,
-    input: Input,
-}
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Point(i32, i32, i32);
+pub struct Point(i32, i32, i32);
 
 impl std::ops::Sub for Point {
     type Output = Point;
@@ -96,9 +72,15 @@ impl ScannerData {
     }
 }
 
-fn solve(file_path: &str) -> Result<()> {
-    let contents = read_to_string(file_path)?;
+/// The beacons and scanners, all in scanner 0's frame of reference.
+pub struct Scan {
+    known_points: HashSet<Point>,
+    scanner_locations: Vec<Point>,
+}
 
+/// Parses the scanner reports and lines every scanner up against the ones
+/// already placed.
+fn align(contents: &str) -> Result<Scan> {
     let mut lines = contents.lines();
     let mut scanner_data = Vec::new();
     let mut scanner = ScannerData::default();
@@ -114,9 +96,9 @@ fn solve(file_path: &str) -> Result<()> {
         }
         let pos: Vec<_> = line.split(',').collect();
         let pos = Point(
-            pos[0].parse::<i32>().unwrap(),
-            pos[1].parse::<i32>().unwrap(),
-            pos[2].parse::<i32>().unwrap(),
+            pos[0].parse::<i32>()?,
+            pos[1].parse::<i32>()?,
+            pos[2].parse::<i32>()?,
         );
         scanner.add_position(pos);
     }
@@ -168,38 +150,48 @@ fn solve(file_path: &str) -> Result<()> {
             }
         }
         if !found {
-            panic!("No progress!");
+            bail!("No progress!");
         }
     }
-    println!("Known points: {}", known_points.len());
 
     let scanner_locations: Vec<_> = scanner_data
         .iter()
         .map(|s| s.correct_transform.unwrap().1)
         .collect();
-    let mut highest_manhattan: Option<i32> = None;
-    for (i, &loc1) in scanner_locations.iter().enumerate() {
-        for &loc2 in scanner_locations.iter().skip(i + 1) {
-            let vector = loc1 - loc2;
-            let manhattan = vector.0.abs() + vector.1.abs() + vector.2.abs();
-            match highest_manhattan {
-                Some(v) => highest_manhattan = Some(v.max(manhattan)),
-                None => highest_manhattan = Some(manhattan),
-            }
-        }
-    }
-    println!("Highest Manhattan distance: {}", highest_manhattan.unwrap());
-
-    Ok(())
+    Ok(Scan {
+        known_points,
+        scanner_locations,
+    })
 }
 
-fn main() -> Result<()> {
-    let args = Args::from_args_safe()?;
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Scan;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        align(input)
+    }
 
-    let file_path = match args.input {
-        Input::ExampleInput => "data/day19/example_input",
-        Input::FinalInput => "data/day19/input",
-    };
+    fn part1(scan: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(scan.known_points.len())
+    }
 
-    solve(file_path)
+    fn part2(scan: &Self::Input<'_>) -> Result<Self::Part2> {
+        let scanner_locations = &scan.scanner_locations;
+        let mut highest_manhattan: Option<i32> = None;
+        for (i, &loc1) in scanner_locations.iter().enumerate() {
+            for &loc2 in scanner_locations.iter().skip(i + 1) {
+                let vector = loc1 - loc2;
+                let manhattan = vector.0.abs() + vector.1.abs() + vector.2.abs();
+                match highest_manhattan {
+                    Some(v) => highest_manhattan = Some(v.max(manhattan)),
+                    None => highest_manhattan = Some(manhattan),
+                }
+            }
+        }
+        highest_manhattan.context("Fewer than two scanners")
+    }
 }
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Grid, Solution};
+
+/// The image enhancement algorithm and the input image, `true` for a lit
+/// pixel.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    algorithm: Vec<bool>,
+    image: Grid<bool>,
+}
+
+fn pixel(c: char) -> Result<bool> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => bail!("Unexpected {c:?} in image"),
+    }
+}
+
+fn parse(contents: &str) -> Result<Puzzle> {
+    let contents = contents.replace("\r\n", "\n");
+    let (algorithm, image) = contents
+        .split_once("\n\n")
+        .context("Expected the algorithm, a blank line, then the image")?;
+    let algorithm = algorithm
+        .lines()
+        .flat_map(str::chars)
+        .map(pixel)
+        .collect::<Result<Vec<_>>>()?;
+    if algorithm.len() != 512 {
+        bail!("The algorithm has {} entries, not 512", algorithm.len());
+    }
+    let image = Grid::parse(image, pixel)?;
+    Ok(Puzzle { algorithm, image })
+}
+
+/// Counts the lit pixels after running the enhancement `iterations` times.
+fn enhance(puzzle: &Puzzle, iterations: usize) -> usize {
+    let algorithm = &puzzle.algorithm;
+
+    let mut grid_bounds = ((0, 0), (0, 0));
+    let mut grid = HashMap::new();
+    for ((x, y), &lit) in puzzle.image.iter() {
+        let (x, y) = (x as i32, y as i32);
+        grid_bounds.1 .0 = grid_bounds.1 .0.min(y);
+        grid_bounds.1 .1 = grid_bounds.1 .1.max(y);
+        grid_bounds.0 .0 = grid_bounds.0 .0.min(x);
+        grid_bounds.0 .1 = grid_bounds.0 .1.max(x);
+        grid.insert((x, y), lit);
+    }
+
+    let mut border = false;
+    let surrounding = (-1..=1)
+        .flat_map(move |y| (-1..=1).map(move |x| -> (i32, i32) { (x, y) }))
+        .collect::<Vec<_>>();
+
+    for _ in 0..iterations {
+        let mut new_grid = HashMap::new();
+        grid_bounds = (
+            (grid_bounds.0 .0 - 1, grid_bounds.0 .1 + 1),
+            (grid_bounds.1 .0 - 1, grid_bounds.1 .1 + 1),
+        );
+        for y in grid_bounds.1 .0..=grid_bounds.1 .1 {
+            for x in grid_bounds.0 .0..=grid_bounds.0 .1 {
+                let idx = surrounding.iter().fold(0, |idx, (dx, dy)| {
+                    let lit = *grid.get(&(x + dx, y + dy)).unwrap_or(&border);
+                    idx << 1 | usize::from(lit)
+                });
+                new_grid.insert((x, y), algorithm[idx]);
+            }
+        }
+        border = algorithm[if border { 511 } else { 0 }];
+
+        grid = new_grid;
+    }
+
+    grid.values().filter(|&&lit| lit).count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(enhance(input, 2))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(enhance(input, 50))
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+#[derive(Hash, PartialEq, Eq)]
+struct State {
+    positions: [u32; 2],
+    scores: [u32; 2],
+    player: usize,
+}
+
+fn state_to_universes(state: State, memo: &mut HashMap<State, [u64; 2]>) -> [u64; 2] {
+    let State {
+        positions,
+        scores,
+        player,
+    } = state;
+    if memo.contains_key(&state) {
+        return memo[&state];
+    }
+    let wins = if scores[0] >= 21 {
+        [1, 0]
+    } else if scores[1] >= 21 {
+        [0, 1]
+    } else {
+        let mut wins = [0, 0];
+        for i in 1..=3 {
+            for j in 1..=3 {
+                for k in 1..=3 {
+                    let new_pos = (positions[player] + i + j + k) % 10;
+                    let score = if new_pos == 0 { 10 } else { new_pos };
+
+                    let mut positions = positions;
+                    positions[player] = new_pos;
+
+                    let mut scores = scores;
+                    scores[player] += score;
+
+                    let this_wins = state_to_universes(
+                        State {
+                            positions,
+                            scores,
+                            player: 1 - player,
+                        },
+                        memo,
+                    );
+
+                    wins[0] += this_wins[0];
+                    wins[1] += this_wins[1];
+                }
+            }
+        }
+
+        wins
+    };
+    memo.insert(state, wins);
+    wins
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = [u32; 2];
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut lines = input.lines();
+        let mut starting_position = || -> Result<u32> {
+            let line = lines.next().context("Missing player")?;
+            Ok(line.split_once(": ").context("Missing :")?.1.parse::<u32>()?)
+        };
+        Ok([starting_position()?, starting_position()?])
+    }
+
+    fn part1(original_positions: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut positions = *original_positions;
+
+        let mut scores = [0, 0];
+        let mut next_player = 0;
+        let mut dice_rolls = (1..=100).cycle();
+        let mut num_dice_rolls = 0;
+        while scores[0].max(scores[1]) < 1000 {
+            num_dice_rolls += 3;
+            let roll = dice_rolls.next().unwrap()
+                + dice_rolls.next().unwrap()
+                + dice_rolls.next().unwrap();
+            let new_pos = (positions[next_player] + roll) % 10;
+            positions[next_player] = new_pos;
+            scores[next_player] += if new_pos == 0 { 10 } else { new_pos };
+            next_player = (next_player + 1) % 2;
+        }
+
+        Ok(num_dice_rolls * scores[0].min(scores[1]))
+    }
+
+    fn part2(original_positions: &Self::Input<'_>) -> Result<Self::Part2> {
+        let state = State {
+            positions: *original_positions,
+            scores: [0, 0],
+            player: 0,
+        };
+        let mut memo = HashMap::new();
+        let wins = state_to_universes(state, &mut memo);
+
+        Ok(wins[0].max(wins[1]))
+    }
+}
@@ -1,33 +1,11 @@
-// cargo run --example day23 -- (part1|part2) (example_input|final_input)
-
-use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use anyhow::Result;
-use structopt::StructOpt;
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[allow(clippy::enum_variant_names)]
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
-
-#[derive(Debug, StructOpt)]
-#[structopt(name = "day23", about = "Advent of Code 2021 Day 23")]
-pub struct Args {
-    part: Part,
-    input: Input,
-}
+use anyhow::{bail, Context, Result};
+use aoc_common::{
+    search::{dijkstra_by_key, Path},
+    Solution,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Position {
@@ -41,28 +19,13 @@ struct Actor {
     position: Position,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 struct State<const A: usize, const R: usize> {
     actors: [Actor; A],
     rooms: [Room<R>; 4],
     hallway: [Option<(Actor, usize)>; 11],
 }
 
-#[derive(PartialEq, Eq)]
-struct Cost<const A: usize, const R: usize>(usize, State<A, R>);
-
-impl<const A: usize, const R: usize> PartialOrd for Cost<A, R> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(&other.0).map(|o| o.reverse())
-    }
-}
-
-impl<const A: usize, const R: usize> Ord for Cost<A, R> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.0.cmp(&other.0).reverse()
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Room<const R: usize> {
     room_number: usize,
@@ -288,7 +251,7 @@ impl<const A: usize, const R: usize> State<A, R> {
                         hallway_options(current_room_hallway_position, false, &self.hallway);
                     for p in reachable.iter() {
                         let hallway_steps =
-                            (*p as i32 - current_room_hallway_position as i32).abs() as usize;
+                            (*p as i32 - current_room_hallway_position as i32).unsigned_abs() as usize;
                         let total_steps = hallway_steps + into_hallway_steps;
                         let cost = total_steps * cost_per_step;
                         let position = Position::Hallway(*p);
@@ -323,19 +286,16 @@ impl<const A: usize, const R: usize> State<A, R> {
                 *actor_id,
                 Position::Room(actor.target_room, new_room_position),
             );
-            let hallway_steps = (position as i32 - room_hallway_target as i32).abs() as usize;
+            let hallway_steps = (position as i32 - room_hallway_target as i32).unsigned_abs() as usize;
             let full_steps = hallway_steps + R - new_room_position;
             moves.push((new_state, full_steps * cost_per_step));
         }
     }
 }
 
-fn solve(input: Input) -> Result<()> {
-    let room_stacks = match input {
-        Input::ExampleInput => [[0, 1], [3, 2], [2, 1], [0, 3]],
-        Input::FinalInput => [[1, 0], [2, 3], [3, 1], [0, 2]],
-    };
-
+/// Builds the starting state from each room's occupants, listed from the
+/// bottom of the room up.
+fn starting_state<const A: usize, const R: usize>(room_stacks: [[usize; R]; 4]) -> State<A, R> {
     let mut actors = Vec::new();
     for (i, &room) in room_stacks.iter().enumerate() {
         for (j, &a) in room.iter().enumerate() {
@@ -345,82 +305,82 @@ fn solve(input: Input) -> Result<()> {
             })
         }
     }
-    let actors = [
-        actors[0], actors[1], actors[2], actors[3], actors[4], actors[5], actors[6], actors[7],
-    ];
-    let starting_state = State::<8, 2>::new(actors);
-
-    let (final_cost, explored) = calculate_cost(starting_state);
-
-    println!("Final cost: {}, explored {}", final_cost, explored);
-
-    Ok(())
+    let actors: [Actor; A] = actors.try_into().unwrap();
+    State::<A, R>::new(actors)
 }
 
-fn part2(input: Input) -> Result<()> {
-    let room_stacks = match input {
-        Input::ExampleInput => [[0, 3, 3, 1], [3, 1, 2, 2], [2, 0, 1, 1], [0, 2, 0, 3]],
-        Input::FinalInput => [[1, 3, 3, 0], [2, 1, 2, 3], [3, 0, 1, 1], [0, 2, 0, 2]],
-    };
-
-    let mut actors = Vec::new();
-    for (i, &room) in room_stacks.iter().enumerate() {
-        for (j, &a) in room.iter().enumerate() {
-            actors.push(Actor {
-                target_room: a,
-                position: Position::Room(i, j),
-            })
-        }
-    }
-    let actors: [Actor; 16] = [
-        // Really?  No pre-existing .into()?
-        actors[0], actors[1], actors[2], actors[3], actors[4], actors[5], actors[6], actors[7],
-        actors[8], actors[9], actors[10], actors[11], actors[12], actors[13], actors[14],
-        actors[15],
-    ];
-    let starting_state = State::<16, 4>::new(actors);
-
-    let (final_cost, explored) = calculate_cost(starting_state);
-
-    println!("Final cost: {}, explored {}", final_cost, explored);
-
-    Ok(())
+/// The cheapest way to sort the amphipods into their rooms.  States are
+/// keyed by their drawing, so amphipods of the same type are
+/// interchangeable.
+fn calculate_cost<const A: usize, const R: usize>(
+    starting_state: State<A, R>,
+) -> Option<Path<State<A, R>, usize>> {
+    dijkstra_by_key(
+        starting_state,
+        |state| state.display(),
+        |state| state.valid_moves(),
+        |state| state.done(),
+    )
 }
 
-fn calculate_cost<const A: usize, const R: usize>(starting_state: State<A, R>) -> (usize, usize) {
-    let mut stack = BinaryHeap::new();
-    stack.push(Cost(0, starting_state));
-    let mut costs = HashMap::new();
-    let mut final_cost = usize::MAX;
-    while let Some(Cost(cost, state)) = stack.pop() {
-        assert!(state.valid());
-        let serialized = state.display();
-        if let Some(current_cost) = costs.get(&serialized) {
-            if cost >= *current_cost {
-                continue;
-            }
-        }
-        costs.insert(serialized, cost);
-
-        if state.done() {
-            final_cost = final_cost.min(cost);
-            continue;
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = [[usize; 2]; 4];
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // The two rows of amphipods, top row first.
+        let rows: Vec<Vec<usize>> = input
+            .lines()
+            .skip(2)
+            .take(2)
+            .map(|line| {
+                line.chars()
+                    .filter(|c| c.is_ascii_alphabetic())
+                    .map(|c| match c {
+                        'A'..='D' => Ok(c as usize - 'A' as usize),
+                        _ => bail!("Unknown amphipod {c}"),
+                    })
+                    .collect::<Result<_>>()
+            })
+            .collect::<Result<_>>()?;
+        if rows.len() != 2 || rows.iter().any(|row| row.len() != 4) {
+            bail!("Expected two rows of four amphipods");
         }
-
-        let moves = state.valid_moves();
-        for (new_state, move_cost) in moves {
-            stack.push(Cost(cost + move_cost, new_state));
+        let mut room_stacks = [[0; 2]; 4];
+        for (i, room) in room_stacks.iter_mut().enumerate() {
+            *room = [rows[1][i], rows[0][i]];
         }
+        Ok(room_stacks)
     }
-    (final_cost, costs.len())
-}
 
-fn main() -> Result<()> {
-    let args = Args::from_args_safe()?;
+    fn part1(room_stacks: &Self::Input<'_>) -> Result<Self::Part1> {
+        let path = calculate_cost(starting_state::<8, 2>(*room_stacks))
+            .context("The amphipods can't be sorted")?;
+        Ok(path.cost)
+    }
 
-    match args.part {
-        Part::Part1 => solve(args.input),
-        Part::Part2 => part2(args.input),
+    fn part2(room_stacks: &Self::Input<'_>) -> Result<Self::Part2> {
+        // Unfold the diagram, inserting these two rows between the original
+        // rows:
+        //   #D#C#B#A#
+        //   #D#B#A#C#
+        // listed, like the rooms, from the bottom up.
+        let inserted = [[3, 3], [1, 2], [0, 1], [2, 0]];
+        let mut unfolded = [[0; 4]; 4];
+        for (i, room) in unfolded.iter_mut().enumerate() {
+            *room = [
+                room_stacks[i][0],
+                inserted[i][0],
+                inserted[i][1],
+                room_stacks[i][1],
+            ];
+        }
+        let path = calculate_cost(starting_state::<16, 4>(unfolded))
+            .context("The amphipods can't be sorted")?;
+        Ok(path.cost)
     }
 }
 
@@ -428,7 +388,7 @@ fn main() -> Result<()> {
 mod tests {
     use std::collections::HashSet;
 
-    use crate::{
+    use super::{
         calculate_cost, hallway_options, hallway_with_actors, Actor, Position, Room, State,
     };
 
@@ -475,9 +435,9 @@ mod tests {
         assert!(room.occupants[1] == Some((non_match_actor, 0)));
 
         let room = Room::<2>::with_actors(0, actors);
-        assert!(room.last_filled_slot == None);
-        assert!(room.occupants[0] == None);
-        assert!(room.occupants[1] == None);
+        assert!(room.last_filled_slot.is_none());
+        assert!(room.occupants[0].is_none());
+        assert!(room.occupants[1].is_none());
     }
 
     #[test]
@@ -495,9 +455,9 @@ mod tests {
 
         let hallway = hallway_with_actors(&actors);
 
-        for i in 0..11 {
+        for (i, h) in hallway.iter().enumerate() {
             let expected = if i == 10 { Some((actors[1], 1)) } else { None };
-            assert_eq!(hallway[i], expected);
+            assert_eq!(*h, expected);
         }
 
         let actors = [
@@ -513,7 +473,7 @@ mod tests {
 
         let hallway = hallway_with_actors(&actors);
 
-        for i in 0..11 {
+        for (i, h) in hallway.iter().enumerate() {
             let expected = if i == 10 {
                 Some((actors[1], 1))
             } else if i == 4 {
@@ -521,7 +481,7 @@ mod tests {
             } else {
                 None
             };
-            assert_eq!(hallway[i], expected);
+            assert_eq!(*h, expected);
         }
     }
 
@@ -587,7 +547,7 @@ mod tests {
             match new_state.actors[0].position {
                 Position::Hallway(p) => {
                     assert!(valid_hallway_position.contains(&p));
-                    let hallway_cost = (1 + (6 as i32 - p as i32).abs()) * 10;
+                    let hallway_cost = (1 + (6_i32 - p as i32).abs()) * 10;
                     assert_eq!(hallway_cost as usize, *cost);
                 }
                 Position::Room(_, _) => panic!(),
@@ -628,7 +588,7 @@ mod tests {
                 Position::Hallway(p) => {
                     assert!(valid_hallway_position.contains(&p));
                     hallway_positions_considered.insert(p);
-                    let hallway_cost = (1 + (6 as i32 - p as i32).abs()) * 10;
+                    let hallway_cost = (1 + (6_i32 - p as i32).abs()) * 10;
                     assert_eq!(hallway_cost as usize, *cost);
                 }
                 Position::Room(_, _) => {
@@ -657,9 +617,9 @@ mod tests {
 
         let state = State::<2, 2>::new(actors);
         assert!(state.done());
-        let (cost, explored) = calculate_cost(state);
-        assert_eq!(cost, 0);
-        assert_eq!(explored, 1);
+        let path = calculate_cost(state).unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states.len(), 1);
     }
     #[test]
     fn test_calculate_cost_hallway_to_room() {
@@ -675,14 +635,15 @@ mod tests {
         ];
 
         let state = State::<2, 2>::new(actors);
-        assert_eq!(state.done(), false);
+        assert!(!state.done());
 
         for m in state.valid_moves() {
             println!("COST: {}\n{}", m.1, m.0.display());
         }
 
-        let (cost, explored) = calculate_cost(state);
-        assert_eq!(cost, 20);
-        assert_eq!(explored, 2);
+        let path = calculate_cost(state).unwrap();
+        assert_eq!(path.cost, 20);
+        assert_eq!(path.states.len(), 2);
+        assert!(path.goal().done());
     }
 }
@@ -0,0 +1,239 @@
+//! Reading MONAD as what it is rather than running it.
+//!
+//! MONAD is fourteen copies of one 18-instruction block, one per input
+//! digit, differing only in three constants.  `z` is used as a stack of
+//! base-26 numbers: a block with `div z 1` always pushes `digit + offset`,
+//! and a block with `div z 26` pops the top value and, unless it plus
+//! `check` equals the digit, pushes again.  The model number is valid when
+//! the stack ends up empty, so every popping block has to match the digit
+//! pushed by its partner, which ties the two digits together.
+
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+
+use super::{Instruction, Program};
+
+/// Each block, with `?` where the constants go.
+const BLOCK_TEMPLATE: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ?", "add x ?", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ?",
+    "mul y x", "add z y",
+];
+
+/// The constants of the block that reads input `digit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub digit: usize,
+    /// 1 for a block that pushes, 26 for one that pops.
+    pub divisor: i64,
+    /// Added to the top of the stack before comparing it with the digit.
+    pub check: i64,
+    /// Added to the digit before pushing it.
+    pub offset: i64,
+}
+
+impl Block {
+    fn pops(&self) -> bool {
+        self.divisor == 26
+    }
+}
+
+/// What the block does to `z`, written out as Rust.
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Block {
+            digit,
+            check,
+            offset,
+            ..
+        } = *self;
+        if self.pops() {
+            writeln!(f, "let top = z % 26;")?;
+            writeln!(f, "z /= 26;")?;
+            writeln!(f, "if top + {check} != input[{digit}] {{")?;
+        } else {
+            writeln!(f, "if z % 26 + {check} != input[{digit}] {{")?;
+        }
+        writeln!(f, "    z = z * 26 + input[{digit}] + {offset};")?;
+        writeln!(f, "}}")
+    }
+}
+
+/// Splits `program` into its blocks and picks out their constants.
+pub fn blocks(program: &Program) -> Result<Vec<Block>> {
+    let lines: Vec<String> = program.instructions.iter().map(|i| i.to_string()).collect();
+    if lines.len() != 14 * BLOCK_TEMPLATE.len() {
+        bail!(
+            "Expected 14 blocks of {} instructions",
+            BLOCK_TEMPLATE.len()
+        );
+    }
+    lines
+        .chunks(BLOCK_TEMPLATE.len())
+        .enumerate()
+        .map(|(digit, lines)| {
+            let mut constants = vec![];
+            for (i, (line, template)) in lines.iter().zip(BLOCK_TEMPLATE).enumerate() {
+                let matches = match template.strip_suffix('?') {
+                    Some(prefix) => line.strip_prefix(prefix).is_some_and(|constant| {
+                        constant.parse().map(|c| constants.push(c)).is_ok()
+                    }),
+                    None => line == template,
+                };
+                if !matches {
+                    bail!("Block {digit}, instruction {i}: expected {template:?}, got {line:?}");
+                }
+            }
+            let block = Block {
+                digit,
+                divisor: constants[0],
+                check: constants[1],
+                offset: constants[2],
+            };
+            // A pushing block has to push whatever the digit: its check
+            // can't be met.
+            match block.divisor {
+                1 if block.check > 9 => Ok(block),
+                26 => Ok(block),
+                _ => bail!("Block {digit} neither always pushes nor pops: {block:?}"),
+            }
+        })
+        .collect()
+}
+
+/// `input[later] == input[earlier] + difference` for every valid model
+/// number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub earlier: usize,
+    pub later: usize,
+    pub difference: i64,
+}
+
+/// Pairs each popping block with the block that pushed the value it pops.
+pub fn constraints(blocks: &[Block]) -> Result<Vec<Constraint>> {
+    let mut stack = vec![];
+    let mut constraints = vec![];
+    for block in blocks {
+        if block.pops() {
+            let pushed: &Block = stack
+                .pop()
+                .with_context(|| format!("Block {} pops an empty stack", block.digit))?;
+            constraints.push(Constraint {
+                earlier: pushed.digit,
+                later: block.digit,
+                difference: pushed.offset + block.check,
+            });
+        } else {
+            stack.push(block);
+        }
+    }
+    if !stack.is_empty() {
+        bail!("{} pushed values are never popped", stack.len());
+    }
+    Ok(constraints)
+}
+
+/// The largest (or smallest) model number meeting every constraint, or
+/// `None` if some pair of digits can't both be 1 to 9.
+pub fn solve(constraints: &[Constraint], largest: bool) -> Option<i64> {
+    let mut digits = vec![0; 2 * constraints.len()];
+    for constraint in constraints {
+        let d = constraint.difference;
+        if d.abs() > 8 {
+            return None;
+        }
+        let earlier = if largest { 9.min(9 - d) } else { 1.max(1 - d) };
+        digits[constraint.earlier] = earlier;
+        digits[constraint.later] = earlier + d;
+    }
+    Some(digits.into_iter().fold(0, |n, digit| n * 10 + digit))
+}
+
+/// The whole program as a Rust function, one statement per instruction.
+pub fn pseudocode(program: &Program) -> String {
+    let mut code = String::from("fn monad(input: [i64; 14]) -> i64 {\n");
+    code += "    let (mut w, mut x, mut y, mut z) = (0, 0, 0, 0);\n";
+    let mut digit = 0;
+    for instruction in &program.instructions {
+        code += &format!("    {}\n", instruction.to_rust(digit));
+        if let Instruction::Inp(_) = instruction {
+            digit += 1;
+        }
+    }
+    code += "    z\n}\n";
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day24::compile;
+
+    #[test]
+    fn test_analyse_input() -> Result<()> {
+        let program = compile(include_str!("../../../data/2021/day24/input"))?;
+        let blocks = blocks(&program)?;
+        assert_eq!(
+            blocks[3],
+            Block {
+                digit: 3,
+                divisor: 26,
+                check: -8,
+                offset: 3
+            }
+        );
+        assert_eq!(
+            blocks[3].to_string(),
+            "let top = z % 26;\nz /= 26;\nif top + -8 != input[3] {\n    z = z * 26 + input[3] + 3;\n}\n"
+        );
+        let constraints = constraints(&blocks)?;
+        assert_eq!(constraints.len(), 7);
+        assert_eq!(
+            constraints[0],
+            Constraint {
+                earlier: 2,
+                later: 3,
+                difference: 0
+            }
+        );
+        assert_eq!(solve(&constraints, true), Some(39999698799429));
+        assert_eq!(solve(&constraints, false), Some(18116121134117));
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_monad() -> Result<()> {
+        let program = compile(include_str!("../../../data/2021/day24/example_input"))?;
+        assert!(blocks(&program).is_err());
+        let unreachable = [Constraint {
+            earlier: 0,
+            later: 1,
+            difference: 9,
+        }];
+        assert_eq!(solve(&unreachable, true), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pseudocode() -> Result<()> {
+        let program = compile("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z 1\neql x w\ninp y")?;
+        assert_eq!(
+            pseudocode(&program),
+            "fn monad(input: [i64; 14]) -> i64 {
+    let (mut w, mut x, mut y, mut z) = (0, 0, 0, 0);
+    w = input[0];
+    x = 0;
+    x += z;
+    x %= 26;
+    z /= 1;
+    x = (x == w) as i64;
+    y = input[1];
+    z
+}
+"
+        );
+        Ok(())
+    }
+}
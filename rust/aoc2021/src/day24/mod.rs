@@ -1,35 +1,12 @@
-// cargo run --example day24 -- (part1|part2) (example_input|final_input)
-
-use std::collections::HashMap;
-use std::fs::read_to_string;
+use std::fmt;
 use std::str::FromStr;
 
-use anyhow::Result;
-use structopt::StructOpt;
-
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
 
-#[allow(clippy::enum_variant_names)]
-#[derive(Debug, strum::EnumString)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
+pub mod analysis;
 
-#[derive(Debug, StructOpt)]
-#[structopt(name = "day24", about = "Advent of Code 2021 Day 24")]
-pub struct Args {
-    part: Part,
-    input: Input,
-}
-
-struct Program {
+pub struct Program {
     instructions: Vec<Instruction>,
 }
 
@@ -56,44 +33,105 @@ impl Default for Operand {
     }
 }
 
+const VARIABLE_NAMES: [&str; 4] = ["w", "x", "y", "z"];
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Variable(v) => write!(f, "{}", VARIABLE_NAMES[*v]),
+            Operand::Number(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// Writes the instruction back out as ALU code.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Inp(a) => write!(f, "inp {a}"),
+            Instruction::Add(a, b) => write!(f, "add {a} {b}"),
+            Instruction::Mul(a, b) => write!(f, "mul {a} {b}"),
+            Instruction::Div(a, b) => write!(f, "div {a} {b}"),
+            Instruction::Mod(a, b) => write!(f, "mod {a} {b}"),
+            Instruction::Eql(a, b) => write!(f, "eql {a} {b}"),
+        }
+    }
+}
+
+impl Instruction {
+    /// The instruction as a Rust statement, reading input number `digit`.
+    fn to_rust(self, digit: usize) -> String {
+        match self {
+            Instruction::Inp(a) => format!("{a} = input[{digit}];"),
+            Instruction::Mul(a, Operand::Number(0)) => format!("{a} = 0;"),
+            Instruction::Add(a, b) => format!("{a} += {b};"),
+            Instruction::Mul(a, b) => format!("{a} *= {b};"),
+            Instruction::Div(a, b) => format!("{a} /= {b};"),
+            Instruction::Mod(a, b) => format!("{a} %= {b};"),
+            Instruction::Eql(a, b) => format!("{a} = ({a} == {b}) as i64;"),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 struct State {
     variables: [i64; 4],
     pc: usize,
 }
 
-fn parse_arg(arg: &str) -> Operand {
-    let variable_map = HashMap::from([("w", 0), ("x", 1), ("y", 2), ("z", 3)]);
-    if let Some(v) = variable_map.get(arg) {
-        return Operand::Variable(*v);
+fn parse_arg(arg: &str) -> Result<Operand> {
+    if let Some(v) = VARIABLE_NAMES.iter().position(|&name| name == arg) {
+        return Ok(Operand::Variable(v));
     }
-    let n = arg.parse::<i64>().unwrap();
-    Operand::Number(n)
+    let n = arg
+        .parse::<i64>()
+        .with_context(|| format!("Not a variable or a number: {arg:?}"))?;
+    Ok(Operand::Number(n))
 }
 
-fn compile(contents: &str) -> Program {
+fn compile(contents: &str) -> Result<Program> {
     let lines = contents.lines();
     let mut instructions = Vec::new();
 
     for mut line in lines {
         line = line.trim();
-        let (instr, rest) = line.split_once(' ').unwrap();
-        let args: Vec<_> = rest.split_whitespace().map(|a| parse_arg(a)).collect();
-        let mut instruction = Instruction::from_str(instr).unwrap();
-        match &mut instruction {
-            Instruction::Inp(o) => *o = args[0],
-            Instruction::Add(o1, o2)
-            | Instruction::Mul(o1, o2)
-            | Instruction::Div(o1, o2)
-            | Instruction::Mod(o1, o2)
-            | Instruction::Eql(o1, o2) => {
-                *o1 = args[0];
-                *o2 = args[1];
+        let (instr, rest) = line
+            .split_once(' ')
+            .with_context(|| format!("No operands: {line:?}"))?;
+        let args = rest
+            .split_whitespace()
+            .map(parse_arg)
+            .collect::<Result<Vec<_>>>()?;
+        let mut instruction = Instruction::from_str(instr)
+            .with_context(|| format!("Unknown instruction: {line:?}"))?;
+        match (&mut instruction, args.as_slice()) {
+            (Instruction::Inp(o), &[a]) => *o = a,
+            (
+                Instruction::Add(o1, o2)
+                | Instruction::Mul(o1, o2)
+                | Instruction::Div(o1, o2)
+                | Instruction::Mod(o1, o2)
+                | Instruction::Eql(o1, o2),
+                &[a, b],
+            ) => {
+                *o1 = a;
+                *o2 = b;
             }
+            _ => bail!("Wrong number of operands: {line:?}"),
+        }
+        // Every instruction stores its result in its first operand.
+        let (Instruction::Inp(o)
+        | Instruction::Add(o, _)
+        | Instruction::Mul(o, _)
+        | Instruction::Div(o, _)
+        | Instruction::Mod(o, _)
+        | Instruction::Eql(o, _)) = instruction;
+        if let Operand::Number(_) = o {
+            bail!("Can't store into a number: {line:?}");
         }
         instructions.push(instruction);
     }
-    Program { instructions }
+    Ok(Program { instructions })
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -200,8 +238,7 @@ fn execute_until_input(
     execute_result
 }
 
-#[cfg(test)]
-fn execute(program: Program, inputs: &[i64]) -> State {
+fn execute(program: &Program, inputs: &[i64]) -> State {
     let mut state = State::default();
     let mut input_position = 0;
     let mut next_input = || {
@@ -211,7 +248,7 @@ fn execute(program: Program, inputs: &[i64]) -> State {
     };
 
     loop {
-        let r = execute_until_input(&program, &mut state, Some(next_input()));
+        let r = execute_until_input(program, &mut state, Some(next_input()));
         if r == ExecuteResult::EndOfInstructions {
             break;
         }
@@ -219,102 +256,65 @@ fn execute(program: Program, inputs: &[i64]) -> State {
     state
 }
 
-fn search<const T: bool>(
-    program: &Program,
-    state: State,
-    memo: &mut HashMap<(usize, i64), Option<i64>>,
-) -> Option<i64> {
-    let res = _search::<T>(program, state, memo);
-    res.map(|r| {
-        r.to_string()
-            .chars()
-            .rev()
-            .collect::<String>()
-            .parse()
-            .unwrap()
-    })
-}
-
-fn _search<const T: bool>(
-    program: &Program,
-    state: State,
-    memo: &mut HashMap<(usize, i64), Option<i64>>,
-) -> Option<i64> {
-    let key = (state.pc, state.variables[3]);
-    if let Some(&result) = memo.get(&key) {
-        return result;
-    }
-    let inputs = if T {
-        [9, 8, 7, 6, 5, 4, 3, 2, 1]
-    } else {
-        [1, 2, 3, 4, 5, 6, 7, 8, 9]
+/// The largest or smallest valid model number, worked out from the
+/// structure of MONAD and double-checked by running it.
+fn model_number(program: &Program, largest: bool) -> Result<i64> {
+    let blocks = analysis::blocks(program)?;
+    let constraints = analysis::constraints(&blocks)?;
+    let Some(n) = analysis::solve(&constraints, largest) else {
+        bail!("No valid model number");
     };
-    for input in inputs {
-        let mut state = state;
-        let execute_result = execute_until_input(program, &mut state, Some(input));
-        let key = (state.pc, state.variables[3]);
-        if execute_result == ExecuteResult::NeedNextInput {
-            if let Some(found) = _search::<T>(program, state, memo) {
-                let result = found * 10 + input;
-                memo.insert(key, Some(result));
-                return Some(result);
-            }
-        }
-
-        if state.variables[3] == 0 {
-            memo.insert(key, Some(input));
-            return Some(input);
-        }
+    let digits: Vec<_> = n
+        .to_string()
+        .chars()
+        .map(|c| c.to_digit(10).unwrap() as i64)
+        .collect();
+    let z = execute(program, &digits).variables[3];
+    if z != 0 {
+        bail!("MONAD rejects {n} (z = {z})");
     }
-
-    memo.insert(key, None);
-    None
+    Ok(n)
 }
 
-fn solve(file_path: &str) -> Result<()> {
-    let contents = read_to_string(file_path)?;
+pub struct Day24;
 
-    let program = compile(&contents);
+impl Solution for Day24 {
+    type Input<'a> = Program;
+    type Part1 = i64;
+    type Part2 = i64;
 
-    {
-        let state = State::default();
-        let mut memo = HashMap::new();
-        let biggest = search::<true>(&program, state, &mut memo);
-        println!("Part 1: {:?}", biggest);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        compile(input)
     }
 
-    {
-        let state = State::default();
-        let mut memo = HashMap::new();
-        let smallest = search::<false>(&program, state, &mut memo);
-        println!("Part 2: {:?}", smallest);
+    fn part1(program: &Self::Input<'_>) -> Result<Self::Part1> {
+        model_number(program, true)
     }
 
-    Ok(())
-}
-
-fn main() -> Result<()> {
-    let args = Args::from_args_safe()?;
-
-    let file_path = match args.input {
-        Input::ExampleInput => "data/day24/example_input",
-        Input::FinalInput => "data/day24/input",
-    };
-
-    solve(file_path)
+    fn part2(program: &Self::Input<'_>) -> Result<Self::Part2> {
+        model_number(program, false)
+    }
 }
 
 #[cfg(test)]
 fn compute(contents: &str, inputs: &[i64]) -> State {
-    let program = compile(contents);
-    execute(program, inputs)
+    let program = compile(contents).unwrap();
+    execute(&program, inputs)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs::read_to_string;
+    use super::{compile, compute};
 
-    use crate::compute;
+    #[test]
+    fn test_malformed() {
+        for program in [
+            "inp", "inp w x", "add x", "sub x 1", "add 1 x", "inp 2", "mul x q",
+        ] {
+            assert!(compile(program).is_err(), "{program}");
+        }
+        assert!(compile("inp w\nadd x -3").is_ok());
+    }
 
     #[test]
     fn test_example_1() {
@@ -363,7 +363,7 @@ mod tests {
 
     #[test]
     fn test_part_a() {
-        let program = read_to_string("data/day24/input").unwrap();
+        let program = include_str!("../../../data/2021/day24/input");
         let inputs: Vec<_> = "71143112161181"
             .chars()
             .rev()
@@ -371,7 +371,7 @@ mod tests {
             .collect();
         println!("{:?}", inputs);
         println!("{:?}", inputs.len());
-        let res = compute(&program, inputs.as_slice());
+        let res = compute(program, inputs.as_slice());
         println!("{:?}", res);
     }
 
@@ -399,7 +399,7 @@ mod tests {
             .chars()
             .map(|c| c.to_digit(10).unwrap() as i64)
             .collect();
-        let res = compute(&program, inputs.as_slice());
+        let res = compute(program, inputs.as_slice());
         println!("{:?}", res);
     }
 }
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+use aoc_common::{Grid, Solution, Unimplemented};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Cucumber {
+    East,
+    South,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Cell(Option<Cucumber>);
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self.0 {
+            Some(Cucumber::East) => '>',
+            Some(Cucumber::South) => 'v',
+            None => '.',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// Moves every cucumber of `herd` that can, wrapping at the edges.
+fn move_herd(grid: &Grid<Cell>, herd: Cucumber, moves: &mut usize) -> Grid<Cell> {
+    let mut new_grid = grid.clone();
+    for ((x, y), &cell) in grid.iter() {
+        if cell.0 != Some(herd) {
+            continue;
+        }
+        let moved_pos = match herd {
+            Cucumber::East => ((x + 1) % grid.width(), y),
+            Cucumber::South => (x, (y + 1) % grid.height()),
+        };
+        if grid[moved_pos].0.is_none() {
+            *moves += 1;
+            new_grid[moved_pos] = cell;
+            new_grid[(x, y)] = Cell(None);
+        }
+    }
+    new_grid
+}
+
+fn one_move(grid: &Grid<Cell>) -> (Grid<Cell>, usize) {
+    let mut moves = 0;
+    let grid = move_herd(grid, Cucumber::East, &mut moves);
+    let grid = move_herd(&grid, Cucumber::South, &mut moves);
+    (grid, moves)
+}
+
+fn load_grid(contents: &str) -> Result<Grid<Cell>> {
+    Grid::parse(contents, |c| {
+        Ok(Cell(match c {
+            '>' => Some(Cucumber::East),
+            'v' => Some(Cucumber::South),
+            '.' => None,
+            _ => bail!("Unexpected character {c:?}"),
+        }))
+    })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Grid<Cell>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        load_grid(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut grid = grid.clone();
+        for i in 0.. {
+            let (new_grid, moves) = one_move(&grid);
+            grid = new_grid;
+            if moves == 0 {
+                return Ok(i + 1);
+            }
+        }
+        unreachable!();
+    }
+
+    fn part2(_grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        bail!(Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_grid, one_move};
+
+    fn trim_multiline_str(s: &str) -> String {
+        let mut new_str = String::new();
+        for l in s.lines() {
+            let l = l.trim();
+            new_str.push_str(l);
+            new_str.push('\n');
+        }
+        new_str
+    }
+
+    #[test]
+    fn test_example_1() {
+        let initial = trim_multiline_str(
+            "...>...
+             .......
+             ......>
+             v.....>
+             ......>
+             .......
+             ..vvv..",
+        );
+
+        let initial_grid = load_grid(&initial).unwrap();
+        assert_eq!(initial, initial_grid.to_string());
+
+        let (step1_grid, _) = one_move(&initial_grid);
+        let step1 = trim_multiline_str(
+            "..vv>..
+             .......
+             >......
+             v.....>
+             >......
+             .......
+             ....v..",
+        );
+        assert_eq!(step1, step1_grid.to_string());
+        let (step2_grid, _) = one_move(&step1_grid);
+        let step2 = trim_multiline_str(
+            "....v>.
+             ..vv...
+             .>.....
+             ......>
+             v>.....
+             .......
+             .......",
+        );
+        assert_eq!(step2, step2_grid.to_string());
+    }
+}
@@ -0,0 +1,57 @@
+//! Solutions for Advent of Code 2021.
+
+use aoc_common::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Day01),
+    Day::new(2, &day02::Day02),
+    Day::new(3, &day03::Day03),
+    Day::new(4, &day04::Day04),
+    Day::new(5, &day05::Day05),
+    Day::new(6, &day06::Day06),
+    Day::new(7, &day07::Day07),
+    Day::new(8, &day08::Day08),
+    Day::new(9, &day09::Day09),
+    Day::new(10, &day10::Day10),
+    Day::new(11, &day11::Day11),
+    Day::new(12, &day12::Day12),
+    Day::new(13, &day13::Day13),
+    Day::new(14, &day14::Day14),
+    Day::new(15, &day15::Day15),
+    Day::new(16, &day16::Day16),
+    Day::new(17, &day17::Day17),
+    Day::new(18, &day18::Day18),
+    Day::new(19, &day19::Day19),
+    Day::new(20, &day20::Day20),
+    Day::new(21, &day21::Day21),
+    Day::new(22, &day22::Day22),
+    Day::new(23, &day23::Day23),
+    Day::new(24, &day24::Day24),
+    Day::new(25, &day25::Day25),
+];
@@ -1,5 +0,0 @@
-// The code is in examples/day*
-
-fn main() {
-    println!("Hello, world!");
-}
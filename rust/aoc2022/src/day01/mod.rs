@@ -0,0 +1,58 @@
+//! Day 01
+//!
+//! Mostly getting the basic setup right (stupid stuff, like updating from
+//! from structopt to clap), and then lamenting that `BinaryHeap`'s obviously
+//! useful method for this (besides just calling `pop()` 3 times),
+//! `into_iter_sorted`, is an unstable feature.
+
+use std::collections::BinaryHeap;
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    /// The calories each elf is carrying, in total.
+    fn parse(file_data: &str) -> Result<Self::Input<'_>> {
+        let mut lines = file_data.lines();
+        let mut elves = Vec::new();
+        loop {
+            let mut calories = 0;
+            let should_continue = loop {
+                match lines.next() {
+                    None => break false,
+                    Some("") => break true,
+                    Some(l) => {
+                        calories += l.parse::<i32>()?;
+                    }
+                }
+            };
+            elves.push(calories);
+            if !should_continue {
+                break;
+            }
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Result<Self::Part1> {
+        elves.iter().copied().max().context("No elves")
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> Result<Self::Part2> {
+        let most_calories_heap = BinaryHeap::from(elves.clone());
+
+        Ok(most_calories_heap
+            .into_sorted_vec()
+            .iter()
+            .rev()
+            .take(3)
+            .sum::<i32>())
+    }
+}
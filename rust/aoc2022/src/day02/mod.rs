@@ -0,0 +1,150 @@
+//! Day 02
+//!
+//! Need to find a nicer "exactly one rule" Result<> for the rule table, else
+//! this was fairly straightforward.
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum RoundResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+static RULE_TABLE: Lazy<Vec<(Move, Move, RoundResult)>> = Lazy::new(|| {
+    Vec::from([
+        (Move::Rock, Move::Rock, RoundResult::Draw),
+        (Move::Rock, Move::Paper, RoundResult::Win),
+        (Move::Rock, Move::Scissors, RoundResult::Loss),
+        (Move::Paper, Move::Rock, RoundResult::Loss),
+        (Move::Paper, Move::Paper, RoundResult::Draw),
+        (Move::Paper, Move::Scissors, RoundResult::Win),
+        (Move::Scissors, Move::Rock, RoundResult::Win),
+        (Move::Scissors, Move::Paper, RoundResult::Loss),
+        (Move::Scissors, Move::Scissors, RoundResult::Draw),
+    ])
+});
+
+fn calc_result_score(result: RoundResult) -> i32 {
+    match result {
+        RoundResult::Win => 6,
+        RoundResult::Draw => 3,
+        RoundResult::Loss => 0,
+    }
+}
+
+fn calc_move_score(output_move: Move) -> i32 {
+    match output_move {
+        Move::Rock => 1,
+        Move::Paper => 2,
+        Move::Scissors => 3,
+    }
+}
+
+/// A round from the strategy guide: the opponent's move and the second
+/// column, which part 1 reads as a move and part 2 as a result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    input_move: Move,
+    column: Column,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Column {
+    X,
+    Y,
+    Z,
+}
+
+fn parse_rounds(file_data: &str) -> Result<Vec<Round>> {
+    file_data
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once(' ').context("Couldn't parse line")?;
+            let input_move = match a {
+                "A" => Move::Rock,
+                "B" => Move::Paper,
+                "C" => Move::Scissors,
+                _ => bail!("Can't decode move"),
+            };
+            let column = match b {
+                "X" => Column::X,
+                "Y" => Column::Y,
+                "Z" => Column::Z,
+                _ => bail!("Can't decode move"),
+            };
+            Ok(Round { input_move, column })
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Round>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_rounds(input)
+    }
+
+    fn part1(rounds: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total_score = 0;
+        for &Round { input_move, column } in rounds {
+            let output_move = match column {
+                Column::X => Move::Rock,
+                Column::Y => Move::Paper,
+                Column::Z => Move::Scissors,
+            };
+            let rule = RULE_TABLE
+                .iter()
+                .filter(|(i, o, _r)| i == &input_move && o == &output_move)
+                .collect::<Vec<_>>();
+            let result = match rule.as_slice() {
+                &[x] => x.2,
+                _ => bail!("Didn't find exactly one rule!"),
+            };
+            let move_score = calc_move_score(output_move);
+            let result_score = calc_result_score(result);
+            let round_score = move_score + result_score;
+            total_score += round_score;
+        }
+
+        Ok(total_score)
+    }
+
+    fn part2(rounds: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut total_score = 0;
+        for &Round { input_move, column } in rounds {
+            let result = match column {
+                Column::X => RoundResult::Loss,
+                Column::Y => RoundResult::Draw,
+                Column::Z => RoundResult::Win,
+            };
+            let rule = RULE_TABLE
+                .iter()
+                .filter(|(i, _o, r)| i == &input_move && r == &result)
+                .collect::<Vec<_>>();
+            let output_move = match rule.as_slice() {
+                &[x] => x.1,
+                _ => bail!("Didn't find exactly one rule!"),
+            };
+
+            let move_score = calc_move_score(output_move);
+            let result_score = calc_result_score(result);
+            let round_score = move_score + result_score;
+            total_score += round_score;
+        }
+
+        Ok(total_score)
+    }
+}
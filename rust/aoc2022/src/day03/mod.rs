@@ -0,0 +1,102 @@
+//! Day 03
+//!
+//! Okay, finally caved and added itertools to the imports for "exactly one"
+//! iterator handling.
+//! 
+//! Wasted a ton of time thinking `to_digit` would help (thinking it was
+//! case-sensitive somehow.  Still find Rust's char -> u8 for ascii stuff
+//! annoying.
+
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+#[derive(Debug)]
+pub struct Rucksack {
+    items1: Vec<Score>,
+    items2: Vec<Score>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+struct Score(u32);
+
+impl TryFrom<char> for Score {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Score> {
+        match c {
+            'a'..='z' => Ok(Score(u32::from(c) - u32::from('a') + 1)),
+            'A'..='Z' => Ok(Score(u32::from(c) - u32::from('A') + 1 + 26)),
+            _ => bail!("Unexpected item {c:?}"),
+        }
+    }
+}
+
+trait ToSet {
+    fn to_set(&self) -> HashSet<Score>;
+}
+impl ToSet for Vec<Score> {
+    fn to_set(&self) -> HashSet<Score> {
+        HashSet::from_iter(self.iter().copied())
+    }
+}
+
+fn get_rucksacks(file_data: &str) -> Result<Vec<Rucksack>> {
+    let mut rucksacks = Vec::new();
+    for line in file_data.lines() {
+        let (items1, items2) = line.split_at(line.len() / 2);
+        //println!("{items1} {items2}");
+        let rucksack = Rucksack {
+            items1: items1.chars().map(Score::try_from).collect::<Result<_>>()?,
+            items2: items2.chars().map(Score::try_from).collect::<Result<_>>()?,
+        };
+        rucksacks.push(rucksack);
+    }
+    Ok(rucksacks)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        get_rucksacks(input)
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total = 0;
+        for rucksack in rucksacks {
+            let s1 = rucksack.items1.to_set();
+            let s2 = rucksack.items2.to_set();
+            let score: u32 = s1.intersection(&s2).map(|x| x.0).sum();
+            //println!("{s1:?} {s2:?} {score}");
+            total += score;
+        }
+        Ok(total)
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut total = 0;
+        for chunk in rucksacks.chunks(3) {
+            let mut sets = Vec::new();
+            for rucksack in chunk {
+                let s1 = rucksack.items1.to_set();
+                let s2 = rucksack.items2.to_set();    
+                let union = &s1 | &s2;
+                sets.push(union);
+            }
+
+            let mut remaining = sets[0].clone();
+            for set in sets.iter().skip(1) {
+                remaining = &remaining & set;
+            }
+
+            let score = remaining.into_iter().exactly_one()?;
+            total += score.0;
+        }
+        Ok(total)
+    }
+}
@@ -0,0 +1,70 @@
+//! Day 04
+//!
+//!
+
+use std::ops::Range;
+
+use anyhow::anyhow;
+
+use crate::prelude::*;
+
+pub struct RangePair(Range<u32>, Range<u32>);
+
+impl TryFrom<&str> for RangePair {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let [a, b]: [Range<u32>; 2] = value
+            .split(',')
+            .map(|x| {
+                let [start, end]: [u32; 2] = x
+                    .split('-')
+                    .map(|x| Ok(x.parse::<u32>()?))
+                    .collect::<Result<Vec<_>>>()?
+                    .try_into()
+                    .map_err(|_| anyhow!("Expected start-end, not {x}"))?;
+                Ok(Range { start, end })
+            })
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+            .map_err(|_| anyhow!("Expected two ranges, not {value}"))?;
+        Ok(RangePair(a, b))
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<RangePair>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(RangePair::try_from).collect()
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut count = 0;
+        for RangePair(a, b) in pairs {
+            let contained =
+                (a.start <= b.start && a.end >= b.end) || (a.start >= b.start && a.end <= b.end);
+            // println!("{a:?} {b:?} {contained}");
+            if contained {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut count = 0;
+        for RangePair(a, b) in pairs {
+            let overlap = !((a.end < b.start) || (a.start > b.end));
+            // println!("{a:?} {b:?} {overlap}");
+            if overlap {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,128 @@
+//! Day 05
+//!
+//!
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Default)]
+struct Stacks(Vec<Vec<char>>);
+
+impl TryFrom<&str> for Stacks {
+    type Error = anyhow::Error;
+
+    fn try_from(prelude: &str) -> Result<Self> {
+        let mut res = Stacks::default();
+        let prelude = prelude.lines().collect::<Vec<_>>();
+        let highest_stack = prelude.len().checked_sub(1).context("No stacks")?;
+        let num_stacks = prelude[highest_stack].len() / 4 + 1;
+        for _ in 0..num_stacks {
+            res.0.push(Vec::new());
+        }
+        for i in 0..highest_stack {
+            let l = prelude[highest_stack - i - 1].chars().collect::<Vec<_>>();
+            for (j, stack) in res.0.iter_mut().enumerate() {
+                let p = 1 + j * 4;
+                let x = l.get(p).copied().unwrap_or(' ');
+                if x != ' ' {
+                    stack.push(x);
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl Stacks {
+    fn stack(&mut self, n: usize) -> Result<&mut Vec<char>> {
+        self.0
+            .get_mut(n)
+            .with_context(|| format!("No stack {}", n + 1))
+    }
+
+    /// Takes `num` crates off stack `from`, the top one first.
+    fn pop(&mut self, from: usize, num: usize) -> Result<Vec<char>> {
+        let stack = self.stack(from)?;
+        let rest = stack
+            .len()
+            .checked_sub(num)
+            .with_context(|| format!("Stack {} has fewer than {num} crates", from + 1))?;
+        Ok(stack.drain(rest..).rev().collect())
+    }
+
+    fn tops(&self) -> String {
+        self.0.iter().filter_map(|stack| stack.last()).collect()
+    }
+}
+
+/// Moving `num` crates from stack `from` to stack `to`, counting stacks from
+/// zero.
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+impl TryFrom<&str> for Move {
+    type Error = anyhow::Error;
+
+    fn try_from(m: &str) -> Result<Self> {
+        let m_ws = m
+            .split(' ')
+            .filter_map(|x| x.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+        match m_ws[..] {
+            [num, from, to] if from > 0 && to > 0 => Ok(Move {
+                num,
+                from: from - 1,
+                to: to - 1,
+            }),
+            _ => bail!("Couldn't parse move {m}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(file_data: &str) -> Result<Self::Input<'_>> {
+        let file_data = file_data.replace("\r\n", "\n");
+        let (prelude, moves) = file_data
+            .split_once("\n\n")
+            .context("Couldn't split into prelude and moves")?;
+        Ok(Procedure {
+            stacks: Stacks::try_from(prelude)?,
+            moves: moves.lines().map(Move::try_from).collect::<Result<_>>()?,
+        })
+    }
+
+    fn part1(procedure: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut stacks = procedure.stacks.clone();
+        for &Move { num, from, to } in &procedure.moves {
+            let crates = stacks.pop(from, num)?;
+            stacks.stack(to)?.extend(crates);
+        }
+
+        Ok(stacks.tops())
+    }
+
+    fn part2(procedure: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut stacks = procedure.stacks.clone();
+        for &Move { num, from, to } in &procedure.moves {
+            let crates = stacks.pop(from, num)?;
+            stacks.stack(to)?.extend(crates.into_iter().rev());
+        }
+
+        Ok(stacks.tops())
+    }
+}
@@ -0,0 +1,35 @@
+//! Day 06
+//!
+//!
+
+use crate::prelude::*;
+
+fn first_unique(signal: &[char], num_unique: usize) -> Option<usize> {
+    for (i, w) in signal.windows(num_unique).enumerate() {
+        let s: HashSet<char> = HashSet::from_iter(w.iter().copied());
+        if s.len() == num_unique {
+            return Some(i + num_unique);
+        }
+    }
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.trim().chars().collect())
+    }
+
+    fn part1(file_data: &Self::Input<'_>) -> Result<Self::Part1> {
+        first_unique(file_data, 4).context("No start-of-packet marker")
+    }
+
+    fn part2(file_data: &Self::Input<'_>) -> Result<Self::Part2> {
+        first_unique(file_data, 14).context("No start-of-message marker")
+    }
+}
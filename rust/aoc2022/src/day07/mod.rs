@@ -6,33 +6,10 @@ use std::cmp::Reverse;
 
 use relative_path::{Component, RelativePath as Path, RelativePathBuf as PathBuf};
 
-use adventofcode2022::prelude::*;
+use crate::prelude::*;
 use strum::EnumDiscriminants;
 use strum_macros::EnumString;
 
-#[derive(Debug, strum::EnumString, Clone, Copy)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[derive(Debug, strum::EnumString, Clone, Copy)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
-
-#[derive(Debug, Parser)]
-#[command(author, version, about, long_about = None)]
-pub struct Args {
-    #[arg(long)]
-    part: Part,
-    #[arg(long)]
-    input: Input,
-}
-
 #[derive(Debug, EnumDiscriminants, Clone)]
 #[strum_discriminants(derive(EnumString))]
 enum Command {
@@ -45,7 +22,7 @@ impl TryFrom<&str> for Command {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if !value.starts_with("$ ") {
-            panic!("Unexpected non-command");
+            bail!("Expected a command, not {value}");
         }
 
         let (_, cmd) = value
@@ -59,9 +36,9 @@ impl TryFrom<&str> for Command {
         match bare_cmd {
             "ls" => Ok(Command::Ls),
             "cd" => Ok(Command::Cd {
-                relative_dir: args.unwrap().to_owned(),
+                relative_dir: args.context("cd needs a directory")?.to_owned(),
             }),
-            _ => unimplemented!(),
+            _ => bail!("Unknown command {bare_cmd}"),
         }
     }
 }
@@ -86,7 +63,7 @@ impl Entry {
 }
 
 #[derive(Debug)]
-struct Filesystem {
+pub struct Filesystem {
     root: Entry,
 }
 
@@ -97,59 +74,35 @@ impl Filesystem {
         }
     }
 
-    fn get_entries(&mut self, dir: &Path) -> &mut HashMap<String, Entry> {
+    fn get_entries(&mut self, dir: &Path) -> Result<&mut HashMap<String, Entry>> {
         let mut curdir = &mut self.root;
         for component in dir.components() {
-            if let Component::Normal(c) = component {
-                curdir = match curdir {
-                    Entry::Directory { entries, .. } => {
-                        if let Some(curdir) = entries.get_mut(c) {
-                            curdir
-                        } else {
-                            panic!("Unknown path");
-                        }
-                    }
-                    _ => unreachable!(),
-                }
-            } else {
-                unreachable!();
+            let Component::Normal(c) = component else {
+                bail!("Unexpected path {dir}");
+            };
+            curdir = match curdir {
+                Entry::Directory { entries, .. } => entries
+                    .get_mut(c)
+                    .with_context(|| format!("Unknown path {dir}"))?,
+                Entry::File { .. } => bail!("{dir} goes through a file"),
             }
         }
         match curdir {
-            Entry::Directory { entries, .. } => entries,
-            Entry::File { .. } => unreachable!(),
+            Entry::Directory { entries, .. } => Ok(entries),
+            Entry::File { .. } => bail!("{dir} is a file"),
         }
     }
 
-    fn add_dir(&mut self, dir: &Path, dirname: &str) {
-        let entries = self.get_entries(dir);
+    fn add_dir(&mut self, dir: &Path, dirname: &str) -> Result<()> {
+        let entries = self.get_entries(dir)?;
         entries.insert(dirname.to_string(), Entry::new_directory());
+        Ok(())
     }
 
-    fn add_file(&mut self, dir: &Path, filename: &str, size: usize) {
-        let mut curdir = &mut self.root;
-        for component in dir.components() {
-            if let Component::Normal(c) = component {
-                curdir = match curdir {
-                    Entry::Directory { entries, .. } => {
-                        if let Some(curdir) = entries.get_mut(c) {
-                            curdir
-                        } else {
-                            panic!("Unknown path");
-                        }
-                    }
-                    _ => unreachable!(),
-                }
-            } else {
-                unreachable!();
-            }
-        }
-        match curdir {
-            Entry::Directory { entries, .. } => {
-                entries.insert(filename.to_string(), Entry::File { size });
-            }
-            Entry::File { .. } => unreachable!(),
-        }
+    fn add_file(&mut self, dir: &Path, filename: &str, size: usize) -> Result<()> {
+        let entries = self.get_entries(dir)?;
+        entries.insert(filename.to_string(), Entry::File { size });
+        Ok(())
     }
 
     fn populate_sizes(&mut self) {
@@ -230,76 +183,57 @@ impl Filesystem {
     }
 }
 
-fn part1(file_data: &str) -> Result<()> {
+/// Rebuilds the filesystem from the terminal output, with every directory's
+/// size filled in.
+fn fs_from_string(file_data: &str) -> Result<Filesystem> {
     let mut fs = Filesystem::new();
-
-    fs_from_string(file_data, &mut fs)?;
-    fs.populate_sizes();
-    let size = fs.sum_of_dirs_smaller_than_n(100000);
-    dbg!(size);
-    Ok(())
-}
-
-fn fs_from_string(file_data: &str, fs: &mut Filesystem) -> Result<(), anyhow::Error> {
     let mut curdir = PathBuf::from("");
     let mut lines = file_data.lines().peekable();
-    loop {
-        let command = Command::try_from(lines.next().unwrap())?;
+    while let Some(line) = lines.next() {
+        let command = Command::try_from(line)?;
         if let Command::Cd { relative_dir } = &command {
             curdir = curdir.join(relative_dir).normalize();
         }
-        while matches!(lines.peek(), Some(l) if !l.starts_with("$ ")) {
-            match lines.next() {
-                Some(l) if !l.starts_with("$ ") => {
-                    let (a, b) = l.split_once(' ').context("ls output parse fail")?;
-                    if a == "dir" {
-                        fs.add_dir(&curdir, b);
-                    } else if let Ok(size) = a.parse::<usize>() {
-                        fs.add_file(&curdir, b, size);
-                    } else {
-                        unimplemented!();
-                    }
-                }
-                _ => unreachable!(),
+        while let Some(l) = lines.next_if(|l| !l.starts_with("$ ")) {
+            let (a, b) = l.split_once(' ').context("ls output parse fail")?;
+            if a == "dir" {
+                fs.add_dir(&curdir, b)?;
+            } else if let Ok(size) = a.parse::<usize>() {
+                fs.add_file(&curdir, b, size)?;
+            } else {
+                bail!("Unexpected ls output {l}");
             }
         }
-        match lines.peek() {
-            Some(l) if l.starts_with("$ ") => continue,
-            Some(_) => unreachable!(),
-            None => break,
-        }
     }
-    Ok(())
+    fs.populate_sizes();
+    Ok(fs)
 }
 
-fn part2(file_data: &str) -> Result<()> {
-    let mut fs = Filesystem::new();
+pub struct Day07;
 
-    fs_from_string(file_data, &mut fs)?;
-    fs.populate_sizes();
-    let available = 70000000;
-    let unused = available - fs.total_size();
-    let needed = 30000000 - unused;
-    let mut smallest_dirs = fs.smallest_dirs();
-    while let Some(Reverse(x)) = smallest_dirs.pop() {
-        if x > needed {
-            println!("Delete {x}!");
-            break;
-        }
+impl Solution for Day07 {
+    type Input<'a> = Filesystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        fs_from_string(input)
     }
-    Ok(())
-}
 
-fn main() -> Result<()> {
-    let args = Args::parse();
-    println!("Args: {:#?}", args);
+    fn part1(fs: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(fs.sum_of_dirs_smaller_than_n(100000))
+    }
 
-    let file_data = match args.input {
-        Input::ExampleInput => include_str!("example_input"),
-        Input::FinalInput => include_str!("input"),
-    };
-    match args.part {
-        Part::Part1 => part1(file_data),
-        Part::Part2 => part2(file_data),
+    fn part2(fs: &Self::Input<'_>) -> Result<Self::Part2> {
+        let available = 70000000;
+        let unused = available - fs.total_size();
+        let needed = 30000000 - unused;
+        let mut smallest_dirs = fs.smallest_dirs();
+        while let Some(Reverse(x)) = smallest_dirs.pop() {
+            if x > needed {
+                return Ok(x);
+            }
+        }
+        bail!("No directory is big enough to delete")
     }
 }
@@ -0,0 +1,75 @@
+//! Day 08
+//!
+//!
+
+use crate::prelude::*;
+
+use aoc_common::grid::NEIGHBOURS4;
+use aoc_common::Grid;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| {
+            Ok(c.to_digit(10).context("Not a digit")? as usize)
+        })
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        let rows = grid.height();
+        let cols = grid.width();
+        let mut visible: HashSet<(usize, usize)> = HashSet::new();
+
+        // Look in from every edge tree: (col, row) to start from and direction
+        let mut searches: Vec<((usize, usize), (i64, i64))> = Vec::new();
+        for row in 0..rows {
+            searches.push(((0, row), (1, 0)));
+            searches.push(((cols - 1, row), (-1, 0)));
+        }
+        for col in 0..cols {
+            searches.push(((col, 0), (0, 1)));
+            searches.push(((col, rows - 1), (0, -1)));
+        }
+
+        for (start, delta) in searches {
+            let mut tallest_seen = None;
+            let mut pos = Some(start);
+            while let Some(p) = pos {
+                let h = grid[p];
+                if tallest_seen.is_none_or(|tallest_so_far| h > tallest_so_far) {
+                    tallest_seen = Some(h);
+                    visible.insert(p);
+                }
+                pos = grid.offset(p, delta);
+            }
+        }
+
+        Ok(visible.len())
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut best = None;
+        for (p, &initial_height) in grid.iter() {
+            let mut score = 1;
+            for delta in NEIGHBOURS4 {
+                let mut seen = 0;
+                let mut pos = p;
+                while let Some(next) = grid.offset(pos, delta) {
+                    seen += 1;
+                    if grid[next] >= initial_height {
+                        break;
+                    }
+                    pos = next;
+                }
+                score *= seen;
+            }
+            best = best.max(Some(score));
+        }
+        best.context("Empty grid")
+    }
+}
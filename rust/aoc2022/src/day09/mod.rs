@@ -0,0 +1,82 @@
+//! Day 09
+//!
+//! This was fun.
+
+use crate::prelude::*;
+
+use aoc_common::{Direction, Point2};
+
+pub struct Command {
+    dir: Direction,
+    distance: usize,
+}
+
+fn parse_command(line: &str) -> Result<Command> {
+    let (a, b) = line.split_once(' ').context("Missing distance")?;
+    let dir = a.chars().exactly_one().ok().context("Bad direction")?;
+    Ok(Command {
+        dir: Direction::try_from(dir)?,
+        distance: b.parse()?,
+    })
+}
+
+struct Rope {
+    pos: Vec<Point2>,
+    tail_visited: HashSet<Point2>,
+}
+
+impl Rope {
+    fn move_head(&mut self, dir: Direction, distance: usize) {
+        for _ in 0..distance {
+            self.pos[0] += dir.delta();
+            for knot in 1..self.pos.len() {
+                let relative_pos = self.pos[knot - 1] - self.pos[knot];
+                // Touching (including diagonally) means the knot stays put
+                if relative_pos.chebyshev(Point2::ZERO) > 1 {
+                    self.pos[knot] += relative_pos.signum();
+                }
+            }
+            let tail_pos = self.pos[self.pos.len() - 1];
+            self.tail_visited.insert(tail_pos);
+        }
+    }
+
+    fn new(size: usize) -> Self {
+        Self {
+            pos: vec![Point2::ZERO; size],
+            tail_visited: HashSet::new(),
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Command>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(parse_command).collect()
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut rope = Rope::new(2);
+
+        for &Command { dir, distance } in instructions {
+            rope.move_head(dir, distance);
+        }
+
+        Ok(rope.tail_visited.len())
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut rope = Rope::new(10);
+
+        for &Command { dir, distance } in instructions {
+            rope.move_head(dir, distance);
+        }
+
+        Ok(rope.tail_visited.len())
+    }
+}
@@ -0,0 +1,139 @@
+//! Day 10
+//!
+//! This was also fun.
+
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+use aoc_common::{ocr, Grid};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, strum_macros::EnumDiscriminants)]
+#[strum_discriminants(derive(strum_macros::EnumString))]
+#[strum_discriminants(strum(serialize_all = "kebab_case"))]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+#[derive(Debug)]
+struct Cpu {
+    val: i32,
+    prev_val: i32,
+    cycle: usize,
+}
+
+impl Cpu {
+    fn new() -> Cpu {
+        Cpu {
+            val: 1,
+            prev_val: 1,
+            cycle: 0,
+        }
+    }
+
+    fn execute(&mut self, instruction: Instruction) {
+        self.cycle += match InstructionDiscriminants::from(instruction) {
+            InstructionDiscriminants::Addx => 2,
+            InstructionDiscriminants::Noop => 1,
+        };
+        self.prev_val = self.val;
+        match instruction {
+            Instruction::Addx(v) => self.val += v,
+            Instruction::Noop => {}
+        }
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = anyhow::Error;
+    fn try_from(line: &str) -> Result<Self, anyhow::Error> {
+        let (a, b) = if let Some((a, b)) = line.split_once(' ') {
+            (a, Some(b))
+        } else {
+            (line, None)
+        };
+        let instruction = match (InstructionDiscriminants::from_str(a)?, b) {
+            (InstructionDiscriminants::Addx, Some(b)) => Instruction::Addx(b.parse()?),
+            (InstructionDiscriminants::Noop, None) => Instruction::Noop,
+            _ => bail!("Couldn't parse instruction {line}"),
+        };
+        Ok(instruction)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(Instruction::try_from).collect()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut cpu = Cpu::new();
+        let mut next_signal_cycle = 20;
+        let mut signal_strength_sum = 0;
+        for &instruction in program {
+            cpu.execute(instruction);
+            if cpu.cycle >= next_signal_cycle {
+                let signal_strength = (next_signal_cycle as i32) * cpu.prev_val;
+                next_signal_cycle += 40;
+                signal_strength_sum += signal_strength;
+            }
+        }
+        Ok(signal_strength_sum)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> Result<Self::Part2> {
+        ocr::read(&draw_screen(program)?)
+    }
+}
+
+/// The CRT's pixels after running the program, 40 to a row.
+fn draw_screen(program: &[Instruction]) -> Result<Grid<bool>> {
+    let mut cpu = Cpu::new();
+    let mut crt = HashSet::new();
+    for &instruction in program {
+        let cycle = cpu.cycle;
+        let val = cpu.val;
+        cpu.execute(instruction);
+        for c in cycle..cpu.cycle {
+            let sprite_range = val - 1..=val + 1;
+            let cm = (c % 40) as i32;
+            if sprite_range.contains(&cm) {
+                crt.insert(c);
+            }
+        }
+    }
+    let pixels = (0..cpu.cycle).map(|c| crt.contains(&c)).collect();
+    Grid::from_vec(40, cpu.cycle / 40, pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_screen() -> Result<()> {
+        let program = Day10::parse(include_str!("../../../data/2022/day10/example_input"))?;
+        let screen = draw_screen(&program)?;
+        let expected = ocr::pixels(
+            "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+",
+        )?;
+        assert_eq!(screen, expected);
+        // The example doesn't spell anything.
+        assert!(Day10::part2(&program).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! Day 12
+//!
+//!
+
+use crate::prelude::*;
+
+use aoc_common::{grid::Pos, search::bfs, Grid};
+
+pub struct Heightmap {
+    elevations: Grid<u32>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+fn build_heightmap(file_data: &str) -> Result<Heightmap> {
+    let chars: Grid<char> = file_data.parse()?;
+    let start_pos = chars.find(|&c| c == 'S').context("No start")?;
+    let end_pos = chars.find(|&c| c == 'E').context("No end")?;
+    let elevations = chars.map(|&c| match c {
+        'S' => 'a'.into(),
+        'E' => 'z'.into(),
+        c => c.into(),
+    });
+    Ok(Heightmap {
+        elevations,
+        start_pos,
+        end_pos,
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        build_heightmap(input)
+    }
+
+    fn part1(heightmap: &Self::Input<'_>) -> Result<Self::Part1> {
+        let Heightmap {
+            elevations,
+            start_pos,
+            end_pos,
+        } = heightmap;
+
+        let path = bfs(
+            *start_pos,
+            |&pos| {
+                let elevation = elevations[pos];
+                elevations
+                    .neighbours4(pos)
+                    .filter(move |&n_pos| elevations[n_pos] <= elevation + 1)
+            },
+            |pos| pos == end_pos,
+        )
+        .context("No path to the end")?;
+
+        Ok(path.cost)
+    }
+
+    fn part2(heightmap: &Self::Input<'_>) -> Result<Self::Part2> {
+        let Heightmap {
+            elevations,
+            start_pos: _,
+            end_pos,
+        } = heightmap;
+
+        // Walk backwards from the end to the nearest lowest square.
+        let path = bfs(
+            *end_pos,
+            |&pos| {
+                let elevation = elevations[pos];
+                elevations
+                    .neighbours4(pos)
+                    .filter(move |&n_pos| elevations[n_pos] + 1 >= elevation)
+            },
+            |&pos| elevations[pos] == 'a' as u32,
+        )
+        .context("No path from any lowest square")?;
+
+        Ok(path.cost)
+    }
+}
@@ -0,0 +1,83 @@
+//! Day 13
+//!
+//!
+
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+
+pub mod packet;
+
+use packet::Packet;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .split("\n\n")
+            .map(|pair| {
+                let (left, right) = pair
+                    .trim()
+                    .split_once('\n')
+                    .with_context(|| format!("Not a pair of packets: {pair:?}"))?;
+                Ok((left.parse()?, right.parse()?))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut correct = 0;
+        for (i, (left, right)) in pairs.iter().enumerate() {
+            match left.cmp(right) {
+                Ordering::Equal => {
+                    bail!(
+                        "Pair {} doesn't have an order:\n{}",
+                        i + 1,
+                        left.explain(right)
+                    )
+                }
+                Ordering::Less => correct += i + 1,
+                Ordering::Greater => {}
+            }
+        }
+        Ok(correct)
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Result<Self::Part2> {
+        let separator1: Packet = "[[2]]".parse()?;
+        let separator2: Packet = "[[6]]".parse()?;
+        let mut packets: Vec<&Packet> = pairs
+            .iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+        packets.push(&separator1);
+        packets.push(&separator2);
+        packets.sort();
+        // By identity: an input packet can be equal to a divider.
+        let position = |divider: &Packet| {
+            packets
+                .iter()
+                .position(|&x| std::ptr::eq(x, divider))
+                .context("Lost a divider packet")
+        };
+        Ok((position(&separator1)? + 1) * (position(&separator2)? + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let pairs = Day13::parse(include_str!("../../../data/2022/day13/example_input"))?;
+        assert_eq!(Day13::part1(&pairs)?, 13);
+        assert_eq!(Day13::part2(&pairs)?, 140);
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+//! Distress-signal packets: integers and lists of packets, written as JSON.
+//!
+//! Packets order by comparing integers numerically and lists element by
+//! element, with an integer compared against a list as if it were a list of
+//! just that integer.  Equality follows that order too, so `[[2]]` equals
+//! `[2]` and `2`.  [`Packet::explain`] gives the reasoning the way the
+//! puzzle describes it, for working out why a pair came out in an
+//! unexpected order.
+
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Packet {
+    Integer(u64),
+    List(Vec<Packet>),
+}
+
+/// The outcome of comparing two packets, with each step that led to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    pub steps: Vec<String>,
+}
+
+/// The steps as an indented list, like the puzzle's walkthrough.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Where [`Packet::compare`] reports its steps, with their depth.  The steps
+/// are only formatted if there is somewhere to send them.
+type Trace<'t> = Option<&'t mut dyn FnMut(usize, fmt::Arguments<'_>)>;
+
+fn note(trace: &mut Trace, depth: usize, step: fmt::Arguments<'_>) {
+    if let Some(trace) = trace {
+        trace(depth, step);
+    }
+}
+
+impl Packet {
+    /// Compares `self` (the left packet) with `other`, noting each step.
+    pub fn explain(&self, other: &Packet) -> Explanation {
+        let mut steps = vec![];
+        let ordering = self.compare(
+            other,
+            0,
+            &mut Some(&mut |depth, step| steps.push(format!("{}- {step}", "  ".repeat(depth)))),
+        );
+        Explanation { ordering, steps }
+    }
+
+    fn compare(&self, other: &Packet, depth: usize, trace: &mut Trace) -> Ordering {
+        note(trace, depth, format_args!("Compare {self} vs {other}"));
+        let ordering = match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => {
+                let decided = a
+                    .iter()
+                    .zip(b)
+                    .map(|(a, b)| a.compare(b, depth + 1, trace))
+                    .find(|&ordering| ordering != Ordering::Equal);
+                if let Some(ordering) = decided {
+                    // The element that decided it has already said why.
+                    return ordering;
+                }
+                let ordering = a.len().cmp(&b.len());
+                match ordering {
+                    Ordering::Less => {
+                        note(trace, depth + 1, format_args!("Left side ran out of items"))
+                    }
+                    Ordering::Greater => note(
+                        trace,
+                        depth + 1,
+                        format_args!("Right side ran out of items"),
+                    ),
+                    Ordering::Equal => {}
+                }
+                return ordering;
+            }
+            (Packet::Integer(a), Packet::List(_)) => {
+                note(
+                    trace,
+                    depth + 1,
+                    format_args!("Mixed types; convert left to [{a}]"),
+                );
+                return Packet::List(vec![Packet::Integer(*a)]).compare(other, depth + 1, trace);
+            }
+            (Packet::List(_), Packet::Integer(b)) => {
+                note(
+                    trace,
+                    depth + 1,
+                    format_args!("Mixed types; convert right to [{b}]"),
+                );
+                return self.compare(&Packet::List(vec![Packet::Integer(*b)]), depth + 1, trace);
+            }
+        };
+        match ordering {
+            Ordering::Less => note(trace, depth + 1, format_args!("Left side is smaller")),
+            Ordering::Greater => note(trace, depth + 1, format_args!("Right side is smaller")),
+            Ordering::Equal => {}
+        }
+        ordering
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other, 0, &mut None)
+    }
+}
+
+/// Packets are equal when neither comes first, which isn't the same as
+/// being written the same way.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Writes the packet back out as compact JSON.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(n) => write!(f, "{n}"),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Packet> {
+        serde_json::from_str(s).with_context(|| format!("Not a packet: {s:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(s: &str) -> Packet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for s in ["[]", "[[[]]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[10,[]]"] {
+            assert_eq!(packet(s).to_string(), s);
+            assert_eq!(serde_json::to_string(&packet(s)).unwrap(), s);
+        }
+        assert_eq!(packet(" [ 1, [2] ]\n").to_string(), "[1,[2]]");
+        for bad in ["[1,", "[-1]", "[1.5]", "{}", "[\"a\"]"] {
+            assert!(bad.parse::<Packet>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = packet("[[1],[2,3,4]]").explain(&packet("[[1],4]"));
+        assert_eq!(explanation.ordering, Ordering::Less);
+        assert_eq!(
+            explanation.to_string(),
+            "\
+- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4]
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller
+"
+        );
+
+        let explanation = packet("[7,7,7,7]").explain(&packet("[7,7,7]"));
+        assert_eq!(explanation.ordering, Ordering::Greater);
+        assert_eq!(
+            explanation.steps.last().unwrap(),
+            "  - Right side ran out of items"
+        );
+        assert_eq!(
+            packet("[[4,4],4,4]")
+                .explain(&packet("[[4,4],4,4,4]"))
+                .ordering,
+            Ordering::Less
+        );
+        assert_eq!(packet("[[2]]").cmp(&packet("[2]")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_eq_agrees_with_cmp() {
+        let packets = ["2", "[2]", "[[2]]", "[2,2]", "[]", "[[]]", "[3]", "[[1],2]"].map(packet);
+        for a in &packets {
+            for b in &packets {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{a} vs {b}");
+            }
+        }
+        assert_eq!(packet("[[2]]"), packet("2"));
+        assert_ne!(packet("[]"), packet("[[]]"));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //!
 
-use adventofcode2022::prelude::*;
+use crate::prelude::*;
 
 use nom::{
     bytes::complete::tag,
@@ -12,30 +12,6 @@ use nom::{
     IResult,
 };
 
-#[derive(Debug, strum::EnumString, Clone, Copy)]
-#[strum(serialize_all = "snake_case")]
-enum Part {
-    Part1,
-    Part2,
-}
-
-#[derive(Debug, strum::EnumString, Clone, Copy)]
-#[allow(clippy::enum_variant_names)]
-#[strum(serialize_all = "snake_case")]
-enum Input {
-    ExampleInput,
-    FinalInput,
-}
-
-#[derive(Debug, Parser)]
-#[command(author, version, about, long_about = None)]
-pub struct Args {
-    #[arg(long)]
-    part: Part,
-    #[arg(long)]
-    input: Input,
-}
-
 fn parse_input(input: &str) -> IResult<&str, Vec<Vec<(u64, u64)>>> {
     separated_list1(
         line_ending,
@@ -43,12 +19,14 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Vec<(u64, u64)>>> {
     )(input)
 }
 
+#[derive(Debug, Clone, Copy)]
 enum GridContent {
     Wall,
     Sand,
 }
 
-struct Grid {
+#[derive(Debug, Clone)]
+pub struct Grid {
     grid: HashMap<(u64, u64), GridContent>,
     lowest_point: u64,
     floor: Option<u64>,
@@ -58,7 +36,7 @@ impl TryFrom<&str> for Grid {
     type Error = anyhow::Error;
 
     fn try_from(input: &str) -> Result<Self> {
-        let (_, walls) = parse_input(input).unwrap();
+        let (_, walls) = parse_input(input.trim_end()).map_err(|e| e.to_owned())?;
         let mut grid = HashMap::new();
         let mut lowest_point = 0;
         for wall in walls {
@@ -128,42 +106,38 @@ impl Grid {
     }
 }
 
-fn part1(file_data: &str) -> Result<()> {
-    let mut grid = Grid::try_from(file_data)?;
-    let mut sand_units = 0;
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    while grid.drop_sand((0, 500)).is_some() {
-        sand_units += 1;
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::try_from(input)
     }
-    dbg!(sand_units);
-    Ok(())
-}
 
-fn part2(file_data: &str) -> Result<()> {
-    let grid = Grid::try_from(file_data)?;
-    let floor = grid.lowest_point + 2;
-    let mut grid = grid.with_floor(floor);
-    let mut sand_units = 0;
-    while let Some(pos) = grid.drop_sand((0, 500)) {
-        sand_units += 1;
-        if pos == (0, 500) {
-            break;
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut grid = grid.clone();
+        let mut sand_units = 0;
+
+        while grid.drop_sand((0, 500)).is_some() {
+            sand_units += 1;
         }
+        Ok(sand_units)
     }
-    dbg!(sand_units);
-    Ok(())
-}
 
-fn main() -> Result<()> {
-    let args = Args::parse();
-    println!("Args: {:#?}", args);
-
-    let file_data = match args.input {
-        Input::ExampleInput => include_str!("example_input"),
-        Input::FinalInput => include_str!("input"),
-    };
-    match args.part {
-        Part::Part1 => part1(file_data),
-        Part::Part2 => part2(file_data),
+    fn part2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        let grid = grid.clone();
+        let floor = grid.lowest_point + 2;
+        let mut grid = grid.with_floor(floor);
+        let mut sand_units = 0;
+        while let Some(pos) = grid.drop_sand((0, 500)) {
+            sand_units += 1;
+            if pos == (0, 500) {
+                break;
+            }
+        }
+        Ok(sand_units)
     }
 }
@@ -0,0 +1,154 @@
+//! Day 15
+//!
+//! Always useful to read.  Manhattan distance is way less work than the
+//! alternative.  Guess which one I wrote first?  Guess whether that wrong
+//! approach works for the example input!
+//!
+//! For part 1, I almost tried to do something fancy with ranges instead
+//! of just populating the entire covered space in a set.  But fancy is
+//! slow to write and complicated.
+//!
+//! For part 2, if there's literally only one place not covered by the
+//! sensors, it has to be exactly one away from the range of a sensor.
+//! (Was not looking forward to trying to do space partitioning.)
+
+use crate::prelude::*;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64 as nom_i64, line_ending},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use aoc_common::{Bounds2, Point2};
+
+fn parse_input(input: &str) -> IResult<&str, HashMap<Point2, Point2>> {
+    // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    let (input, pairs) = separated_list1(
+        line_ending,
+        tuple((
+            preceded(tag("Sensor at x="), nom_i64),
+            preceded(tag(", y="), nom_i64),
+            preceded(tag(": closest beacon is at x="), nom_i64),
+            preceded(tag(", y="), nom_i64),
+        )),
+    )(input)?;
+    Ok((
+        input,
+        pairs
+            .into_iter()
+            .map(|(sx, sy, bx, by)| (Point2::new(sx, sy), Point2::new(bx, by)))
+            .collect::<HashMap<_, _>>(),
+    ))
+}
+
+/// The sensor report, along with the puzzle parameters that differ between
+/// the example and the real input.
+pub struct Report {
+    sensor_closest_beacons: HashMap<Point2, Point2>,
+    /// The row to count covered positions in for part 1.
+    line_no: i64,
+    /// The largest coordinate the distress beacon can be at in part 2.
+    range_max: i64,
+}
+
+fn within_sensor_range(pos: Point2, sensors: &Vec<(Point2, i64)>) -> bool {
+    for &(spos, sd) in sensors {
+        let d = pos.manhattan(spos);
+        if d <= sd {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Report;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, sensor_closest_beacons) =
+            parse_input(input).map_err(|e| anyhow::anyhow!("Couldn't parse report: {e}"))?;
+        // The example is much smaller than the real input, and asks about a
+        // different row and search area.
+        let is_example = sensor_closest_beacons.keys().all(|spos| spos.x.abs() < 1000);
+        let (line_no, range_max) = if is_example {
+            (10, 20)
+        } else {
+            (2_000_000, 4_000_000)
+        };
+        Ok(Report {
+            sensor_closest_beacons,
+            line_no,
+            range_max,
+        })
+    }
+
+    fn part1(report: &Self::Input<'_>) -> Result<Self::Part1> {
+        let line_no = report.line_no;
+        let mut covered = HashSet::new();
+        let mut beacons = HashSet::new();
+        for (spos, bpos) in report.sensor_closest_beacons.iter() {
+            if bpos.y == line_no {
+                beacons.insert(bpos.x);
+            }
+            let sensor_clear_distance = spos.manhattan(*bpos);
+            let sensor_to_line_no_distance = (spos.y - line_no).abs();
+            if sensor_to_line_no_distance > sensor_clear_distance {
+                continue;
+            }
+
+            let x_delta = sensor_clear_distance - sensor_to_line_no_distance;
+            let x_start = spos.x - x_delta;
+            let x_end = spos.x + x_delta;
+
+            for x in x_start..=x_end {
+                covered.insert(x);
+            }
+        }
+        let covered_without_beacons: HashSet<_> = covered.difference(&beacons).collect();
+        Ok(covered_without_beacons.len())
+    }
+
+    fn part2(report: &Self::Input<'_>) -> Result<Self::Part2> {
+        let search_area = Bounds2 {
+            min: Point2::ZERO,
+            max: Point2::new(report.range_max, report.range_max),
+        };
+        let sensors_with_distances = report
+            .sensor_closest_beacons
+            .iter()
+            .map(|(&spos, &bpos)| (spos, spos.manhattan(bpos)))
+            .collect::<Vec<_>>();
+
+        for &(spos, d) in &sensors_with_distances {
+            // Walk the diamond just out of this sensor's range
+            let d = d + 1;
+            let explore = [
+                (Point2::new(0, d), Point2::new(1, -1), Point2::new(d, 0)),
+                (Point2::new(d, 0), Point2::new(-1, -1), Point2::new(0, -d)),
+                (Point2::new(0, -d), Point2::new(-1, 1), Point2::new(-d, 0)),
+                (Point2::new(-d, 0), Point2::new(1, 1), Point2::new(0, d)),
+            ];
+            for (start, dpos, end) in explore {
+                let (mut pos, final_pos) = (spos + start, spos + end);
+                while pos != final_pos {
+                    if search_area.contains(pos)
+                        && !within_sensor_range(pos, &sensors_with_distances)
+                    {
+                        let tuning_frequency = (pos.x * 4000000) + pos.y;
+                        return Ok(tuning_frequency);
+                    }
+                    pos += dpos;
+                }
+            }
+        }
+
+        bail!("No uncovered position found")
+    }
+}
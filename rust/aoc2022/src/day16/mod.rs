@@ -0,0 +1,319 @@
+//! Day 16
+//!
+//! Ugh.
+
+use crate::prelude::*;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending, u64 as nom_u64},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+struct Valve {
+    flow_rate: u64,
+    tunnels: HashSet<String>,
+}
+
+impl Valve {
+    fn new(flow_rate: u64, tunnels: Vec<&str>) -> Self {
+        Self {
+            flow_rate,
+            tunnels: tunnels.into_iter().map(|x| x.to_owned()).collect(),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, HashMap<&str, Valve>> {
+    // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    let (input, valve_data) = separated_list1(
+        line_ending,
+        tuple((
+            preceded(tag("Valve "), alpha1),
+            preceded(tag(" has flow rate="), nom_u64),
+            preceded(
+                alt((
+                    tag("; tunnels lead to valves "),
+                    tag("; tunnel leads to valve "),
+                )),
+                separated_list1(tag(", "), alpha1),
+            ),
+        )),
+    )(input)?;
+    Ok((
+        input,
+        valve_data
+            .into_iter()
+            .map(|(name, flow_rate, tunnels)| (name, Valve::new(flow_rate, tunnels)))
+            .collect(),
+    ))
+}
+
+/// The valves worth opening, with the shortest times between them: walking
+/// through a valve that's not worth opening is just part of the walk.
+#[derive(Debug, Clone)]
+pub struct Network {
+    flow_rates: Vec<u64>,
+    /// The minutes from the starting valve to each valve worth opening.
+    from_start: Vec<Option<u64>>,
+    /// The minutes between each pair of valves worth opening.
+    distances: Vec<Vec<Option<u64>>>,
+}
+
+impl Network {
+    /// The most valves we'll split between two actors, since that goes
+    /// through every subset of them.
+    const MAX_SHARED_VALVES: usize = 20;
+
+    /// The most valves we'll split between three or more actors, since each
+    /// actor after the second goes through every subset of every subset of
+    /// them: 3^15 is about 14 million.
+    const MAX_TEAM_VALVES: usize = 15;
+
+    fn new(valves: &HashMap<&str, Valve>, start: &str) -> Result<Network> {
+        let names = valves.keys().copied().sorted().collect_vec();
+        let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let start = *index
+            .get(start)
+            .with_context(|| format!("No valve {start}"))?;
+
+        // Floyd-Warshall.
+        let n = names.len();
+        let mut distances = vec![vec![None; n]; n];
+        for (i, name) in names.iter().enumerate() {
+            distances[i][i] = Some(0);
+            for tunnel in &valves[name].tunnels {
+                let j = *index
+                    .get(tunnel.as_str())
+                    .with_context(|| format!("{name} leads to a missing valve {tunnel}"))?;
+                distances[i][j] = Some(1);
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if let (Some(a), Some(b)) = (distances[i][k], distances[k][j]) {
+                        if distances[i][j].is_none_or(|d| a + b < d) {
+                            distances[i][j] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+
+        let useful = (0..n)
+            .filter(|&i| valves[names[i]].flow_rate > 0)
+            .collect_vec();
+        if useful.len() > 63 {
+            bail!("{} valves worth opening is too many", useful.len());
+        }
+        Ok(Network {
+            flow_rates: useful.iter().map(|&i| valves[names[i]].flow_rate).collect(),
+            from_start: useful.iter().map(|&j| distances[start][j]).collect(),
+            distances: useful
+                .iter()
+                .map(|&i| useful.iter().map(|&j| distances[i][j]).collect())
+                .collect(),
+        })
+    }
+
+    /// The most pressure one actor can release in `minutes` by opening
+    /// exactly each set of valves (as a bitmask) it can get round to.
+    fn best_by_subset(&self, minutes: u64) -> HashMap<u64, u64> {
+        let mut best = HashMap::new();
+        self.visit(None, minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn visit(
+        &self,
+        at: Option<usize>,
+        minutes_left: u64,
+        opened: u64,
+        pressure: u64,
+        best: &mut HashMap<u64, u64>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = (*entry).max(pressure);
+        for next in 0..self.flow_rates.len() {
+            if opened & 1 << next != 0 {
+                continue;
+            }
+            let distance = match at {
+                None => self.from_start[next],
+                Some(at) => self.distances[at][next],
+            };
+            // Walk there and spend a minute opening it.
+            let Some(minutes) = distance.map(|d| d + 1) else {
+                continue;
+            };
+            if minutes < minutes_left {
+                let remaining = minutes_left - minutes;
+                self.visit(
+                    Some(next),
+                    remaining,
+                    opened | 1 << next,
+                    pressure + self.flow_rates[next] * remaining,
+                    best,
+                );
+            }
+        }
+    }
+
+    /// The most pressure `actors` working together can release in
+    /// `minutes`.  Each valve only needs opening once, so the actors split
+    /// the valves between them, each doing the best it can with its share.
+    pub fn max_pressure(&self, minutes: u64, actors: usize) -> Result<u64> {
+        let best = self.best_by_subset(minutes);
+        if actors <= 1 {
+            return Ok(if actors == 0 {
+                0
+            } else {
+                best.values().copied().max().unwrap_or(0)
+            });
+        }
+
+        let valves = self.flow_rates.len();
+        let max_valves = if actors == 2 {
+            Self::MAX_SHARED_VALVES
+        } else {
+            Self::MAX_TEAM_VALVES
+        };
+        if valves > max_valves {
+            bail!("Too many valves ({valves}) to split between {actors} actors");
+        }
+        // The best an actor can do with the valves in each subset, whether
+        // or not it opens all of them.
+        let mut within = vec![0; 1 << valves];
+        for (&opened, &pressure) in &best {
+            within[opened as usize] = pressure;
+        }
+        for bit in 0..valves {
+            for subset in 0..within.len() {
+                if subset & 1 << bit != 0 {
+                    within[subset] = within[subset].max(within[subset ^ 1 << bit]);
+                }
+            }
+        }
+
+        // The best a team can do with each subset, growing the team one
+        // actor at a time.
+        let all = within.len() - 1;
+        let mut team = within.clone();
+        for _ in 2..actors {
+            team = (0..within.len())
+                .map(|subset| {
+                    submasks(subset)
+                        .map(|share| within[share] + team[subset ^ share])
+                        .max()
+                        .unwrap()
+                })
+                .collect();
+        }
+        Ok(submasks(all)
+            .map(|share| within[share] + team[all ^ share])
+            .max()
+            .unwrap())
+    }
+}
+
+/// Every subset of `set`, itself and the empty set included.
+fn submasks(set: usize) -> impl Iterator<Item = usize> {
+    let mut next = Some(set);
+    std::iter::from_fn(move || {
+        let subset = next?;
+        next = (subset != 0).then(|| (subset - 1) & set);
+        Some(subset)
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Network;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, valves) = parse_input(input.trim_end()).map_err(|e| e.to_owned())?;
+        Network::new(&valves, "AA")
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Result<Self::Part1> {
+        network.max_pressure(30, 1)
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Result<Self::Part2> {
+        network.max_pressure(26, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part1() -> Result<()> {
+        let network = Day16::parse(include_str!("../../../data/2022/day16/example_input"))?;
+        assert_eq!(network.flow_rates.len(), 6);
+        assert_eq!(Day16::part1(&network)?, 1651);
+        assert_eq!(Day16::part2(&network)?, 1707);
+        Ok(())
+    }
+
+    #[test]
+    fn test_actors_and_minutes() -> Result<()> {
+        let network = Day16::parse(include_str!("../../../data/2022/day16/example_input"))?;
+        assert_eq!(network.max_pressure(26, 0)?, 0);
+        assert_eq!(network.max_pressure(1, 3)?, 0);
+        // BB and DD are each a walk and an opening away, leaving a minute:
+        // one actor picks DD for 20, two get BB's 13 as well.
+        assert_eq!(network.max_pressure(3, 1)?, 20);
+        assert_eq!(network.max_pressure(3, 2)?, 33);
+        let mut previous = 0;
+        for actors in 1..=6 {
+            let pressure = network.max_pressure(26, actors)?;
+            assert!(pressure >= previous);
+            previous = pressure;
+        }
+        // With as many actors as valves, each can head straight for one.
+        let direct: u64 = network
+            .flow_rates
+            .iter()
+            .zip(&network.from_start)
+            .map(|(rate, distance)| rate * (26 - distance.unwrap() - 1))
+            .sum();
+        assert_eq!(network.max_pressure(26, 6)?, direct);
+        assert_eq!(submasks(0b101).collect_vec(), [0b101, 0b100, 0b001, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_three_actors() -> Result<()> {
+        let network = Day16::parse(include_str!("../../../data/2022/day16/example_input"))?;
+        // Every way of giving three actors separate valves to open.
+        let best = network.best_by_subset(26);
+        let mut brute_force = 0;
+        for ((&a, &pa), (&b, &pb), (&c, &pc)) in best.iter().tuple_combinations() {
+            if a & b == 0 && a & c == 0 && b & c == 0 {
+                brute_force = brute_force.max(pa + pb + pc);
+            }
+        }
+        assert_eq!(network.max_pressure(26, 3)?, brute_force);
+        assert_eq!(network.max_pressure(26, 3)?, 1794);
+
+        // Too many valves for a team, though not for a pair.
+        let valves = Network::MAX_TEAM_VALVES + 1;
+        let network = Network {
+            flow_rates: vec![1; valves],
+            from_start: vec![Some(1); valves],
+            distances: vec![vec![Some(1); valves]; valves],
+        };
+        assert_eq!(network.max_pressure(1, 2)?, 0);
+        assert!(network.max_pressure(1, 3).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+//! A narrow chamber that rocks fall into, pushed side to side by jets of
+//! gas, Tetris-style.
+//!
+//! Each row of the chamber is a bitmask, with bit `x` set when column `x` is
+//! rock.  Rows that no falling rock could ever reach again are thrown away,
+//! so the chamber stays small however tall the tower gets.
+
+use std::{fmt, str::FromStr};
+
+use crate::prelude::*;
+use aoc_common::cycle::find_cycle_by_key;
+
+/// How far from the left wall each rock appears.
+const LEFT_GAP: usize = 2;
+/// How many empty rows each rock appears above the top of the tower.
+const DROP_GAP: usize = 3;
+/// The widest chamber a row's bitmask has room for.
+const MAX_WIDTH: usize = 32;
+/// How far down into the tower a falling rock may be able to get before
+/// [`Chamber::height_after`] gives up: a column that never closes over keeps
+/// that growing, so the chamber never repeats.
+const MAX_REACH: usize = 1000;
+
+/// A rock, as a bitmask per row from the bottom up, pushed against the left
+/// wall.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    rows: Vec<u32>,
+    width: usize,
+}
+
+/// Parses a drawing of the rock, like the puzzle's, with `#` for rock.
+impl FromStr for Shape {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Shape> {
+        let mut rows = vec![];
+        for line in s.lines().rev() {
+            let mut row = 0_u32;
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' if x < MAX_WIDTH => row |= 1 << x,
+                    '#' => bail!("Rock is more than {MAX_WIDTH} wide"),
+                    '.' => {}
+                    _ => bail!("Unexpected {c:?} in rock"),
+                }
+            }
+            if row == 0 {
+                bail!("Rock has an empty row:\n{s}");
+            }
+            rows.push(row);
+        }
+        let all = rows.iter().fold(0, |all, row| all | row);
+        if all == 0 {
+            bail!("Rock is empty");
+        }
+        let shift = all.trailing_zeros();
+        Ok(Shape {
+            rows: rows.into_iter().map(|row| row >> shift).collect(),
+            width: (u32::BITS - (all >> shift).leading_zeros()) as usize,
+        })
+    }
+}
+
+/// Parses rocks drawn one after another, separated by blank lines.
+fn parse_shapes(s: &str) -> Result<Vec<Shape>> {
+    s.trim()
+        .split("\n\n")
+        .map(|drawing| drawing.trim_matches('\n').parse())
+        .collect()
+}
+
+/// How wide the chamber is and which rocks fall into it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    width: usize,
+    shapes: Vec<Shape>,
+}
+
+/// Parses a drawing of a row of the empty chamber, like `|.......|`, and
+/// then the rocks in the order they fall, all separated by blank lines.
+impl FromStr for Layout {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Layout> {
+        let (chamber, rocks) = s
+            .trim()
+            .split_once("\n\n")
+            .context("No rocks after the chamber")?;
+        let width = chamber
+            .strip_prefix('|')
+            .and_then(|row| row.strip_suffix('|'))
+            .filter(|row| row.chars().all(|c| c == '.'))
+            .with_context(|| format!("Not an empty row of the chamber: {chamber:?}"))?
+            .len();
+        if width > MAX_WIDTH {
+            bail!("The chamber can be at most {MAX_WIDTH} wide");
+        }
+        let shapes = parse_shapes(rocks)?;
+        if let Some(shape) = shapes.iter().find(|s| LEFT_GAP + s.width > width) {
+            bail!(
+                "A rock {} wide doesn't fit {LEFT_GAP} from the wall of a chamber {width} wide",
+                shape.width
+            );
+        }
+        Ok(Layout { width, shapes })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// The jet pattern, repeating forever.
+#[derive(Debug, Clone)]
+pub struct Jets {
+    pattern: Vec<Jet>,
+    next: usize,
+}
+
+impl Jets {
+    /// Where in the pattern the next jet comes from.
+    pub fn position(&self) -> usize {
+        self.next
+    }
+}
+
+impl Iterator for Jets {
+    type Item = Jet;
+
+    fn next(&mut self) -> Option<Jet> {
+        let jet = self.pattern[self.next];
+        self.next = (self.next + 1) % self.pattern.len();
+        Some(jet)
+    }
+}
+
+impl FromStr for Jets {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Jets> {
+        let pattern = s
+            .trim()
+            .chars()
+            .map(|c| match c {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => bail!("Unexpected {c:?} in jet pattern"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if pattern.is_empty() {
+            bail!("No jets");
+        }
+        Ok(Jets { pattern, next: 0 })
+    }
+}
+
+/// Everything that decides how the tower grows from here, relative to its
+/// height: which rock falls next, where the jets are up to, and the empty
+/// cells at the top that a rock could get into.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChamberState {
+    pub shape: usize,
+    pub jet: usize,
+    /// The reachable cells in each row, from the top of the tower down.
+    pub profile: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Chamber {
+    width: usize,
+    shapes: Vec<Shape>,
+    jets: Jets,
+    /// The rows kept, from the bottom up, with no empty rows at the top.
+    rows: Vec<u32>,
+    /// How many rows have been thrown away beneath `rows`.
+    floor: usize,
+    rocks: usize,
+}
+
+impl Chamber {
+    pub fn new(layout: Layout, jets: Jets) -> Chamber {
+        Chamber {
+            width: layout.width,
+            shapes: layout.shapes,
+            jets,
+            rows: vec![],
+            floor: 0,
+            rocks: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.floor + self.rows.len()
+    }
+
+    fn full_row(&self) -> u32 {
+        u32::MAX >> (u32::BITS as usize - self.width)
+    }
+
+    /// Whether `shape` can be at column `x` with its bottom in row `y`.
+    fn fits(&self, shape: &Shape, x: usize, y: usize) -> bool {
+        shape
+            .rows
+            .iter()
+            .enumerate()
+            .all(|(i, row)| match (y + i).checked_sub(self.floor) {
+                Some(kept) => self.rows.get(kept).is_none_or(|r| r & (row << x) == 0),
+                None => false,
+            })
+    }
+
+    /// Drops the next rock until it lands.
+    pub fn drop_rock(&mut self) {
+        let shape = self.shapes[self.rocks % self.shapes.len()].clone();
+        let (mut x, mut y) = (LEFT_GAP, self.height() + DROP_GAP);
+        loop {
+            let pushed = match self.jets.next().unwrap() {
+                Jet::Left => x.checked_sub(1),
+                Jet::Right => Some(x + 1).filter(|x| x + shape.width <= self.width),
+            };
+            if let Some(pushed) = pushed.filter(|&pushed| self.fits(&shape, pushed, y)) {
+                x = pushed;
+            }
+            if y == 0 || !self.fits(&shape, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (i, row) in shape.rows.iter().enumerate() {
+            let kept = y + i - self.floor;
+            if kept >= self.rows.len() {
+                self.rows.resize(kept + 1, 0);
+            }
+            self.rows[kept] |= row << x;
+        }
+        self.rocks += 1;
+
+        // A rock can only get as far down as the lowest reachable row, and
+        // needs the row beneath that to land on.
+        let Some(profile) = self.profile() else {
+            return;
+        };
+        let lowest = self.height() - profile.len();
+        if lowest > self.floor + 1 {
+            self.rows.drain(..lowest - 1 - self.floor);
+            self.floor = lowest - 1;
+        }
+    }
+
+    /// The empty cells a falling rock could reach, row by row from the top
+    /// of the tower down to the lowest row with any.  Rocks only move down
+    /// and sideways, so neither does the search; anything it doesn't reach
+    /// can never matter again.  `None` if that goes more than [`MAX_REACH`]
+    /// rows down.
+    fn profile(&self) -> Option<Vec<u32>> {
+        let full = self.full_row();
+        let mut profile = vec![];
+        let mut above = full;
+        for row in self.rows.iter().rev() {
+            let empty = !row & full;
+            let mut reached = above & empty;
+            loop {
+                let spread = (reached | reached << 1 | reached >> 1) & empty;
+                if spread == reached {
+                    break;
+                }
+                reached = spread;
+            }
+            if reached == 0 {
+                break;
+            }
+            if profile.len() == MAX_REACH {
+                return None;
+            }
+            profile.push(reached);
+            above = reached;
+        }
+        Some(profile)
+    }
+
+    pub fn state(&self) -> Result<ChamberState> {
+        let profile = self.profile().with_context(|| {
+            format!("A rock could fall more than {MAX_REACH} rows into the tower")
+        })?;
+        Ok(ChamberState {
+            shape: self.rocks % self.shapes.len(),
+            jet: self.jets.position(),
+            profile,
+        })
+    }
+
+    /// The height of the tower once `rocks` more rocks have fallen.  Once the
+    /// chamber is back in a state it's been in, the tower grows the same way
+    /// again, so only one cycle's worth of rocks needs dropping.  Fails if
+    /// the tower stays open so far down that it might never repeat.
+    pub fn height_after(mut self, rocks: usize) -> Result<usize> {
+        let base = self.height();
+        let first = (self.state()?, 0);
+        let mut error = None;
+        let dropped = std::iter::from_fn(|| {
+            self.drop_rock();
+            match self.state() {
+                Ok(state) => Some((state, (self.height() - base) as i64)),
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            }
+        });
+        let mut states = std::iter::once(first).chain(dropped).take(rocks + 1);
+        let found = find_cycle_by_key(&mut states, |(state, _)| state.clone());
+        drop(states);
+        if let Some(err) = error {
+            return Err(err);
+        }
+        let Some((cycle, history)) = found else {
+            // All the rocks fell before anything repeated.
+            return Ok(self.height());
+        };
+        let heights = history.iter().map(|&(_, height)| height).collect_vec();
+        Ok(base + cycle.extrapolate(&heights, rocks) as usize)
+    }
+}
+
+/// Draws the kept rows as the puzzle does, with `~` for a floor of rows
+/// thrown away.
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows.iter().rev() {
+            write!(f, "|")?;
+            for x in 0..self.width {
+                write!(f, "{}", if row & (1 << x) != 0 { '#' } else { '.' })?;
+            }
+            writeln!(f, "|")?;
+        }
+        let floor = if self.floor == 0 { "-" } else { "~" };
+        write!(f, "+{}+", floor.repeat(self.width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day17::LAYOUT;
+
+    fn example() -> Result<Chamber> {
+        let jets = include_str!("../../../data/2022/day17/example_input").parse()?;
+        Ok(Chamber::new(LAYOUT.parse()?, jets))
+    }
+
+    #[test]
+    fn test_drop_rock() -> Result<()> {
+        let mut chamber = example()?;
+        chamber.drop_rock();
+        assert_eq!(chamber.to_string(), "|..####.|\n+-------+");
+        chamber.drop_rock();
+        assert_eq!(
+            chamber.to_string(),
+            "|...#...|\n|..###..|\n|...#...|\n|..####.|\n+-------+"
+        );
+        for _ in 2..10 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 17);
+        assert_eq!(chamber.state()?.shape, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_height_after() -> Result<()> {
+        assert_eq!(example()?.height_after(0)?, 0);
+        assert_eq!(example()?.height_after(10)?, 17);
+        let mut chamber = example()?;
+        for _ in 0..2022 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 3068);
+        // Most of the tower has been thrown away.
+        assert!(chamber.rows.len() < 100, "{}", chamber.rows.len());
+        assert_eq!(example()?.height_after(2022)?, 3068);
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_chambers() -> Result<()> {
+        // Dominoes lying down and standing up, all blown against the left
+        // wall.
+        let layout = "|....|\n\n##\n\n#\n#".parse()?;
+        let mut chamber = Chamber::new(layout, "<".parse()?);
+        chamber.drop_rock();
+        chamber.drop_rock();
+        assert_eq!(chamber.to_string(), "|#...|\n|#...|\n|##..|\n+----+");
+        assert_eq!(chamber.state()?.profile, [0b1110, 0b1110, 0b1100]);
+
+        // Skipping ahead by cycles gives the same height as dropping every
+        // rock.
+        let layout = "|.....|\n\n##\n##\n\n#\n\n###".parse()?;
+        let chamber = Chamber::new(layout, "><<><>>><".parse()?);
+        let mut dropped = chamber.clone();
+        for _ in 0..5000 {
+            dropped.drop_rock();
+        }
+        assert_eq!(chamber.height_after(5000)?, dropped.height());
+
+        assert!("<>x".parse::<Jets>().is_err());
+        assert!(parse_shapes("#\n.\n#").is_err());
+        assert_eq!(
+            parse_shapes(".#\n##")?,
+            [Shape {
+                rows: vec![0b11, 0b10],
+                width: 2
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_layout() -> Result<()> {
+        let layout: Layout = LAYOUT.parse()?;
+        assert_eq!(layout.width, 7);
+        assert_eq!(layout.shapes.len(), 5);
+        // Too narrow for the L, 2 from the wall.
+        assert!("|....|\n\n#\n\n..#\n..#\n###".parse::<Layout>().is_err());
+        assert!("|...|\n\n#".parse::<Layout>().is_ok());
+        assert!(format!("|{}|\n\n#", ".".repeat(MAX_WIDTH + 1))
+            .parse::<Layout>()
+            .is_err());
+        for bad in ["#\n\n#", "|.#.|\n\n#", "|...|", "|...|\n\n"] {
+            assert!(bad.parse::<Layout>().is_err(), "{bad:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_never_closes() -> Result<()> {
+        // Every rock is blown against the left wall, so the rest of the
+        // chamber stays open all the way down.
+        let layout: Layout = "|.......|\n\n#".parse()?;
+        let mut chamber = Chamber::new(layout.clone(), "<".parse()?);
+        for _ in 0..10 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 10);
+        assert_eq!(
+            Chamber::new(layout.clone(), "<".parse()?).height_after(10)?,
+            10
+        );
+        assert!(Chamber::new(layout, "<".parse()?)
+            .height_after(1_000_000_000_000)
+            .is_err());
+        Ok(())
+    }
+}
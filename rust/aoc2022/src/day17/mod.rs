@@ -0,0 +1,53 @@
+//! Day 17
+
+pub mod chamber;
+
+use crate::prelude::*;
+
+use chamber::{Chamber, Jets};
+
+/// The chamber, and then the rocks in the order they fall.
+pub const LAYOUT: &str = "\
+|.......|
+
+####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+fn chamber(jets: &Jets) -> Result<Chamber> {
+    Ok(Chamber::new(LAYOUT.parse()?, jets.clone()))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Jets;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse()
+    }
+
+    fn part1(jets: &Self::Input<'_>) -> Result<Self::Part1> {
+        chamber(jets)?.height_after(2022)
+    }
+
+    fn part2(jets: &Self::Input<'_>) -> Result<Self::Part2> {
+        chamber(jets)?.height_after(1_000_000_000_000)
+    }
+}
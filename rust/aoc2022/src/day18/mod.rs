@@ -0,0 +1,93 @@
+//! Day 18
+
+use crate::prelude::*;
+
+use aoc_common::{Bounds3, Point3};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64 as nom_i64, line_ending},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+fn parse_line(input: &str) -> IResult<&str, Point3> {
+    let (input, (x, y, z)) = tuple((
+        nom_i64,
+        preceded(tag(","), nom_i64),
+        preceded(tag(","), nom_i64),
+    ))(input)?;
+    Ok((input, Point3::new(x, y, z)))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Point3>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Point3>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, pos) = parse_input(input).map_err(|e| e.to_owned())?;
+        Ok(pos)
+    }
+
+    fn part1(pos: &Self::Input<'_>) -> Result<Self::Part1> {
+        let grid: HashSet<Point3> = pos.iter().copied().collect();
+
+        let mut surface = 0;
+        for p in pos {
+            for sp in p.neighbours6() {
+                if !grid.contains(&sp) {
+                    surface += 1;
+                }
+            }
+        }
+        Ok(surface)
+    }
+
+    fn part2(pos: &Self::Input<'_>) -> Result<Self::Part2> {
+        let grid: HashSet<Point3> = pos.iter().copied().collect();
+
+        // Flood the air around the droplet, from a corner of a box one larger
+        // than it in every direction.
+        let bounds = Bounds3::from_points(pos.iter().copied())
+            .context("No cubes")?
+            .expand(1);
+
+        let mut potential_external = VecDeque::from([bounds.min]);
+        let mut known_external = HashSet::new();
+        while let Some(p) = potential_external.pop_front() {
+            if known_external.contains(&p) {
+                continue;
+            }
+            known_external.insert(p);
+            for sp in p.neighbours6() {
+                if !bounds.contains(sp) {
+                    continue;
+                }
+                if known_external.contains(&sp) {
+                    continue;
+                }
+                if grid.contains(&sp) {
+                    continue;
+                }
+                potential_external.push_back(sp);
+            }
+        }
+
+        let mut surface = 0;
+        for p in pos {
+            for sp in p.neighbours6() {
+                if known_external.contains(&sp) {
+                    surface += 1;
+                }
+            }
+        }
+        Ok(surface)
+    }
+}
@@ -0,0 +1,383 @@
+//! Day 19
+
+use std::{fmt, str::FromStr};
+
+use crate::prelude::*;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending, u64 as nom_u64},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair, tuple},
+    IResult,
+};
+use rayon::prelude::*;
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(
+    Debug,
+    strum::EnumString,
+    strum::Display,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    EnumIter,
+)]
+#[allow(clippy::enum_variant_names)]
+#[strum(serialize_all = "snake_case")]
+pub enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug)]
+struct Recipe {
+    resource: Resource,
+    // cost: Vec<(Resource, u64)>,
+    cost: [u64; 4],
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u64,
+    recipes: [Recipe; 4],
+    /// The most of each resource any robot costs: there's no point having
+    /// more robots collecting it than that, as it can't be spent any faster.
+    max_costs: [u64; 4],
+}
+
+fn parse_phrase(input: &str) -> IResult<&str, Recipe> {
+    let (input, (resource_name, costs)) = tuple((
+        preceded(tag("Each "), alpha1),
+        delimited(
+            tag(" robot costs "),
+            separated_list1(tag(" and "), separated_pair(nom_u64, tag(" "), alpha1)),
+            tag("."),
+        ),
+    ))(input)?;
+    let mut cost = [0; 4];
+    for (n, r) in costs {
+        let r = Resource::from_str(r).unwrap();
+        cost[r as usize] = n;
+    }
+    Ok((
+        input,
+        Recipe {
+            resource: Resource::from_str(resource_name).unwrap(),
+            cost,
+        },
+    ))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Blueprint> {
+    let (input, (id, mut recipes)) = tuple((
+        delimited(tag("Blueprint "), nom_u64, tag(": ")),
+        separated_list1(tag(" "), parse_phrase),
+    ))(input)?;
+    // Keep the recipes in the same order as `Resource`, to look them up.
+    recipes.sort_by_key(|r| r.resource);
+    let mut max_costs = [0; 4];
+    let recipes: [Recipe; 4] = recipes.try_into().unwrap();
+    for r in &recipes {
+        max_costs[0] = max_costs[0].max(r.cost[0]);
+        max_costs[1] = max_costs[1].max(r.cost[1]);
+        max_costs[2] = max_costs[2].max(r.cost[2]);
+        max_costs[3] = max_costs[3].max(r.cost[3]);
+    }
+    Ok((
+        input,
+        Blueprint {
+            id,
+            recipes,
+            max_costs,
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Blueprint>> {
+    all_consuming(separated_list1(line_ending, parse_line))(input)
+}
+
+/// A robot ordered at the start of a minute, which is ready at its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Purchase {
+    pub minute: u64,
+    pub robot: Resource,
+}
+
+/// The most geodes a blueprint can open, and the purchases that do it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub geodes: u64,
+    pub purchases: Vec<Purchase>,
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for Purchase { minute, robot } in &self.purchases {
+            writeln!(f, "Minute {minute}: build a {robot} robot")?;
+        }
+        write!(f, "{} geodes", self.geodes)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    robots: [u64; 4],
+    stock: [u64; 4],
+    minutes_left: u64,
+}
+
+impl Blueprint {
+    /// The best plan over `minutes`.  Rather than go minute by minute, each
+    /// step of the search picks the next robot to buy and waits until it
+    /// can; branches that couldn't beat the best plan so far even with
+    /// unlimited ore and clay are cut off.
+    pub fn best_plan(&self, minutes: u64) -> Plan {
+        let mut search = Search {
+            blueprint: self,
+            minutes,
+            purchases: vec![],
+            best: Plan {
+                geodes: 0,
+                purchases: vec![],
+            },
+        };
+        search.visit(State {
+            robots: [1, 0, 0, 0],
+            stock: [0; 4],
+            minutes_left: minutes,
+        });
+        search.best
+    }
+
+    /// Goes through `purchases` minute by minute, returning how many geodes
+    /// they open, or why they can't be made.
+    pub fn replay(&self, purchases: &[Purchase], minutes: u64) -> Result<u64> {
+        let mut robots = [1, 0, 0, 0];
+        let mut stock = [0; 4];
+        let mut purchases = purchases.iter().peekable();
+        for minute in 1..=minutes {
+            let bought = purchases.next_if(|p| p.minute == minute);
+            if let Some(purchase) = bought {
+                let cost = self.recipes[purchase.robot as usize].cost;
+                if (0..4).any(|i| stock[i] < cost[i]) {
+                    bail!("Can't afford a {} robot in minute {minute}", purchase.robot);
+                }
+                for i in 0..4 {
+                    stock[i] -= cost[i];
+                }
+            }
+            for i in 0..4 {
+                stock[i] += robots[i];
+            }
+            if let Some(purchase) = bought {
+                robots[purchase.robot as usize] += 1;
+            }
+        }
+        if let Some(purchase) = purchases.next() {
+            bail!(
+                "Can't build a {} robot in minute {}",
+                purchase.robot,
+                purchase.minute
+            );
+        }
+        Ok(stock[Resource::Geode as usize])
+    }
+}
+
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    minutes: u64,
+    purchases: Vec<Purchase>,
+    best: Plan,
+}
+
+impl Search<'_> {
+    fn visit(&mut self, state: State) {
+        let geode = Resource::Geode as usize;
+        let t = state.minutes_left;
+        let geodes = state.stock[geode] + state.robots[geode] * t;
+        if geodes > self.best.geodes {
+            self.best = Plan {
+                geodes,
+                purchases: self.purchases.clone(),
+            };
+        }
+        if self.upper_bound(&state) <= self.best.geodes {
+            return;
+        }
+
+        for robot in Resource::iter().rev() {
+            let r = robot as usize;
+            // Enough robots to spend the most that could be spent in every
+            // remaining minute.
+            let max_cost = self.blueprint.max_costs[r];
+            if robot != Resource::Geode && state.robots[r] * t + state.stock[r] >= max_cost * t {
+                continue;
+            }
+            let Some(wait) = self.wait_for(&state, robot) else {
+                continue;
+            };
+            // The robot has to be ready with a minute to spare to collect
+            // anything.
+            if wait + 1 >= t {
+                continue;
+            }
+            let mut next = state;
+            for i in 0..4 {
+                next.stock[i] =
+                    next.stock[i] + next.robots[i] * (wait + 1) - self.blueprint.recipes[r].cost[i];
+            }
+            next.robots[r] += 1;
+            next.minutes_left -= wait + 1;
+            self.purchases.push(Purchase {
+                minute: self.minutes - t + wait + 1,
+                robot,
+            });
+            self.visit(next);
+            self.purchases.pop();
+        }
+    }
+
+    /// How many minutes until `robot` can be bought, if it ever can with the
+    /// robots there are.
+    fn wait_for(&self, state: &State, robot: Resource) -> Option<u64> {
+        let cost = &self.blueprint.recipes[robot as usize].cost;
+        let mut wait = 0;
+        for ((&cost, &stock), &robots) in cost.iter().zip(&state.stock).zip(&state.robots) {
+            if cost > stock {
+                if robots == 0 {
+                    return None;
+                }
+                wait = wait.max((cost - stock).div_ceil(robots));
+            }
+        }
+        Some(wait)
+    }
+
+    /// The most geodes there could be, were ore and clay free and an
+    /// obsidian robot built every minute alongside any geode robot.
+    fn upper_bound(&self, state: &State) -> u64 {
+        let (obsidian, geode) = (Resource::Obsidian as usize, Resource::Geode as usize);
+        let geode_cost = self.blueprint.recipes[geode].cost[obsidian];
+        let mut stock = state.stock;
+        let mut robots = state.robots;
+        for _ in 0..state.minutes_left {
+            let build_geode = stock[obsidian] >= geode_cost;
+            if build_geode {
+                stock[obsidian] -= geode_cost;
+            }
+            stock[obsidian] += robots[obsidian];
+            stock[geode] += robots[geode];
+            robots[obsidian] += 1;
+            if build_geode {
+                robots[geode] += 1;
+            }
+        }
+        stock[geode]
+    }
+}
+
+/// The best plan for each blueprint, worked out in parallel.
+pub fn best_plans(blueprints: &[Blueprint], minutes: u64) -> Vec<Plan> {
+    blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.best_plan(minutes))
+        .collect()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, blueprints) = parse_input(input.trim_end()).map_err(|e| e.to_owned())?;
+        Ok(blueprints)
+    }
+
+    fn part1(blueprints: &Self::Input<'_>) -> Result<Self::Part1> {
+        let plans = best_plans(blueprints, 24);
+        let quality_level = blueprints
+            .iter()
+            .zip(&plans)
+            .map(|(blueprint, plan)| blueprint.id * plan.geodes)
+            .sum::<u64>();
+        Ok(quality_level)
+    }
+
+    fn part2(blueprints: &Self::Input<'_>) -> Result<Self::Part2> {
+        // Only the first three blueprints survive the elephants (the example
+        // has just two).
+        let blueprints = &blueprints[..blueprints.len().min(3)];
+        let plans = best_plans(blueprints, 32);
+        Ok(plans.iter().map(|plan| plan.geodes).product::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let blueprints = Day19::parse(include_str!("../../../data/2022/day19/example_input"))?;
+        for (minutes, expected) in [(24, [9, 12]), (32, [56, 62])] {
+            for (blueprint, expected) in blueprints.iter().zip(expected) {
+                let plan = blueprint.best_plan(minutes);
+                assert_eq!(plan.geodes, expected);
+                assert_eq!(blueprint.replay(&plan.purchases, minutes)?, expected);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay() -> Result<()> {
+        let blueprints = Day19::parse(include_str!("../../../data/2022/day19/example_input"))?;
+        // The puzzle's walkthrough for the first blueprint.
+        let purchase = |minute, robot| Purchase { minute, robot };
+        let mut purchases = vec![
+            purchase(3, Resource::Clay),
+            purchase(5, Resource::Clay),
+            purchase(7, Resource::Clay),
+            purchase(11, Resource::Obsidian),
+            purchase(12, Resource::Clay),
+            purchase(15, Resource::Obsidian),
+            purchase(18, Resource::Geode),
+            purchase(21, Resource::Geode),
+        ];
+        assert_eq!(blueprints[0].replay(&purchases, 24)?, 9);
+        let plan = Plan {
+            geodes: 9,
+            purchases: purchases[6..].to_vec(),
+        };
+        assert_eq!(
+            plan.to_string(),
+            "Minute 18: build a geode robot\nMinute 21: build a geode robot\n9 geodes"
+        );
+
+        purchases[0].minute = 2;
+        assert_eq!(
+            blueprints[0]
+                .replay(&purchases, 24)
+                .unwrap_err()
+                .to_string(),
+            "Can't afford a clay robot in minute 2"
+        );
+        purchases[0].minute = 3;
+        purchases.push(purchase(30, Resource::Ore));
+        assert!(blueprints[0].replay(&purchases, 24).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+//! Day 18
+
+use crate::prelude::*;
+
+pub mod treap;
+
+use nom::{
+    character::complete::{i64 as nom_i64, line_ending},
+    combinator::all_consuming,
+    multi::separated_list1,
+    IResult,
+};
+
+use treap::Treap;
+
+fn parse_input(input: &str) -> IResult<&str, Vec<i64>> {
+    all_consuming(separated_list1(line_ending, nom_i64))(input)
+}
+
+/// Mixes `numbers` `rounds` times, giving the order they end up in as
+/// indices into `numbers`.
+fn mix(numbers: &[i64], rounds: usize) -> Treap {
+    let mut order = Treap::new(numbers.len());
+    for _ in 0..rounds {
+        for (i, &n) in numbers.iter().enumerate() {
+            order.move_by(i, n);
+        }
+    }
+    order
+}
+
+/// The sum of the 1000th, 2000th and 3000th numbers after the 0.
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> Result<i64> {
+    let numbers = numbers.iter().map(|x| x * key).collect::<Vec<_>>();
+    let order = mix(&numbers, rounds);
+    let zero = numbers
+        .iter()
+        .position(|&n| n == 0)
+        .context("No 0 in the file")?;
+    let zero_position = order.position(zero);
+    [1000, 2000, 3000]
+        .iter()
+        .map(|x| {
+            let index = order
+                .get((x + zero_position) % order.len())
+                .context("Position past the end of the mixed file")?;
+            Ok(numbers[index])
+        })
+        .sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, numbers) = parse_input(input.trim_end()).map_err(|e| e.to_owned())?;
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        grove_coordinates(numbers, 1, 1)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        grove_coordinates(numbers, 811589153, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::random::Rng;
+
+    /// Mixing by moving entries around a `Vec`, one at a time.
+    fn mix_naive(numbers: &[i64], rounds: usize) -> Vec<usize> {
+        let mut indices = (0..numbers.len()).collect::<Vec<_>>();
+        for _ in 0..rounds {
+            for (i, &n) in numbers.iter().enumerate() {
+                let p = indices.iter().position(|&x| x == i).unwrap();
+                indices.remove(p);
+                let new_p = (p as i64 + n).rem_euclid(indices.len() as i64) as usize;
+                indices.insert(new_p, i);
+            }
+        }
+        indices
+    }
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let numbers = [1, 2, -3, 3, -2, 0, 4];
+        let mixed = mix(&numbers, 1)
+            .to_vec()
+            .iter()
+            .map(|&i| numbers[i])
+            .collect_vec();
+        // The puzzle's final arrangement, 1, 2, -3, 4, 0, 3, -2, rotated.
+        assert_eq!(mixed, [-2, 1, 2, -3, 4, 0, 3]);
+        assert_eq!(grove_coordinates(&numbers, 1, 1)?, 3);
+        assert_eq!(grove_coordinates(&numbers, 811589153, 10)?, 1623178306);
+        Ok(())
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let mut rng = Rng::new(0x5eed);
+        let mut random = |n: u64| rng.below(n);
+        for _ in 0..200 {
+            let len = 2 + random(60) as usize;
+            let scale = [1, 10, 811589153][random(3) as usize];
+            let numbers = (0..len)
+                .map(|_| (random(201) as i64 - 100) * scale)
+                .collect_vec();
+            let rounds = 1 + random(3) as usize;
+            assert_eq!(
+                mix(&numbers, rounds).to_vec(),
+                mix_naive(&numbers, rounds),
+                "{numbers:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! A list of the numbers `0..n` that can find where any of them is and move
+//! it, both in O(log n).
+//!
+//! It's an implicit treap: a binary tree kept in list order, balanced by
+//! giving each node a random priority and keeping every node's priority
+//! above its children's.  Nodes are stored by the number they hold and know
+//! their parent, so a number's position can be worked out by walking up to
+//! the root.
+
+use aoc_common::random::Rng;
+
+#[derive(Debug, Clone)]
+struct Node {
+    left: Option<usize>,
+    right: Option<usize>,
+    parent: Option<usize>,
+    /// The number of nodes in this subtree.
+    size: usize,
+    priority: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Treap {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl Treap {
+    /// The list `0, 1, ..., len - 1`.
+    pub fn new(len: usize) -> Treap {
+        // Seeded, so that the same list always has the same shape.
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let nodes = (0..len)
+            .map(|_| Node {
+                left: None,
+                right: None,
+                parent: None,
+                size: 1,
+                priority: rng.next_u64(),
+            })
+            .collect();
+        let mut treap = Treap { nodes, root: None };
+        for i in 0..len {
+            treap.root = treap.merge(treap.root, Some(i));
+        }
+        treap.detach(treap.root);
+        treap
+    }
+
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Where `i` is in the list.
+    pub fn position(&self, i: usize) -> usize {
+        let mut position = self.size(self.nodes[i].left);
+        let mut node = i;
+        while let Some(parent) = self.nodes[node].parent {
+            if self.nodes[parent].right == Some(node) {
+                position += self.size(self.nodes[parent].left) + 1;
+            }
+            node = parent;
+        }
+        position
+    }
+
+    /// The number at `position` in the list.
+    pub fn get(&self, mut position: usize) -> Option<usize> {
+        let mut node = self.root?;
+        loop {
+            let left = self.size(self.nodes[node].left);
+            match position.cmp(&left) {
+                std::cmp::Ordering::Less => node = self.nodes[node].left?,
+                std::cmp::Ordering::Equal => return Some(node),
+                std::cmp::Ordering::Greater => {
+                    position -= left + 1;
+                    node = self.nodes[node].right?;
+                }
+            }
+        }
+    }
+
+    /// Takes `i` out of the list and puts it back `offset` places further on,
+    /// wrapping around the ends of the rest of the list.
+    pub fn move_by(&mut self, i: usize, offset: i64) {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+        let position = self.position(i);
+        let (before, rest) = self.split(self.root, position);
+        let (_, after) = self.split(rest, 1);
+        let rest = self.merge(before, after);
+        self.detach(rest);
+
+        let target = (position as i64 + offset).rem_euclid(len as i64 - 1) as usize;
+        let (before, after) = self.split(rest, target);
+        let before = self.merge(before, Some(i));
+        self.root = self.merge(before, after);
+        self.detach(self.root);
+    }
+
+    /// The whole list, in order.
+    pub fn to_vec(&self) -> Vec<usize> {
+        let mut list = Vec::with_capacity(self.len());
+        let mut stack = vec![];
+        let mut node = self.root;
+        while node.is_some() || !stack.is_empty() {
+            while let Some(n) = node {
+                stack.push(n);
+                node = self.nodes[n].left;
+            }
+            let n = stack.pop().unwrap();
+            list.push(n);
+            node = self.nodes[n].right;
+        }
+        list
+    }
+
+    fn size(&self, node: Option<usize>) -> usize {
+        node.map_or(0, |n| self.nodes[n].size)
+    }
+
+    fn detach(&mut self, node: Option<usize>) {
+        if let Some(n) = node {
+            self.nodes[n].parent = None;
+        }
+    }
+
+    /// Recomputes `node`'s size after its children change, and points them
+    /// back at it.
+    fn update(&mut self, node: usize) {
+        let Node { left, right, .. } = self.nodes[node];
+        self.nodes[node].size = 1 + self.size(left) + self.size(right);
+        for child in [left, right].into_iter().flatten() {
+            self.nodes[child].parent = Some(node);
+        }
+    }
+
+    /// Splits the subtree at `node` into its first `k` entries and the rest.
+    fn split(&mut self, node: Option<usize>, k: usize) -> (Option<usize>, Option<usize>) {
+        let Some(n) = node else {
+            return (None, None);
+        };
+        let left = self.size(self.nodes[n].left);
+        if left < k {
+            let (middle, right) = self.split(self.nodes[n].right, k - left - 1);
+            self.nodes[n].right = middle;
+            self.update(n);
+            self.detach(right);
+            (Some(n), right)
+        } else {
+            let (left, middle) = self.split(self.nodes[n].left, k);
+            self.nodes[n].left = middle;
+            self.update(n);
+            self.detach(left);
+            (left, Some(n))
+        }
+    }
+
+    /// Joins two subtrees, all of `a` coming before all of `b`.
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        match (a, b) {
+            (None, node) | (node, None) => node,
+            (Some(a), Some(b)) => {
+                if self.nodes[a].priority > self.nodes[b].priority {
+                    self.nodes[a].right = self.merge(self.nodes[a].right, Some(b));
+                    self.update(a);
+                    Some(a)
+                } else {
+                    self.nodes[b].left = self.merge(Some(a), self.nodes[b].left);
+                    self.update(b);
+                    Some(b)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_treap() {
+        let mut treap = Treap::new(7);
+        assert_eq!(treap.to_vec(), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(treap.position(4), 4);
+        treap.move_by(1, 2);
+        assert_eq!(treap.to_vec(), [0, 2, 3, 1, 4, 5, 6]);
+        treap.move_by(5, -6);
+        assert_eq!(treap.to_vec(), [0, 2, 3, 1, 4, 5, 6]);
+        treap.move_by(0, -1);
+        assert_eq!(treap.to_vec(), [2, 3, 1, 4, 5, 0, 6]);
+        assert_eq!(treap.position(0), 5);
+        assert_eq!(treap.get(2), Some(1));
+        assert_eq!(treap.get(7), None);
+
+        let mut single = Treap::new(1);
+        single.move_by(0, 5);
+        assert_eq!(single.to_vec(), [0]);
+        assert!(Treap::new(0).is_empty());
+    }
+}
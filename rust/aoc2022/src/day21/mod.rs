@@ -0,0 +1,269 @@
+//! Day 21
+
+use crate::prelude::*;
+
+use num::rational::Ratio;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, i128 as nom_i128, line_ending},
+    combinator::all_consuming,
+    error::Error,
+    multi::separated_list1,
+    sequence::{delimited, terminated, tuple},
+    IResult,
+};
+
+/// Monkeys' numbers, exactly: working back from `root` can pass through
+/// fractions even when the answer is whole.
+type Rational = Ratio<i128>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    fn perform(&self, op1: Rational, op2: Rational) -> Result<Rational> {
+        Ok(match self {
+            Operation::Add => op1 + op2,
+            Operation::Subtract => op1 - op2,
+            Operation::Multiply => op1 * op2,
+            Operation::Divide => {
+                if op2 == Rational::from(0) {
+                    bail!("Division by zero");
+                }
+                op1 / op2
+            }
+        })
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Subtract => "-",
+            Operation::Multiply => "*",
+            Operation::Divide => "/",
+        }
+    }
+
+    /// The `x` for which `x op known == target` (if `x_on_left`) or
+    /// `known op x == target`.
+    fn invert(&self, known: Rational, target: Rational, x_on_left: bool) -> Result<Rational> {
+        let zero = Rational::from(0);
+        Ok(match (self, x_on_left) {
+            (Operation::Add, _) => target - known,
+            (Operation::Subtract, true) => target + known,
+            (Operation::Subtract, false) => known - target,
+            (Operation::Multiply, _) => {
+                if known == zero {
+                    bail!("Can't undo multiplying by zero");
+                }
+                target / known
+            }
+            (Operation::Divide, true) => target * known,
+            (Operation::Divide, false) => {
+                if target == zero {
+                    bail!("Can't undo dividing {known} by the unknown to get zero");
+                }
+                known / target
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
+pub enum Expression {
+    Constant(i128),
+    Expression(Operation, String, String),
+    Variable,
+}
+
+#[derive(Debug)]
+struct Monkey {
+    name: String,
+    expr: Expression,
+}
+
+fn parse_expression(input: &str) -> IResult<&str, Expression> {
+    if let Ok((input, value)) = nom_i128::<_, Error<_>>(input) {
+        Ok((input, Expression::Constant(value)))
+    } else {
+        let (input, (op1, operator, op2)) = tuple((
+            alpha1,
+            delimited(
+                tag(" "),
+                alt((tag("+"), tag("-"), tag("*"), tag("/"))),
+                tag(" "),
+            ),
+            alpha1,
+        ))(input)?;
+
+        let operator = match operator {
+            "+" => Operation::Add,
+            "-" => Operation::Subtract,
+            "*" => Operation::Multiply,
+            "/" => Operation::Divide,
+            _ => unreachable!(),
+        };
+        Ok((
+            input,
+            Expression::Expression(operator, op1.to_string(), op2.to_string()),
+        ))
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Monkey> {
+    let (input, (name, expr)) = tuple((terminated(alpha1, tag(": ")), parse_expression))(input)?;
+    let name = name.to_string();
+    Ok((input, Monkey { name, expr }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Monkey>> {
+    all_consuming(separated_list1(line_ending, parse_line))(input)
+}
+
+/// The number monkey `name` yells, or `None` if it depends on the human.
+fn evaluate(monkeys: &HashMap<String, Expression>, name: &str) -> Result<Option<Rational>> {
+    Ok(
+        match monkeys
+            .get(name)
+            .with_context(|| format!("No monkey called {name}"))?
+        {
+            Expression::Constant(value) => Some(Rational::from(*value)),
+            Expression::Expression(operator, op1, op2) => {
+                match (evaluate(monkeys, op1)?, evaluate(monkeys, op2)?) {
+                    (Some(op1), Some(op2)) => Some(operator.perform(op1, op2)?),
+                    _ => None,
+                }
+            }
+            Expression::Variable => None,
+        },
+    )
+}
+
+/// The value of the variable that makes monkey `name` yell `target`,
+/// undoing one operation at a time on the way down to it.
+fn solve_for_variable<'a>(
+    monkeys: &'a HashMap<String, Expression>,
+    mut name: &'a str,
+    mut target: Rational,
+) -> Result<Rational> {
+    loop {
+        match &monkeys[name] {
+            Expression::Variable => return Ok(target),
+            Expression::Constant(value) => {
+                bail!("{name} yells {value}, which doesn't depend on the variable")
+            }
+            Expression::Expression(operator, op1, op2) => {
+                let (next, known, x_on_left) =
+                    match (evaluate(monkeys, op1)?, evaluate(monkeys, op2)?) {
+                        (None, Some(known)) => (op1, known, true),
+                        (Some(known), None) => (op2, known, false),
+                        (None, None) => bail!(
+                            "The variable appears on both sides of {op1} {} {op2}",
+                            operator.symbol()
+                        ),
+                        (Some(_), Some(_)) => {
+                            bail!("{name} doesn't depend on the variable")
+                        }
+                    };
+                target = operator.invert(known, target, x_on_left).with_context(|| {
+                    format!("Solving {name} = {op1} {} {op2}", operator.symbol())
+                })?;
+                name = next;
+            }
+        }
+    }
+}
+
+fn parse_monkeys(file_data: &str) -> Result<HashMap<String, Expression>> {
+    let (_, monkeys) = parse_input(file_data.trim_end()).map_err(|e| e.to_owned())?;
+    Ok(monkeys.into_iter().map(|m| (m.name, m.expr)).collect())
+}
+
+/// A whole-number answer from an exact one.
+fn integer(value: Rational) -> Result<i128> {
+    if !value.is_integer() {
+        bail!("{value} is not a whole number");
+    }
+    Ok(value.to_integer())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = HashMap<String, Expression>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_monkeys(input)
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Result<Self::Part1> {
+        let root = evaluate(monkeys, "root")?.context("root depends on the variable")?;
+        integer(root)
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut monkeys = monkeys.clone();
+        monkeys.insert("humn".to_string(), Expression::Variable);
+
+        // root checks its two numbers are equal: solve the unknown side for
+        // the value of the known one.
+        let Expression::Expression(_, op1, op2) = &monkeys["root"] else {
+            bail!("root doesn't compare two monkeys");
+        };
+        let (unknown, target) = match (evaluate(&monkeys, op1)?, evaluate(&monkeys, op2)?) {
+            (None, Some(target)) => (op1, target),
+            (Some(target), None) => (op2, target),
+            (None, None) => bail!("humn appears on both sides of root"),
+            (Some(_), Some(_)) => bail!("root doesn't depend on humn"),
+        };
+        integer(solve_for_variable(&monkeys, unknown, target)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let monkeys = Day21::parse(include_str!("../../../data/2022/day21/example_input"))?;
+        assert_eq!(Day21::part1(&monkeys)?, 152);
+        assert_eq!(Day21::part2(&monkeys)?, 301);
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_invertible() -> Result<()> {
+        let both_sides = Day21::parse(
+            "root: aaaa + bbbb\naaaa: humn * cccc\nbbbb: humn + cccc\ncccc: 2\nhumn: 5",
+        )?;
+        let err = Day21::part2(&both_sides).unwrap_err();
+        assert_eq!(err.to_string(), "humn appears on both sides of root");
+
+        let inside = Day21::parse("root: aaaa + cccc\naaaa: humn - humn\ncccc: 2\nhumn: 5")?;
+        let err = Day21::part2(&inside).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The variable appears on both sides of humn - humn"
+        );
+
+        let times_zero =
+            Day21::parse("root: aaaa + cccc\naaaa: humn * zero\ncccc: 2\nzero: 0\nhumn: 5")?;
+        let err = Day21::part2(&times_zero).unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Solving aaaa = humn * zero: Can't undo multiplying by zero"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,414 @@
+//! Day 22
+
+use crate::prelude::*;
+use aoc_common::{Direction, Point2, Point3};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{i64 as nom_i64, line_ending},
+    combinator::{map, value},
+    multi::many1,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+enum Instruction {
+    Forward(i64),
+    TurnRight,
+    TurnLeft,
+}
+
+fn parse_map_line(input: &str) -> IResult<&str, HashMap<i64, Tile>> {
+    let (input, chars) = many1(alt((tag(" "), tag("."), tag("#"))))(input)?;
+    let mut h = HashMap::new();
+    for (i, c) in chars.into_iter().enumerate() {
+        if let Some(tile) = match c {
+            " " => None,
+            "." => Some(Tile::Open),
+            "#" => Some(Tile::Wall),
+            _ => unreachable!(),
+        } {
+            h.insert(i as i64, tile);
+        }
+    }
+    Ok((input, h))
+}
+
+fn parse_map(input: &str) -> IResult<&str, HashMap<Point2, Tile>> {
+    let (input, map_tiles) = many1(terminated(parse_map_line, line_ending))(input)?;
+    let mut h = HashMap::new();
+    for (i, l) in map_tiles.into_iter().enumerate() {
+        for (k, v) in l {
+            h.insert(Point2::new(k, i as i64), v);
+        }
+    }
+    Ok((input, h))
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    many1(alt((
+        value(Instruction::TurnLeft, tag("L")),
+        value(Instruction::TurnRight, tag("R")),
+        map(nom_i64, Instruction::Forward),
+    )))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (HashMap<Point2, Tile>, Vec<Instruction>)> {
+    separated_pair(parse_map, line_ending, parse_instructions)(input)
+}
+
+/// The map of the board and the path to follow over it.
+#[derive(Debug, Clone)]
+pub struct Notes {
+    grid: HashMap<Point2, Tile>,
+    instructions: Vec<Instruction>,
+    /// The leftmost open tile of the top row.
+    start: Point2,
+}
+
+impl TryFrom<&str> for Notes {
+    type Error = anyhow::Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        let (_, (grid, instructions)) = parse_input(input).map_err(|e| e.to_owned())?;
+        let start = *grid.keys().min_by_key(|p| (p.y, p.x)).context("Empty map")?;
+        Ok(Notes {
+            grid,
+            instructions,
+            start,
+        })
+    }
+}
+
+fn turned(dir: Direction, instr: Instruction) -> Direction {
+    match instr {
+        Instruction::Forward(_) => dir,
+        Instruction::TurnRight => dir.turn_right(),
+        Instruction::TurnLeft => dir.turn_left(),
+    }
+}
+
+fn score(pos: Point2, dir: Direction) -> i64 {
+    let facing = match dir {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+    1000 * (pos.y + 1) + 4 * (pos.x + 1) + facing
+}
+
+fn dot(a: Point3, b: Point3) -> i64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Where one face of the net ends up once the map is folded into a cube.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// The map position of the face's top-left tile.
+    corner: Point2,
+    /// Which way the face looks out of the cube.
+    normal: Point3,
+    /// Which ways the map's `x` and `y` axes run across the face.
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    fn heading(&self, dir: Direction) -> Point3 {
+        match dir {
+            Direction::Up => -self.down,
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+        }
+    }
+
+    /// The face next to this one in the net, folded over the edge in `dir`.
+    fn fold(&self, dir: Direction, size: i64) -> Face {
+        let (normal, out) = (self.normal, self.heading(dir));
+        let (right, down) = match dir {
+            Direction::Up => (self.right, normal),
+            Direction::Right => (-normal, self.down),
+            Direction::Down => (self.right, -normal),
+            Direction::Left => (normal, self.down),
+        };
+        Face {
+            corner: self.corner + dir.delta() * size,
+            normal: out,
+            right,
+            down,
+        }
+    }
+}
+
+/// The map folded up into a cube.  Tile centres sit at odd coordinates on
+/// the faces of a cube spanning `-size..=size` on each axis, so moving off
+/// a face onto its neighbour is a matter of vector arithmetic.
+struct Cube {
+    size: i64,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Works out the face size and the net from the tiles, and folds it.
+    fn fold(grid: &HashMap<Point2, Tile>) -> Result<Cube> {
+        let size = (1..)
+            .find(|size| 6 * size * size >= grid.len() as i64)
+            .unwrap();
+        if 6 * size * size != grid.len() as i64 {
+            bail!("{} tiles can't cover the six faces of a cube", grid.len());
+        }
+        let net: HashSet<Point2> = grid
+            .keys()
+            .map(|p| Point2::new(p.x.div_euclid(size), p.y.div_euclid(size)))
+            .collect();
+        if net.len() != 6 {
+            bail!("The map isn't a net of six {size}x{size} faces");
+        }
+
+        let start = *net.iter().min_by_key(|p| (p.y, p.x)).unwrap();
+        let mut faces = vec![Face {
+            corner: start * size,
+            normal: Point3::new(0, 0, -1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        }];
+        let mut queue = VecDeque::from([faces[0]]);
+        while let Some(face) = queue.pop_front() {
+            for dir in Direction::ALL {
+                let next = face.fold(dir, size);
+                let in_net = net.contains(&Point2::new(next.corner.x / size, next.corner.y / size));
+                if in_net && faces.iter().all(|f| f.corner != next.corner) {
+                    faces.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        if faces.len() != 6 {
+            bail!("The faces of the map aren't all joined up");
+        }
+        if faces.iter().map(|f| f.normal).collect::<HashSet<_>>().len() != 6 {
+            bail!("The map doesn't fold into a cube");
+        }
+        Ok(Cube { size, faces })
+    }
+
+    fn face_of(&self, pos: Point2) -> &Face {
+        let corner = Point2::new(
+            pos.x.div_euclid(self.size) * self.size,
+            pos.y.div_euclid(self.size) * self.size,
+        );
+        self.faces.iter().find(|f| f.corner == corner).unwrap()
+    }
+
+    /// Where walking `dir` from `pos`, at the edge of its face, comes out on
+    /// the neighbouring face, and which way it's facing then.
+    fn next_pos(&self, pos: Point2, dir: Direction) -> (Point2, Direction) {
+        let face = self.face_of(pos);
+        let offset = pos - face.corner;
+        let n = self.size;
+        let centre = face.normal * n
+            + face.right * (2 * offset.x + 1 - n)
+            + face.down * (2 * offset.y + 1 - n);
+        // Over the edge, the heading becomes the normal, and the old normal
+        // points back the way we came.
+        let heading = face.heading(dir);
+        let centre = centre + heading - face.normal;
+        let target = self.faces.iter().find(|f| f.normal == heading).unwrap();
+        let new_dir = Direction::ALL
+            .into_iter()
+            .find(|&d| target.heading(d) == -face.normal)
+            .unwrap();
+        let offset = Point2::new(
+            (dot(centre, target.right) + n - 1) / 2,
+            (dot(centre, target.down) + n - 1) / 2,
+        );
+        (target.corner + offset, new_dir)
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Notes;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Notes::try_from(input)
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> Result<Self::Part1> {
+        let grid = &notes.grid;
+        let mut pos = notes.start;
+        let mut dir = Direction::Right;
+
+        for &instr in &notes.instructions {
+            match instr {
+                Instruction::Forward(x) => {
+                    let delta = dir.delta();
+                    for _ in 0..x {
+                        let test_pos = pos + delta;
+                        if let Some(Tile::Wall) = grid.get(&test_pos) {
+                            break;
+                        }
+                        if let Some(Tile::Open) = grid.get(&test_pos) {
+                            pos = test_pos;
+                            continue;
+                        }
+                        // Wrap round to the far end of the row or column.
+                        let new_pos = *grid
+                            .keys()
+                            .filter(|p| if dir.is_vertical() { p.x == pos.x } else { p.y == pos.y })
+                            .min_by_key(|&&p| {
+                                let back = p - pos;
+                                back.x * delta.x + back.y * delta.y
+                            })
+                            .unwrap();
+                        if let Some(Tile::Wall) = grid.get(&new_pos) {
+                            break;
+                        }
+                        pos = new_pos;
+                    }
+                }
+                Instruction::TurnRight => {
+                    dir = turned(dir, instr);
+                }
+                Instruction::TurnLeft => {
+                    dir = turned(dir, instr);
+                }
+            }
+        }
+        Ok(score(pos, dir))
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> Result<Self::Part2> {
+        let grid = &notes.grid;
+        let mut pos = notes.start;
+        let mut dir = Direction::Right;
+
+        let cube = Cube::fold(grid)?;
+
+        for &instr in &notes.instructions {
+            match instr {
+                Instruction::Forward(x) => {
+                    let mut delta = dir.delta();
+                    for _ in 0..x {
+                        let test_pos = pos + delta;
+                        if let Some(Tile::Wall) = grid.get(&test_pos) {
+                            break;
+                        }
+                        if let Some(Tile::Open) = grid.get(&test_pos) {
+                            pos = test_pos;
+                            continue;
+                        }
+
+                        let (test_pos, test_dir) = cube.next_pos(pos, dir);
+                        if let Some(Tile::Wall) = grid.get(&test_pos) {
+                            break;
+                        }
+                        if let Some(Tile::Open) = grid.get(&test_pos) {
+                            (pos, dir) = (test_pos, test_dir);
+                            delta = dir.delta();
+                            continue;
+                        }
+                        // If we somehow end up _not_ on the existing grid, that's pretty bad...
+                        unreachable!();
+                    }
+                }
+                Instruction::TurnRight => {
+                    dir = turned(dir, instr);
+                }
+                Instruction::TurnLeft => {
+                    dir = turned(dir, instr);
+                }
+            }
+        }
+
+        Ok(score(pos, dir))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The eleven nets of a cube, one character per face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// An open map of the net with `size` x `size` faces.
+    fn open_map(net: &str, size: i64) -> HashMap<Point2, Tile> {
+        let mut grid = HashMap::new();
+        for (y, line) in net.lines().enumerate() {
+            for (x, _) in line.match_indices('#') {
+                let corner = Point2::new(x as i64, y as i64) * size;
+                for dy in 0..size {
+                    for dx in 0..size {
+                        grid.insert(corner + Point2::new(dx, dy), Tile::Open);
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_fold_every_net() -> Result<()> {
+        for net in NETS {
+            let grid = open_map(net, 3);
+            let cube = Cube::fold(&grid)?;
+            assert_eq!(cube.size, 3);
+            for (&pos, dir) in grid.keys().cartesian_product(Direction::ALL) {
+                if grid.contains_key(&(pos + dir.delta())) {
+                    continue;
+                }
+                // Stepping off an edge lands on the map, and stepping
+                // straight back returns to where we started.
+                let (next, next_dir) = cube.next_pos(pos, dir);
+                assert!(grid.contains_key(&next), "{net}: {pos} {dir:?} -> {next}");
+                assert_eq!(
+                    cube.next_pos(next, next_dir.opposite()),
+                    (pos, dir.opposite()),
+                    "{net}: {pos} {dir:?}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_a_cube() {
+        assert!(Cube::fold(&open_map("######", 2)).is_err());
+        assert!(Cube::fold(&open_map("####\n####", 1)).is_err());
+        assert!(Cube::fold(&open_map("###", 2)).is_err());
+    }
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let input = Day22::parse(include_str!("../../../data/2022/day22/example_input"))?;
+        assert_eq!(Day22::part1(&input)?, 6032);
+        assert_eq!(Day22::part2(&input)?, 5031);
+        Ok(())
+    }
+}
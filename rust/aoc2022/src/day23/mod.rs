@@ -0,0 +1,177 @@
+//! Day 22
+
+use crate::prelude::*;
+use aoc_common::{Bounds2, Point2};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::all_consuming,
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+const VERBOSE: bool = false;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+enum Tile {
+    Open,
+    Elf,
+}
+
+fn parse_map_line(input: &str) -> IResult<&str, HashMap<i64, Tile>> {
+    let (input, chars) = many1(alt((tag("."), tag("#"))))(input)?;
+    let mut h = HashMap::new();
+    for (i, c) in chars.into_iter().enumerate() {
+        if let Some(tile) = match c {
+            "." => Some(Tile::Open),
+            "#" => Some(Tile::Elf),
+            _ => unreachable!(),
+        } {
+            h.insert(i as i64, tile);
+        }
+    }
+    Ok((input, h))
+}
+
+fn parse_input(input: &str) -> IResult<&str, HashMap<(i64, i64), Tile>> {
+    let (input, map_tiles) = separated_list1(line_ending, parse_map_line)(input)?;
+    let mut h = HashMap::new();
+    for (i, l) in map_tiles.into_iter().enumerate() {
+        for (k, v) in l {
+            h.insert((i as i64, k), v);
+        }
+    }
+    Ok((input, h))
+}
+
+const NORTH: Point2 = Point2::new(0, -1);
+const NORTH_EAST: Point2 = Point2::new(1, -1);
+const EAST: Point2 = Point2::new(1, 0);
+const SOUTH_EAST: Point2 = Point2::new(1, 1);
+const SOUTH: Point2 = Point2::new(0, 1);
+const SOUTH_WEST: Point2 = Point2::new(-1, 1);
+const WEST: Point2 = Point2::new(-1, 0);
+const NORTH_WEST: Point2 = Point2::new(-1, -1);
+
+/// The directions an elf considers, in the order of the first round: the
+/// first is where it moves, and it only does so if all three are empty.
+const RULES: [[Point2; 3]; 4] = [
+    [NORTH, NORTH_EAST, NORTH_WEST],
+    [SOUTH, SOUTH_EAST, SOUTH_WEST],
+    [WEST, NORTH_WEST, SOUTH_WEST],
+    [EAST, SOUTH_EAST, NORTH_EAST],
+];
+
+enum ProposedMove {
+    Blocked, // some other elf was going to go there,
+    MoveFrom(Point2),
+}
+
+fn print_map(elves: &HashSet<Point2>) {
+    let bounds = Bounds2::from_points(elves.iter().copied()).unwrap();
+
+    println!();
+    for y in bounds.min.y - 1..=bounds.max.y + 1 {
+        for x in bounds.min.x - 1..=bounds.max.x + 1 {
+            if elves.contains(&Point2::new(x, y)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+/// Where the elves start out.
+fn elves_from_input(file_data: &str) -> Result<HashSet<Point2>> {
+    let (_, grid) = all_consuming(parse_input)(file_data.trim_end()).map_err(|e| e.to_owned())?;
+    Ok(grid
+        .into_iter()
+        .filter_map(|((row, col), c)| {
+            if c == Tile::Elf {
+                Some(Point2::new(col, row))
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+fn simulate(elves: &HashSet<Point2>, rounds: Option<usize>) -> (HashSet<Point2>, usize) {
+    let mut elves = elves.clone();
+    if VERBOSE {
+        println!("== Initial state == ({})\n", elves.len());
+        print_map(&elves);
+    }
+    for round in 0..rounds.unwrap_or(usize::MAX) {
+        let mut proposed_moves = HashMap::new();
+        for &elf in &elves {
+            if !elf.neighbours8().any(|p| elves.contains(&p)) {
+                continue;
+            }
+            for i in 0..4 {
+                let rule = RULES[(round + i) % 4];
+                if rule.iter().any(|&d| elves.contains(&(elf + d))) {
+                    continue;
+                }
+                match proposed_moves.entry(elf + rule[0]) {
+                    std::collections::hash_map::Entry::Occupied(mut e) => {
+                        e.insert(ProposedMove::Blocked);
+                    }
+                    std::collections::hash_map::Entry::Vacant(e) => {
+                        e.insert(ProposedMove::MoveFrom(elf));
+                    }
+                }
+                break;
+            }
+        }
+        let mut any_move = false;
+        for (p, m) in proposed_moves {
+            if let ProposedMove::MoveFrom(from_pos) = m {
+                elves.remove(&from_pos);
+                assert!(!elves.contains(&p));
+                elves.insert(p);
+                any_move = true;
+            }
+        }
+        if !any_move {
+            return (elves, round + 1);
+        }
+        if VERBOSE {
+            println!("== End of round {} == ({})\n", round, elves.len());
+            print_map(&elves);
+        }
+    }
+    (elves, rounds.unwrap())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = HashSet<Point2>;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        elves_from_input(input)
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Result<Self::Part1> {
+        let (elves, _) = simulate(elves, Some(10));
+
+        let rectangle = Bounds2::from_points(elves.iter().copied())
+            .context("No elves")?
+            .area();
+        Ok(rectangle - elves.len() as i64)
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> Result<Self::Part2> {
+        let (_, rounds) = simulate(elves, None);
+
+        Ok(rounds)
+    }
+}
@@ -0,0 +1,203 @@
+//! Day 22
+
+use crate::prelude::*;
+
+use aoc_common::{search::astar, Direction, Grid, Point2};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+enum Tile {
+    Open,
+    Wall,
+    Blizzard(Direction),
+}
+
+type Pos = Point2;
+
+fn parse_input(input: &str) -> Result<Grid<Tile>> {
+    Grid::parse(input, |c| {
+        Ok(match c {
+            '.' => Tile::Open,
+            '#' => Tile::Wall,
+            '>' => Tile::Blizzard(Direction::Right),
+            '<' => Tile::Blizzard(Direction::Left),
+            '^' => Tile::Blizzard(Direction::Up),
+            'v' => Tile::Blizzard(Direction::Down),
+            _ => bail!("Unexpected map character {c:?}"),
+        })
+    })
+}
+
+fn print_map(walls: &Grid<bool>, blizzards: &Vec<(Pos, Direction)>, player: Pos) {
+    println!();
+    let mut blizzards_count: HashMap<Pos, i64> = HashMap::new();
+    let mut blizzards_hash = HashMap::new();
+    for &b in blizzards {
+        *blizzards_count.entry(b.0).or_default() += 1;
+        blizzards_hash.insert(b.0, b.1.arrow());
+    }
+    for y in 0..walls.height() as i64 {
+        for x in 0..walls.width() as i64 {
+            let p = Pos::new(x, y);
+            if p == player {
+                print!("E");
+            } else if walls.get(p.into()) == Some(&true) {
+                print!("#");
+            } else if let Some(c) = blizzards_count.get(&p) {
+                if *c == 1 {
+                    print!("{}", blizzards_hash[&p]);
+                } else {
+                    print!("{}", c);
+                }
+            } else {
+                print!(".");
+            };
+        }
+        println!();
+    }
+    println!();
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    state: Vec<Vec<(Pos, Direction)>>,
+    sets: Vec<HashSet<Pos>>,
+    /// `true` where there's a wall; anywhere off the grid is out of bounds.
+    walls: Grid<bool>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Field {
+    fn new(blizzards: Vec<(Pos, Direction)>, walls: Grid<bool>) -> Self {
+        let sets = vec![blizzards.iter().map(|x| x.0).collect::<HashSet<_>>()];
+        let state = vec![blizzards];
+
+        let start_pos = Pos::new(1, 0);
+        let end_pos = Pos::new(walls.width() as i64 - 2, walls.height() as i64 - 1);
+
+        Self {
+            state,
+            sets,
+            walls,
+            start_pos,
+            end_pos,
+        }
+    }
+
+    fn is_wall(&self, p: Pos) -> bool {
+        self.walls.get(p.into()) == Some(&true)
+    }
+
+    fn state_at(&mut self, round: usize) -> (&Vec<(Pos, Direction)>, &HashSet<Pos>) {
+        while self.state.len() - 1 < round {
+            let last_round = self.state.len() - 1;
+            let mut blizzards = self.state[last_round].clone();
+
+            for (p, d) in blizzards.iter_mut() {
+                *p += d.delta();
+                if self.is_wall(*p) {
+                    match d {
+                        Direction::Up => p.y = self.walls.height() as i64 - 2,
+                        Direction::Right => p.x = 1,
+                        Direction::Down => p.y = 1,
+                        Direction::Left => p.x = self.walls.width() as i64 - 2,
+                    }
+                }
+            }
+            let sets = blizzards.iter().map(|x| x.0).collect::<HashSet<_>>();
+            self.state.push(blizzards);
+            self.sets.push(sets);
+        }
+        (&self.state[round], &self.sets[round])
+    }
+
+    #[allow(unused)]
+    fn print_map(&self, round: usize, player: Pos) {
+        if round > self.state.len() - 1 {
+            unreachable!();
+        }
+
+        let blizzards = &self.state[round];
+        print_map(&self.walls, blizzards, player);
+    }
+}
+
+/// The round the expedition can reach `end_pos` by at the earliest, having
+/// set off from `start_pos` at `start_round`.
+fn run_once(field: &mut Field, start_round: usize, start_pos: Pos, end_pos: Pos) -> Option<usize> {
+    let successors = |&(pos, round): &(Pos, usize)| {
+        let (_, blizzard_set) = field.state_at(round + 1);
+        let blizzard_set = blizzard_set.clone();
+        let mut next = vec![];
+        for d in Direction::ALL {
+            let pos = pos + d.delta();
+            if field.walls.get(pos.into()) != Some(&false) {
+                // Off the map or into a wall
+                continue;
+            }
+            if blizzard_set.contains(&pos) {
+                // next round will have the blizzard
+                continue;
+            }
+            next.push(((pos, round + 1), 1));
+        }
+        if !blizzard_set.contains(&pos) {
+            next.push(((pos, round + 1), 1));
+        }
+        next
+    };
+    let path = astar(
+        (start_pos, start_round),
+        successors,
+        |&(pos, _)| pos.manhattan(end_pos) as usize,
+        |&(pos, _)| pos == end_pos,
+    )?;
+    Some(path.goal().1)
+}
+
+fn field_from_input(file_data: &str) -> Result<Field> {
+    let grid = parse_input(file_data)?;
+    let blizzards = grid
+        .iter()
+        .filter_map(|(p, &t)| {
+            if let Tile::Blizzard(d) = t {
+                Some((Pos::from(p), d))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let walls = grid.map(|&t| t == Tile::Wall);
+
+    Ok(Field::new(blizzards, walls))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Field;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        field_from_input(input)
+    }
+
+    fn part1(field: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut field = field.clone();
+        let start_pos = field.start_pos;
+        let end_pos = field.end_pos;
+        run_once(&mut field, 0, start_pos, end_pos).context("No path found")
+    }
+
+    fn part2(field: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut field = field.clone();
+        let start_pos = field.start_pos;
+        let end_pos = field.end_pos;
+        let mut round = 0;
+        for (from, to) in [(start_pos, end_pos), (end_pos, start_pos), (start_pos, end_pos)] {
+            round = run_once(&mut field, round, from, to).context("No path found")?;
+        }
+        Ok(round)
+    }
+}
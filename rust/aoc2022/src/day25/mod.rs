@@ -0,0 +1,93 @@
+//! Day 25
+
+use crate::prelude::*;
+
+use nom::{
+    character::complete::{line_ending, not_line_ending},
+    combinator::{all_consuming, map_res},
+    multi::separated_list1,
+    IResult,
+};
+
+fn snafu_to_number(chars: &str) -> Result<i64> {
+    let mut r = 0;
+
+    for (i, c) in chars.chars().rev().enumerate() {
+        let m = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => bail!("Unexpected SNAFU digit {c:?}"),
+        };
+        r += m * 5_i64.pow(i as u32);
+    }
+    Ok(r)
+}
+
+fn parse_line(input: &str) -> IResult<&str, i64> {
+    map_res(not_line_ending, snafu_to_number)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+fn number_to_snafu(mut number: i64) -> String {
+    
+    let mut digits = Vec::new();
+    for _ in 0.. {
+        let mut carry = 0;
+        let m = number % 5;
+        digits.push(match m {
+            m if m < 3 => m.to_string(),
+            3 => {
+                carry = 1;
+                "=".to_string()
+            }
+            4 => {
+                carry = 1;
+                "-".to_string()
+            }
+            _ => unreachable!(),
+        });
+        number /= 5;
+        number += carry;
+        if number == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits.into_iter().collect::<String>()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, numbers) = all_consuming(parse_input)(input.trim_end()).map_err(|e| e.to_owned())?;
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        let number = numbers.iter().sum::<i64>();
+
+        // for a in 0..=10 {
+        //     println!("{:10} {:10}", a, number_to_snafu(a));
+        // }
+        // for a in [15, 20, 2022, 12345, 314159265] {
+        //     println!("{:10} {:10}", a, number_to_snafu(a));
+        // }
+
+        Ok(number_to_snafu(number))
+    }
+
+    fn part2(_numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        bail!(Unimplemented)
+    }
+}
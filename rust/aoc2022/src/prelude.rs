@@ -4,6 +4,6 @@ pub use std::collections::HashSet;
 pub use std::collections::VecDeque;
 
 pub use anyhow::{bail, Context, Result};
-pub use clap::Parser;
+pub use aoc_common::{Solution, Unimplemented};
 pub use itertools::Itertools;
 pub use once_cell::sync::Lazy;
@@ -1,2712 +0,0 @@
-#!/usr/bin/env python3
-
-from z3 import *
-
-px, py, pz = Int('px'), Int('py'), Int('pz')
-dx, dy, dz = Int('dx'), Int('dy'), Int('dz')
-
-solver = Solver()
-
-t0 = Int('t0')
-solver.add(
-    And(
-        px + dx * t0 == 346929738756520 + 6 * t0,
-        py + dy * t0 == 180308062329517 + -5 * t0,
-        pz + dz * t0 == 348158644025623 + -22 * t0,
-    )
-)
-
-t1 = Int('t1')
-solver.add(
-    And(
-        px + dx * t1 == 254810664927620 + -144 * t1,
-        py + dy * t1 == 353739895010289 + 403 * t1,
-        pz + dz * t1 == 244141919277765 + -76 * t1,
-    )
-)
-
-t2 = Int('t2')
-solver.add(
-    And(
-        px + dx * t2 == 295870756794909 + -23 * t2,
-        py + dy * t2 == 404627177923603 + -185 * t2,
-        pz + dz * t2 == 198720163538165 + 145 * t2,
-    )
-)
-
-t3 = Int('t3')
-solver.add(
-    And(
-        px + dx * t3 == 245963903877515 + -175 * t3,
-        py + dy * t3 == 463230951802017 + -493 * t3,
-        pz + dz * t3 == 204063276689103 + 282 * t3,
-    )
-)
-
-t4 = Int('t4')
-solver.add(
-    And(
-        px + dx * t4 == 240883930774627 + 85 * t4,
-        py + dy * t4 == 293767063383987 + 41 * t4,
-        pz + dz * t4 == 385416738115181 + -232 * t4,
-    )
-)
-
-t5 = Int('t5')
-solver.add(
-    And(
-        px + dx * t5 == 258783790598300 + -106 * t5,
-        py + dy * t5 == 416085987737022 + -108 * t5,
-        pz + dz * t5 == 244244689804488 + -37 * t5,
-    )
-)
-
-t6 = Int('t6')
-solver.add(
-    And(
-        px + dx * t6 == 316881219411455 + 36 * t6,
-        py + dy * t6 == 207115465839939 + -33 * t6,
-        pz + dz * t6 == 3231271691721 + 311 * t6,
-    )
-)
-
-t7 = Int('t7')
-solver.add(
-    And(
-        px + dx * t7 == 159692118609179 + 194 * t7,
-        py + dy * t7 == 278631004484055 + -83 * t7,
-        pz + dz * t7 == 273317916049077 + 45 * t7,
-    )
-)
-
-t8 = Int('t8')
-solver.add(
-    And(
-        px + dx * t8 == 372719112455483 + -55 * t8,
-        py + dy * t8 == 164426621521265 + 75 * t8,
-        pz + dz * t8 == 78323731342860 + 273 * t8,
-    )
-)
-
-t9 = Int('t9')
-solver.add(
-    And(
-        px + dx * t9 == 288690854255051 + 45 * t9,
-        py + dy * t9 == 303366864842388 + -90 * t9,
-        pz + dz * t9 == 441853886465373 + -168 * t9,
-    )
-)
-
-t10 = Int('t10')
-solver.add(
-    And(
-        px + dx * t10 == 231474036233045 + 116 * t10,
-        py + dy * t10 == 203197598170947 + 10 * t10,
-        pz + dz * t10 == 325158536216283 + -16 * t10,
-    )
-)
-
-t11 = Int('t11')
-solver.add(
-    And(
-        px + dx * t11 == 307983644394617 + -16 * t11,
-        py + dy * t11 == 107725860376215 + 288 * t11,
-        pz + dz * t11 == 326740994553027 + -72 * t11,
-    )
-)
-
-t12 = Int('t12')
-solver.add(
-    And(
-        px + dx * t12 == 347506682121503 + -10 * t12,
-        py + dy * t12 == 194283076183827 + 11 * t12,
-        pz + dz * t12 == 291055039661129 + 25 * t12,
-    )
-)
-
-t13 = Int('t13')
-solver.add(
-    And(
-        px + dx * t13 == 291356463389145 + 27 * t13,
-        py + dy * t13 == 224489891532087 + 50 * t13,
-        pz + dz * t13 == 277427773123203 + 21 * t13,
-    )
-)
-
-t14 = Int('t14')
-solver.add(
-    And(
-        px + dx * t14 == 271941427915733 + -121 * t14,
-        py + dy * t14 == 503433763356759 + -547 * t14,
-        pz + dz * t14 == 213162447327457 + 136 * t14,
-    )
-)
-
-t15 = Int('t15')
-solver.add(
-    And(
-        px + dx * t15 == 242916393274521 + -45 * t15,
-        py + dy * t15 == 370793247991631 + 247 * t15,
-        pz + dz * t15 == 228014536745307 + 48 * t15,
-    )
-)
-
-t16 = Int('t16')
-solver.add(
-    And(
-        px + dx * t16 == 195691614411751 + 168 * t16,
-        py + dy * t16 == 344202548829805 + -98 * t16,
-        pz + dz * t16 == 373444276238427 + -150 * t16,
-    )
-)
-
-t17 = Int('t17')
-solver.add(
-    And(
-        px + dx * t17 == 448108397653615 + -171 * t17,
-        py + dy * t17 == 342984318211037 + -129 * t17,
-        pz + dz * t17 == 372702655692203 + -98 * t17,
-    )
-)
-
-t18 = Int('t18')
-solver.add(
-    And(
-        px + dx * t18 == 271056836136539 + 53 * t18,
-        py + dy * t18 == 405347750286045 + -205 * t18,
-        pz + dz * t18 == 246867310344900 + 63 * t18,
-    )
-)
-
-t19 = Int('t19')
-solver.add(
-    And(
-        px + dx * t19 == 274231496615407 + 30 * t19,
-        py + dy * t19 == 283191180514355 + 27 * t19,
-        pz + dz * t19 == 255949392007171 + 38 * t19,
-    )
-)
-
-t20 = Int('t20')
-solver.add(
-    And(
-        px + dx * t20 == 268173892092665 + -157 * t20,
-        py + dy * t20 == 387613124026942 + 54 * t20,
-        pz + dz * t20 == 256019132096528 + -103 * t20,
-    )
-)
-
-t21 = Int('t21')
-solver.add(
-    And(
-        px + dx * t21 == 145730094834998 + 288 * t21,
-        py + dy * t21 == 142101281327937 + 391 * t21,
-        pz + dz * t21 == 172439903235819 + 206 * t21,
-    )
-)
-
-t22 = Int('t22')
-solver.add(
-    And(
-        px + dx * t22 == 305847991862255 + 41 * t22,
-        py + dy * t22 == 276335347676657 + -89 * t22,
-        pz + dz * t22 == 503944348756392 + -189 * t22,
-    )
-)
-
-t23 = Int('t23')
-solver.add(
-    And(
-        px + dx * t23 == 265457297226648 + 54 * t23,
-        py + dy * t23 == 144055141922672 + 226 * t23,
-        pz + dz * t23 == 446464604425555 + -266 * t23,
-    )
-)
-
-t24 = Int('t24')
-solver.add(
-    And(
-        px + dx * t24 == 443126534652569 + -157 * t24,
-        py + dy * t24 == 178726379965761 + 78 * t24,
-        pz + dz * t24 == 343056914693889 + -55 * t24,
-    )
-)
-
-t25 = Int('t25')
-solver.add(
-    And(
-        px + dx * t25 == 317507350884249 + -71 * t25,
-        py + dy * t25 == 298357353125303 + 33 * t25,
-        pz + dz * t25 == 316323674823843 + -93 * t25,
-    )
-)
-
-t26 = Int('t26')
-solver.add(
-    And(
-        px + dx * t26 == 317102853816209 + 15 * t26,
-        py + dy * t26 == 234699930026565 + -16 * t26,
-        pz + dz * t26 == 480571101005673 + -203 * t26,
-    )
-)
-
-t27 = Int('t27')
-solver.add(
-    And(
-        px + dx * t27 == 283815679400309 + 23 * t27,
-        py + dy * t27 == 265670286941517 + 31 * t27,
-        pz + dz * t27 == 179687664955092 + 169 * t27,
-    )
-)
-
-t28 = Int('t28')
-solver.add(
-    And(
-        px + dx * t28 == 354277266977008 + -8 * t28,
-        py + dy * t28 == 90413312230484 + 100 * t28,
-        pz + dz * t28 == 298759791596563 + 22 * t28,
-    )
-)
-
-t29 = Int('t29')
-solver.add(
-    And(
-        px + dx * t29 == 188130119114625 + 171 * t29,
-        py + dy * t29 == 305334499796621 + -77 * t29,
-        pz + dz * t29 == 197145830115250 + 133 * t29,
-    )
-)
-
-t30 = Int('t30')
-solver.add(
-    And(
-        px + dx * t30 == 217619375871548 + 134 * t30,
-        py + dy * t30 == 283307600493768 + 622 * t30,
-        pz + dz * t30 == 172874363222667 + 366 * t30,
-    )
-)
-
-t31 = Int('t31')
-solver.add(
-    And(
-        px + dx * t31 == 327036301988501 + -55 * t31,
-        py + dy * t31 == 284507486595513 + 10 * t31,
-        pz + dz * t31 == 301783526443797 + -37 * t31,
-    )
-)
-
-t32 = Int('t32')
-solver.add(
-    And(
-        px + dx * t32 == 291294719486768 + -28 * t32,
-        py + dy * t32 == 281439705434034 + 90 * t32,
-        pz + dz * t32 == 111406071781614 + 339 * t32,
-    )
-)
-
-t33 = Int('t33')
-solver.add(
-    And(
-        px + dx * t33 == 393431484027881 + -85 * t33,
-        py + dy * t33 == 192044228859519 + 49 * t33,
-        pz + dz * t33 == 311300408945955 + -10 * t33,
-    )
-)
-
-t34 = Int('t34')
-solver.add(
-    And(
-        px + dx * t34 == 327212822886707 + 5 * t34,
-        py + dy * t34 == 176259962509678 + 48 * t34,
-        pz + dz * t34 == 417753026665839 + -126 * t34,
-    )
-)
-
-t35 = Int('t35')
-solver.add(
-    And(
-        px + dx * t35 == 253019932261409 + 95 * t35,
-        py + dy * t35 == 87556034477403 + 107 * t35,
-        pz + dz * t35 == 425707031938091 + -110 * t35,
-    )
-)
-
-t36 = Int('t36')
-solver.add(
-    And(
-        px + dx * t36 == 343802345556755 + -124 * t36,
-        py + dy * t36 == 200045103898485 + 232 * t36,
-        pz + dz * t36 == 358872039028185 + -179 * t36,
-    )
-)
-
-t37 = Int('t37')
-solver.add(
-    And(
-        px + dx * t37 == 226423296932161 + 108 * t37,
-        py + dy * t37 == 287474657895527 + 171 * t37,
-        pz + dz * t37 == 236227429420451 + 58 * t37,
-    )
-)
-
-t38 = Int('t38')
-solver.add(
-    And(
-        px + dx * t38 == 423624248192453 + -73 * t38,
-        py + dy * t38 == 222403770309101 + -41 * t38,
-        pz + dz * t38 == 230490512494973 + 92 * t38,
-    )
-)
-
-t39 = Int('t39')
-solver.add(
-    And(
-        px + dx * t39 == 259972516151316 + -132 * t39,
-        py + dy * t39 == 417326315176952 + -104 * t39,
-        pz + dz * t39 == 297367106203031 + -382 * t39,
-    )
-)
-
-t40 = Int('t40')
-solver.add(
-    And(
-        px + dx * t40 == 357044388564161 + -73 * t40,
-        py + dy * t40 == 168284250644379 + 143 * t40,
-        pz + dz * t40 == 392663023445865 + -151 * t40,
-    )
-)
-
-t41 = Int('t41')
-solver.add(
-    And(
-        px + dx * t41 == 286242181450641 + -21 * t41,
-        py + dy * t41 == 310282933434825 + 35 * t41,
-        pz + dz * t41 == 298355297631705 + -72 * t41,
-    )
-)
-
-t42 = Int('t42')
-solver.add(
-    And(
-        px + dx * t42 == 253948061505860 + -10 * t42,
-        py + dy * t42 == 369630604078812 + 26 * t42,
-        pz + dz * t42 == 239455106291328 + 27 * t42,
-    )
-)
-
-t43 = Int('t43')
-solver.add(
-    And(
-        px + dx * t43 == 239247112781688 + -116 * t43,
-        py + dy * t43 == 473045134379203 + -623 * t43,
-        pz + dz * t43 == 264130376870531 + -410 * t43,
-    )
-)
-
-t44 = Int('t44')
-solver.add(
-    And(
-        px + dx * t44 == 266954546929617 + -71 * t44,
-        py + dy * t44 == 409841545933499 + -126 * t44,
-        pz + dz * t44 == 190223858265703 + 227 * t44,
-    )
-)
-
-t45 = Int('t45')
-solver.add(
-    And(
-        px + dx * t45 == 294916260345622 + 24 * t45,
-        py + dy * t45 == 222459326307321 + 47 * t45,
-        pz + dz * t45 == 289284460090659 + 6 * t45,
-    )
-)
-
-t46 = Int('t46')
-solver.add(
-    And(
-        px + dx * t46 == 219407383690143 + 114 * t46,
-        py + dy * t46 == 427730064131051 + -65 * t46,
-        pz + dz * t46 == 234951115565415 + -96 * t46,
-    )
-)
-
-t47 = Int('t47')
-solver.add(
-    And(
-        px + dx * t47 == 301401145552568 + 42 * t47,
-        py + dy * t47 == 36984984825456 + 174 * t47,
-        pz + dz * t47 == 197971574438235 + 126 * t47,
-    )
-)
-
-t48 = Int('t48')
-solver.add(
-    And(
-        px + dx * t48 == 319901756793401 + -13 * t48,
-        py + dy * t48 == 364249214426947 + -149 * t48,
-        pz + dz * t48 == 483252487665531 + -270 * t48,
-    )
-)
-
-t49 = Int('t49')
-solver.add(
-    And(
-        px + dx * t49 == 280122340920530 + -14 * t49,
-        py + dy * t49 == 338555287223541 + -17 * t49,
-        pz + dz * t49 == 253750371602817 + 24 * t49,
-    )
-)
-
-t50 = Int('t50')
-solver.add(
-    And(
-        px + dx * t50 == 407171712463280 + -128 * t50,
-        py + dy * t50 == 363153037705467 + -151 * t50,
-        pz + dz * t50 == 353967780171198 + -81 * t50,
-    )
-)
-
-t51 = Int('t51')
-solver.add(
-    And(
-        px + dx * t51 == 269729137439913 + 55 * t51,
-        py + dy * t51 == 248583309313707 + 26 * t51,
-        pz + dz * t51 == 272666749250639 + 25 * t51,
-    )
-)
-
-t52 = Int('t52')
-solver.add(
-    And(
-        px + dx * t52 == 270357777166223 + 32 * t52,
-        py + dy * t52 == 506821092648093 + -382 * t52,
-        pz + dz * t52 == 426574947795905 + -289 * t52,
-    )
-)
-
-t53 = Int('t53')
-solver.add(
-    And(
-        px + dx * t53 == 275826795507555 + -63 * t53,
-        py + dy * t53 == 257671822789787 + 361 * t53,
-        pz + dz * t53 == 306324926273288 + -187 * t53,
-    )
-)
-
-t54 = Int('t54')
-solver.add(
-    And(
-        px + dx * t54 == 285012671761982 + 32 * t54,
-        py + dy * t54 == 521652273856721 + -377 * t54,
-        pz + dz * t54 == 216367020466425 + 108 * t54,
-    )
-)
-
-t55 = Int('t55')
-solver.add(
-    And(
-        px + dx * t55 == 327965141155649 + 17 * t55,
-        py + dy * t55 == 178050422463823 + 15 * t55,
-        pz + dz * t55 == 346598709787519 + -29 * t55,
-    )
-)
-
-t56 = Int('t56')
-solver.add(
-    And(
-        px + dx * t56 == 271104057789300 + 76 * t56,
-        py + dy * t56 == 199387239172395 + -7 * t56,
-        pz + dz * t56 == 229082348529192 + 93 * t56,
-    )
-)
-
-t57 = Int('t57')
-solver.add(
-    And(
-        px + dx * t57 == 237639276866838 + -48 * t57,
-        py + dy * t57 == 421292181214357 + -69 * t57,
-        pz + dz * t57 == 238483621512125 + -64 * t57,
-    )
-)
-
-t58 = Int('t58')
-solver.add(
-    And(
-        px + dx * t58 == 268609557946865 + 26 * t58,
-        py + dy * t58 == 417945112890887 + -209 * t58,
-        pz + dz * t58 == 234352501966459 + 73 * t58,
-    )
-)
-
-t59 = Int('t59')
-solver.add(
-    And(
-        px + dx * t59 == 460084443910591 + -306 * t59,
-        py + dy * t59 == 444183291889519 + -263 * t59,
-        pz + dz * t59 == 400893505677911 + -224 * t59,
-    )
-)
-
-t60 = Int('t60')
-solver.add(
-    And(
-        px + dx * t60 == 245379946602488 + -172 * t60,
-        py + dy * t60 == 419420052950156 + -8 * t60,
-        pz + dz * t60 == 246560981116374 + -189 * t60,
-    )
-)
-
-t61 = Int('t61')
-solver.add(
-    And(
-        px + dx * t61 == 311593654255928 + 8 * t61,
-        py + dy * t61 == 231552467174470 + 18 * t61,
-        pz + dz * t61 == 286506183154830 + 15 * t61,
-    )
-)
-
-t62 = Int('t62')
-solver.add(
-    And(
-        px + dx * t62 == 259640165565909 + 79 * t62,
-        py + dy * t62 == 216219162731006 + 24 * t62,
-        pz + dz * t62 == 373577535562947 + -90 * t62,
-    )
-)
-
-t63 = Int('t63')
-solver.add(
-    And(
-        px + dx * t63 == 376825234704953 + -21 * t63,
-        py + dy * t63 == 155953288596591 + 15 * t63,
-        pz + dz * t63 == 340461757659663 + -13 * t63,
-    )
-)
-
-t64 = Int('t64')
-solver.add(
-    And(
-        px + dx * t64 == 250701059815145 + -19 * t64,
-        py + dy * t64 == 381007974543967 + 22 * t64,
-        pz + dz * t64 == 245801016194043 + -15 * t64,
-    )
-)
-
-t65 = Int('t65')
-solver.add(
-    And(
-        px + dx * t65 == 307889037742285 + 21 * t65,
-        py + dy * t65 == 255168611968841 + -30 * t65,
-        pz + dz * t65 == 274256349658287 + 36 * t65,
-    )
-)
-
-t66 = Int('t66')
-solver.add(
-    And(
-        px + dx * t66 == 259743178472647 + 33 * t66,
-        py + dy * t66 == 195862295848667 + 321 * t66,
-        pz + dz * t66 == 385479856860614 + -287 * t66,
-    )
-)
-
-t67 = Int('t67')
-solver.add(
-    And(
-        px + dx * t67 == 356184986451331 + -30 * t67,
-        py + dy * t67 == 470259784254945 + -292 * t67,
-        pz + dz * t67 == 338726147578791 + -36 * t67,
-    )
-)
-
-t68 = Int('t68')
-solver.add(
-    And(
-        px + dx * t68 == 544879725653049 + -213 * t68,
-        py + dy * t68 == 240618134651495 + -47 * t68,
-        pz + dz * t68 == 269806636609235 + 50 * t68,
-    )
-)
-
-t69 = Int('t69')
-solver.add(
-    And(
-        px + dx * t69 == 226448920127645 + 111 * t69,
-        py + dy * t69 == 384358468265112 + -120 * t69,
-        pz + dz * t69 == 302896930125678 + -97 * t69,
-    )
-)
-
-t70 = Int('t70')
-solver.add(
-    And(
-        px + dx * t70 == 297191152126465 + -149 * t70,
-        py + dy * t70 == 204874378856127 + 581 * t70,
-        pz + dz * t70 == 383280058614266 + -475 * t70,
-    )
-)
-
-t71 = Int('t71')
-solver.add(
-    And(
-        px + dx * t71 == 238039746567361 + 27 * t71,
-        py + dy * t71 == 367609679835106 + 130 * t71,
-        pz + dz * t71 == 236093408040181 + 20 * t71,
-    )
-)
-
-t72 = Int('t72')
-solver.add(
-    And(
-        px + dx * t72 == 325134771317770 + 6 * t72,
-        py + dy * t72 == 239144052305622 + -22 * t72,
-        pz + dz * t72 == 444638567349793 + -160 * t72,
-    )
-)
-
-t73 = Int('t73')
-solver.add(
-    And(
-        px + dx * t73 == 207437495918169 + 163 * t73,
-        py + dy * t73 == 362557904124171 + -22 * t73,
-        pz + dz * t73 == 262637210676171 + -24 * t73,
-    )
-)
-
-t74 = Int('t74')
-solver.add(
-    And(
-        px + dx * t74 == 188566572519873 + 187 * t74,
-        py + dy * t74 == 176688051987113 + 243 * t74,
-        pz + dz * t74 == 158590319064474 + 219 * t74,
-    )
-)
-
-t75 = Int('t75')
-solver.add(
-    And(
-        px + dx * t75 == 421199238751382 + -150 * t75,
-        py + dy * t75 == 509889592963725 + -353 * t75,
-        pz + dz * t75 == 313629062398536 + -27 * t75,
-    )
-)
-
-t76 = Int('t76')
-solver.add(
-    And(
-        px + dx * t76 == 329474824303769 + 20 * t76,
-        py + dy * t76 == 137105704471351 + 45 * t76,
-        pz + dz * t76 == 308668584420995 + 14 * t76,
-    )
-)
-
-t77 = Int('t77')
-solver.add(
-    And(
-        px + dx * t77 == 384284947399523 + -43 * t77,
-        py + dy * t77 == 94442398625779 + 105 * t77,
-        pz + dz * t77 == 247151524648919 + 74 * t77,
-    )
-)
-
-t78 = Int('t78')
-solver.add(
-    And(
-        px + dx * t78 == 224628872740967 + 110 * t78,
-        py + dy * t78 == 397608510897879 + -115 * t78,
-        pz + dz * t78 == 304826638045071 + -172 * t78,
-    )
-)
-
-t79 = Int('t79')
-solver.add(
-    And(
-        px + dx * t79 == 224802125565602 + 72 * t79,
-        py + dy * t79 == 401888871179499 + 97 * t79,
-        pz + dz * t79 == 218504785717512 + 119 * t79,
-    )
-)
-
-t80 = Int('t80')
-solver.add(
-    And(
-        px + dx * t80 == 261639052395764 + -56 * t80,
-        py + dy * t80 == 292929865317270 + 382 * t80,
-        pz + dz * t80 == 278546258379453 + -148 * t80,
-    )
-)
-
-t81 = Int('t81')
-solver.add(
-    And(
-        px + dx * t81 == 464503384688289 + -296 * t81,
-        py + dy * t81 == 477737756450951 + -321 * t81,
-        pz + dz * t81 == 527338778199907 + -430 * t81,
-    )
-)
-
-t82 = Int('t82')
-solver.add(
-    And(
-        px + dx * t82 == 225304070101391 + 113 * t82,
-        py + dy * t82 == 36324730187453 + 763 * t82,
-        pz + dz * t82 == 137464093593680 + 311 * t82,
-    )
-)
-
-t83 = Int('t83')
-solver.add(
-    And(
-        px + dx * t83 == 391971233533925 + -49 * t83,
-        py + dy * t83 == 172560926934078 + 20 * t83,
-        pz + dz * t83 == 298633929998754 + 21 * t83,
-    )
-)
-
-t84 = Int('t84')
-solver.add(
-    And(
-        px + dx * t84 == 194646872355977 + 179 * t84,
-        py + dy * t84 == 342529410718314 + -56 * t84,
-        pz + dz * t84 == 226296913665495 + 90 * t84,
-    )
-)
-
-t85 = Int('t85')
-solver.add(
-    And(
-        px + dx * t85 == 301831680914049 + 15 * t85,
-        py + dy * t85 == 286165790187783 + -43 * t85,
-        pz + dz * t85 == 318516958643987 + -34 * t85,
-    )
-)
-
-t86 = Int('t86')
-solver.add(
-    And(
-        px + dx * t86 == 229272198230105 + -29 * t86,
-        py + dy * t86 == 406617091656483 + 250 * t86,
-        pz + dz * t86 == 264313273687395 + -530 * t86,
-    )
-)
-
-t87 = Int('t87')
-solver.add(
-    And(
-        px + dx * t87 == 206759346436820 + 152 * t87,
-        py + dy * t87 == 314394022225212 + -24 * t87,
-        pz + dz * t87 == 216618616528428 + 109 * t87,
-    )
-)
-
-t88 = Int('t88')
-solver.add(
-    And(
-        px + dx * t88 == 210109523618201 + 147 * t88,
-        py + dy * t88 == 252287712315219 + 118 * t88,
-        pz + dz * t88 == 327082928096211 + -110 * t88,
-    )
-)
-
-t89 = Int('t89')
-solver.add(
-    And(
-        px + dx * t89 == 359756323365687 + -8 * t89,
-        py + dy * t89 == 458267774674135 + -275 * t89,
-        pz + dz * t89 == 179671475220083 + 142 * t89,
-    )
-)
-
-t90 = Int('t90')
-solver.add(
-    And(
-        px + dx * t90 == 256636215052467 + 57 * t90,
-        py + dy * t90 == 382174219516492 + -144 * t90,
-        pz + dz * t90 == 22836371057063 + 482 * t90,
-    )
-)
-
-t91 = Int('t91')
-solver.add(
-    And(
-        px + dx * t91 == 303600101392345 + -221 * t91,
-        py + dy * t91 == 304099062287287 + 309 * t91,
-        pz + dz * t91 == 271877170686403 + -110 * t91,
-    )
-)
-
-t92 = Int('t92')
-solver.add(
-    And(
-        px + dx * t92 == 192427419113801 + 169 * t92,
-        py + dy * t92 == 270646375181823 + -6 * t92,
-        pz + dz * t92 == 205521109713267 + 124 * t92,
-    )
-)
-
-t93 = Int('t93')
-solver.add(
-    And(
-        px + dx * t93 == 317580015441782 + -16 * t93,
-        py + dy * t93 == 303321432596532 + -54 * t93,
-        pz + dz * t93 == 160901766712155 + 190 * t93,
-    )
-)
-
-t94 = Int('t94')
-solver.add(
-    And(
-        px + dx * t94 == 312210109792613 + -27 * t94,
-        py + dy * t94 == 308036264286237 + -34 * t94,
-        pz + dz * t94 == 276372788042127 + 8 * t94,
-    )
-)
-
-t95 = Int('t95')
-solver.add(
-    And(
-        px + dx * t95 == 274808900596306 + -12 * t95,
-        py + dy * t95 == 313220586507812 + 66 * t95,
-        pz + dz * t95 == 302025327543365 + -104 * t95,
-    )
-)
-
-t96 = Int('t96')
-solver.add(
-    And(
-        px + dx * t96 == 183695853615473 + 167 * t96,
-        py + dy * t96 == 47549844076063 + 153 * t96,
-        pz + dz * t96 == 245309240110855 + 76 * t96,
-    )
-)
-
-t97 = Int('t97')
-solver.add(
-    And(
-        px + dx * t97 == 189960720081275 + 176 * t97,
-        py + dy * t97 == 156846337036957 + 196 * t97,
-        pz + dz * t97 == 355773594863379 + -114 * t97,
-    )
-)
-
-t98 = Int('t98')
-solver.add(
-    And(
-        px + dx * t98 == 315898949803573 + 22 * t98,
-        py + dy * t98 == 42044780661455 + 187 * t98,
-        pz + dz * t98 == 267046640179187 + 50 * t98,
-    )
-)
-
-t99 = Int('t99')
-solver.add(
-    And(
-        px + dx * t99 == 418323980502335 + -70 * t99,
-        py + dy * t99 == 178070634118737 + 6 * t99,
-        pz + dz * t99 == 292119373352883 + 30 * t99,
-    )
-)
-
-t100 = Int('t100')
-solver.add(
-    And(
-        px + dx * t100 == 347391101811593 + -13 * t100,
-        py + dy * t100 == 215813841268803 + -7 * t100,
-        pz + dz * t100 == 243752174696145 + 76 * t100,
-    )
-)
-
-t101 = Int('t101')
-solver.add(
-    And(
-        px + dx * t101 == 38776347800773 + 313 * t101,
-        py + dy * t101 == 67432306786607 + 121 * t101,
-        pz + dz * t101 == 58776619731659 + 266 * t101,
-    )
-)
-
-t102 = Int('t102')
-solver.add(
-    And(
-        px + dx * t102 == 290017976156156 + 50 * t102,
-        py + dy * t102 == 59611625019226 + 172 * t102,
-        pz + dz * t102 == 197353083885397 + 128 * t102,
-    )
-)
-
-t103 = Int('t103')
-solver.add(
-    And(
-        px + dx * t103 == 229601257408320 + 114 * t103,
-        py + dy * t103 == 129680370628487 + 205 * t103,
-        pz + dz * t103 == 140327485196978 + 221 * t103,
-    )
-)
-
-t104 = Int('t104')
-solver.add(
-    And(
-        px + dx * t104 == 220146734490753 + 123 * t104,
-        py + dy * t104 == 391298290057083 + -55 * t104,
-        pz + dz * t104 == 344611334445703 + -398 * t104,
-    )
-)
-
-t105 = Int('t105')
-solver.add(
-    And(
-        px + dx * t105 == 174825913754515 + 201 * t105,
-        py + dy * t105 == 276681616157484 + 8 * t105,
-        pz + dz * t105 == 157894323921694 + 203 * t105,
-    )
-)
-
-t106 = Int('t106')
-solver.add(
-    And(
-        px + dx * t106 == 483401510893445 + -161 * t106,
-        py + dy * t106 == 357491162194512 + -166 * t106,
-        pz + dz * t106 == 463859876172903 + -166 * t106,
-    )
-)
-
-t107 = Int('t107')
-solver.add(
-    And(
-        px + dx * t107 == 245206442460985 + 73 * t107,
-        py + dy * t107 == 141656245486967 + 385 * t107,
-        pz + dz * t107 == 217620774820323 + 108 * t107,
-    )
-)
-
-t108 = Int('t108')
-solver.add(
-    And(
-        px + dx * t108 == 291464705325145 + -9 * t108,
-        py + dy * t108 == 277556116295287 + 55 * t108,
-        pz + dz * t108 == 297389023917603 + -45 * t108,
-    )
-)
-
-t109 = Int('t109')
-solver.add(
-    And(
-        px + dx * t109 == 261983560452785 + 11 * t109,
-        py + dy * t109 == 360894647865153 + -37 * t109,
-        pz + dz * t109 == 280311392609911 + -62 * t109,
-    )
-)
-
-t110 = Int('t110')
-solver.add(
-    And(
-        px + dx * t110 == 238119753223441 + 27 * t110,
-        py + dy * t110 == 384199740050288 + 42 * t110,
-        pz + dz * t110 == 223297579256688 + 87 * t110,
-    )
-)
-
-t111 = Int('t111')
-solver.add(
-    And(
-        px + dx * t111 == 342600534427159 + 9 * t111,
-        py + dy * t111 == 197167142655207 + -19 * t111,
-        pz + dz * t111 == 329559491968412 + -5 * t111,
-    )
-)
-
-t112 = Int('t112')
-solver.add(
-    And(
-        px + dx * t112 == 252758590023093 + 39 * t112,
-        py + dy * t112 == 359232287922907 + -39 * t112,
-        pz + dz * t112 == 176041820643661 + 220 * t112,
-    )
-)
-
-t113 = Int('t113')
-solver.add(
-    And(
-        px + dx * t113 == 313253294659799 + -7 * t113,
-        py + dy * t113 == 251781593278779 + 17 * t113,
-        pz + dz * t113 == 261074695981700 + 43 * t113,
-    )
-)
-
-t114 = Int('t114')
-solver.add(
-    And(
-        px + dx * t114 == 248049581038085 + -55 * t114,
-        py + dy * t114 == 423970640867607 + -147 * t114,
-        pz + dz * t114 == 261396846241653 + -153 * t114,
-    )
-)
-
-t115 = Int('t115')
-solver.add(
-    And(
-        px + dx * t115 == 310310365543019 + -7 * t115,
-        py + dy * t115 == 281706767922567 + -19 * t115,
-        pz + dz * t115 == 377342034050858 + -133 * t115,
-    )
-)
-
-t116 = Int('t116')
-solver.add(
-    And(
-        px + dx * t116 == 274524314300875 + 61 * t116,
-        py + dy * t116 == 363058350994865 + -161 * t116,
-        pz + dz * t116 == 422526291132292 + -149 * t116,
-    )
-)
-
-t117 = Int('t117')
-solver.add(
-    And(
-        px + dx * t117 == 212983922822636 + 172 * t117,
-        py + dy * t117 == 358147301791551 + 405 * t117,
-        pz + dz * t117 == 244530045292062 + -89 * t117,
-    )
-)
-
-t118 = Int('t118')
-solver.add(
-    And(
-        px + dx * t118 == 263592759586169 + 83 * t118,
-        py + dy * t118 == 439129308139773 + -256 * t118,
-        pz + dz * t118 == 339680624336391 + -24 * t118,
-    )
-)
-
-t119 = Int('t119')
-solver.add(
-    And(
-        px + dx * t119 == 233868210908012 + -10 * t119,
-        py + dy * t119 == 409660495064857 + 31 * t119,
-        pz + dz * t119 == 233230663284208 + -13 * t119,
-    )
-)
-
-t120 = Int('t120')
-solver.add(
-    And(
-        px + dx * t120 == 269259823873537 + -33 * t120,
-        py + dy * t120 == 396788847877277 + -114 * t120,
-        pz + dz * t120 == 259990192662409 + -25 * t120,
-    )
-)
-
-t121 = Int('t121')
-solver.add(
-    And(
-        px + dx * t121 == 216345390855161 + 135 * t121,
-        py + dy * t121 == 383912500300599 + -131 * t121,
-        pz + dz * t121 == 205451058893835 + 135 * t121,
-    )
-)
-
-t122 = Int('t122')
-solver.add(
-    And(
-        px + dx * t122 == 286337439756209 + -37 * t122,
-        py + dy * t122 == 339702196645491 + -7 * t122,
-        pz + dz * t122 == 252072691186197 + 24 * t122,
-    )
-)
-
-t123 = Int('t123')
-solver.add(
-    And(
-        px + dx * t123 == 275611813821911 + -187 * t123,
-        py + dy * t123 == 373676685727101 + 119 * t123,
-        pz + dz * t123 == 212650030133112 + 145 * t123,
-    )
-)
-
-t124 = Int('t124')
-solver.add(
-    And(
-        px + dx * t124 == 245406568832581 + 103 * t124,
-        py + dy * t124 == 362174337037453 + -177 * t124,
-        pz + dz * t124 == 90022762517045 + 236 * t124,
-    )
-)
-
-t125 = Int('t125')
-solver.add(
-    And(
-        px + dx * t125 == 338003345512565 + -10 * t125,
-        py + dy * t125 == 108786412025047 + 133 * t125,
-        pz + dz * t125 == 403159101348503 + -113 * t125,
-    )
-)
-
-t126 = Int('t126')
-solver.add(
-    And(
-        px + dx * t126 == 237607008125125 + -9 * t126,
-        py + dy * t126 == 409880740768920 + -28 * t126,
-        pz + dz * t126 == 194303698368487 + 290 * t126,
-    )
-)
-
-t127 = Int('t127')
-solver.add(
-    And(
-        px + dx * t127 == 320517997573421 + 32 * t127,
-        py + dy * t127 == 219675501957427 + -44 * t127,
-        pz + dz * t127 == 303132532685123 + 22 * t127,
-    )
-)
-
-t128 = Int('t128')
-solver.add(
-    And(
-        px + dx * t128 == 190493006619409 + 175 * t128,
-        py + dy * t128 == 145810007452413 + 212 * t128,
-        pz + dz * t128 == 300903507010435 + -26 * t128,
-    )
-)
-
-t129 = Int('t129')
-solver.add(
-    And(
-        px + dx * t129 == 409372170846134 + -196 * t129,
-        py + dy * t129 == 393434813069206 + -176 * t129,
-        pz + dz * t129 == 367775154646167 + -150 * t129,
-    )
-)
-
-t130 = Int('t130')
-solver.add(
-    And(
-        px + dx * t130 == 126706795930865 + 251 * t130,
-        py + dy * t130 == 185138771407284 + 78 * t130,
-        pz + dz * t130 == 279863075454285 + 24 * t130,
-    )
-)
-
-t131 = Int('t131')
-solver.add(
-    And(
-        px + dx * t131 == 259975694120207 + -100 * t131,
-        py + dy * t131 == 310184989013831 + 469 * t131,
-        pz + dz * t131 == 170094651355847 + 380 * t131,
-    )
-)
-
-t132 = Int('t132')
-solver.add(
-    And(
-        px + dx * t132 == 241669096325265 + -116 * t132,
-        py + dy * t132 == 333749292411567 + 878 * t132,
-        pz + dz * t132 == 208426046916243 + 228 * t132,
-    )
-)
-
-t133 = Int('t133')
-solver.add(
-    And(
-        px + dx * t133 == 276541809961373 + 63 * t133,
-        py + dy * t133 == 227328593043837 + -9 * t133,
-        pz + dz * t133 == 345768644614869 + -44 * t133,
-    )
-)
-
-t134 = Int('t134')
-solver.add(
-    And(
-        px + dx * t134 == 317215535236435 + -24 * t134,
-        py + dy * t134 == 357611609562111 + -127 * t134,
-        pz + dz * t134 == 227448490908701 + 91 * t134,
-    )
-)
-
-t135 = Int('t135')
-solver.add(
-    And(
-        px + dx * t135 == 297409993247840 + 38 * t135,
-        py + dy * t135 == 72133954474177 + 175 * t135,
-        pz + dz * t135 == 293703979805093 + 16 * t135,
-    )
-)
-
-t136 = Int('t136')
-solver.add(
-    And(
-        px + dx * t136 == 267425274950468 + 60 * t136,
-        py + dy * t136 == 178987597651560 + 120 * t136,
-        pz + dz * t136 == 327276547274805 + -52 * t136,
-    )
-)
-
-t137 = Int('t137')
-solver.add(
-    And(
-        px + dx * t137 == 225123145589429 + 109 * t137,
-        py + dy * t137 == 340048662295203 + 63 * t137,
-        pz + dz * t137 == 270745781295801 + -57 * t137,
-    )
-)
-
-t138 = Int('t138')
-solver.add(
-    And(
-        px + dx * t138 == 264716260516415 + 86 * t138,
-        py + dy * t138 == 373685852434491 + -193 * t138,
-        pz + dz * t138 == 193519345153847 + 128 * t138,
-    )
-)
-
-t139 = Int('t139')
-solver.add(
-    And(
-        px + dx * t139 == 219211205263007 + 125 * t139,
-        py + dy * t139 == 383081930275815 + 77 * t139,
-        pz + dz * t139 == 239521924631696 + -7 * t139,
-    )
-)
-
-t140 = Int('t140')
-solver.add(
-    And(
-        px + dx * t140 == 183533214122180 + 186 * t140,
-        py + dy * t140 == 37154332503678 + 384 * t140,
-        pz + dz * t140 == 273932305391058 + 17 * t140,
-    )
-)
-
-t141 = Int('t141')
-solver.add(
-    And(
-        px + dx * t141 == 211600294479339 + 154 * t141,
-        py + dy * t141 == 354700894876755 + 47 * t141,
-        pz + dz * t141 == 262056383423015 + -44 * t141,
-    )
-)
-
-t142 = Int('t142')
-solver.add(
-    And(
-        px + dx * t142 == 245726548134265 + 19 * t142,
-        py + dy * t142 == 396188140159227 + -73 * t142,
-        pz + dz * t142 == 224602979518873 + 85 * t142,
-    )
-)
-
-t143 = Int('t143')
-solver.add(
-    And(
-        px + dx * t143 == 296017416223129 + -20 * t143,
-        py + dy * t143 == 262019476718071 + 90 * t143,
-        pz + dz * t143 == 245168206357635 + 54 * t143,
-    )
-)
-
-t144 = Int('t144')
-solver.add(
-    And(
-        px + dx * t144 == 393308924026495 + -131 * t144,
-        py + dy * t144 == 467372206762237 + -297 * t144,
-        pz + dz * t144 == 509882972456228 + -331 * t144,
-    )
-)
-
-t145 = Int('t145')
-solver.add(
-    And(
-        px + dx * t145 == 237149300135070 + 66 * t145,
-        py + dy * t145 == 357245834641037 + 31 * t145,
-        pz + dz * t145 == 243498444015758 + 23 * t145,
-    )
-)
-
-t146 = Int('t146')
-solver.add(
-    And(
-        px + dx * t146 == 262792373213470 + -216 * t146,
-        py + dy * t146 == 447756982297962 + -304 * t146,
-        pz + dz * t146 == 301066314564528 + -525 * t146,
-    )
-)
-
-t147 = Int('t147')
-solver.add(
-    And(
-        px + dx * t147 == 236201706595106 + 98 * t147,
-        py + dy * t147 == 364333372414073 + -117 * t147,
-        pz + dz * t147 == 200189749005974 + 139 * t147,
-    )
-)
-
-t148 = Int('t148')
-solver.add(
-    And(
-        px + dx * t148 == 250325589368367 + 12 * t148,
-        py + dy * t148 == 340065027343661 + 118 * t148,
-        pz + dz * t148 == 256909487155833 + -33 * t148,
-    )
-)
-
-t149 = Int('t149')
-solver.add(
-    And(
-        px + dx * t149 == 322682840322333 + 30 * t149,
-        py + dy * t149 == 321922443943079 + -143 * t149,
-        pz + dz * t149 == 162465267997475 + 158 * t149,
-    )
-)
-
-t150 = Int('t150')
-solver.add(
-    And(
-        px + dx * t150 == 252845275150105 + 67 * t150,
-        py + dy * t150 == 166109785775702 + 250 * t150,
-        pz + dz * t150 == 254020415404633 + 40 * t150,
-    )
-)
-
-t151 = Int('t151')
-solver.add(
-    And(
-        px + dx * t151 == 290539383225193 + 27 * t151,
-        py + dy * t151 == 485118332097731 + -321 * t151,
-        pz + dz * t151 == 263568173368847 + 40 * t151,
-    )
-)
-
-t152 = Int('t152')
-solver.add(
-    And(
-        px + dx * t152 == 314213388853217 + -5 * t152,
-        py + dy * t152 == 273874838945961 + -21 * t152,
-        pz + dz * t152 == 298815518006850 + -9 * t152,
-    )
-)
-
-t153 = Int('t153')
-solver.add(
-    And(
-        px + dx * t153 == 249010320438313 + -17 * t153,
-        py + dy * t153 == 390481614169435 + -12 * t153,
-        pz + dz * t153 == 243765169904195 + -10 * t153,
-    )
-)
-
-t154 = Int('t154')
-solver.add(
-    And(
-        px + dx * t154 == 264546133397077 + -195 * t154,
-        py + dy * t154 == 347915307439685 + 402 * t154,
-        pz + dz * t154 == 295405452829817 + -425 * t154,
-    )
-)
-
-t155 = Int('t155')
-solver.add(
-    And(
-        px + dx * t155 == 287479772707633 + -68 * t155,
-        py + dy * t155 == 414798176994951 + -181 * t155,
-        pz + dz * t155 == 177572614230027 + 225 * t155,
-    )
-)
-
-t156 = Int('t156')
-solver.add(
-    And(
-        px + dx * t156 == 142206521256717 + 225 * t156,
-        py + dy * t156 == 242389904441473 + -12 * t156,
-        pz + dz * t156 == 65280407690499 + 294 * t156,
-    )
-)
-
-t157 = Int('t157')
-solver.add(
-    And(
-        px + dx * t157 == 226068175168529 + 44 * t157,
-        py + dy * t157 == 422152119716711 + -41 * t157,
-        pz + dz * t157 == 244053383631523 + -158 * t157,
-    )
-)
-
-t158 = Int('t158')
-solver.add(
-    And(
-        px + dx * t158 == 239770615926105 + -29 * t158,
-        py + dy * t158 == 416846132500671 + -73 * t158,
-        pz + dz * t158 == 222985020485767 + 83 * t158,
-    )
-)
-
-t159 = Int('t159')
-solver.add(
-    And(
-        px + dx * t159 == 267385178551504 + 72 * t159,
-        py + dy * t159 == 361873106344791 + -163 * t159,
-        pz + dz * t159 == 203732099641183 + 122 * t159,
-    )
-)
-
-t160 = Int('t160')
-solver.add(
-    And(
-        px + dx * t160 == 324949088506511 + -7 * t160,
-        py + dy * t160 == 219103967683671 + 29 * t160,
-        pz + dz * t160 == 331077336923604 + -41 * t160,
-    )
-)
-
-t161 = Int('t161')
-solver.add(
-    And(
-        px + dx * t161 == 242726897402074 + 88 * t161,
-        py + dy * t161 == 352269867368416 + -102 * t161,
-        pz + dz * t161 == 97011163496955 + 318 * t161,
-    )
-)
-
-t162 = Int('t162')
-solver.add(
-    And(
-        px + dx * t162 == 256506861588105 + 82 * t162,
-        py + dy * t162 == 319883885561847 + -103 * t162,
-        pz + dz * t162 == 277548233971123 + 29 * t162,
-    )
-)
-
-t163 = Int('t163')
-solver.add(
-    And(
-        px + dx * t163 == 226382902069970 + 106 * t163,
-        py + dy * t163 == 184416705088008 + 524 * t163,
-        pz + dz * t163 == 269758215192153 + -48 * t163,
-    )
-)
-
-t164 = Int('t164')
-solver.add(
-    And(
-        px + dx * t164 == 301649720323014 + 34 * t164,
-        py + dy * t164 == 186334340182818 + 38 * t164,
-        pz + dz * t164 == 22452578721493 + 332 * t164,
-    )
-)
-
-t165 = Int('t165')
-solver.add(
-    And(
-        px + dx * t165 == 248841817400819 + 62 * t165,
-        py + dy * t165 == 493995156705969 + -376 * t165,
-        pz + dz * t165 == 184845194231523 + 182 * t165,
-    )
-)
-
-t166 = Int('t166')
-solver.add(
-    And(
-        px + dx * t166 == 227803699895655 + 121 * t166,
-        py + dy * t166 == 360958880352603 + -175 * t166,
-        pz + dz * t166 == 172198482302653 + 152 * t166,
-    )
-)
-
-t167 = Int('t167')
-solver.add(
-    And(
-        px + dx * t167 == 246330580009595 + 97 * t167,
-        py + dy * t167 == 71604038045112 + 188 * t167,
-        pz + dz * t167 == 331445314710153 + -32 * t167,
-    )
-)
-
-t168 = Int('t168')
-solver.add(
-    And(
-        px + dx * t168 == 241861496905749 + 22 * t168,
-        py + dy * t168 == 413048278354139 + -126 * t168,
-        pz + dz * t168 == 254340624153939 + -54 * t168,
-    )
-)
-
-t169 = Int('t169')
-solver.add(
-    And(
-        px + dx * t169 == 237008737207956 + 30 * t169,
-        py + dy * t169 == 395872601195470 + -12 * t169,
-        pz + dz * t169 == 221538466193469 + 96 * t169,
-    )
-)
-
-t170 = Int('t170')
-solver.add(
-    And(
-        px + dx * t170 == 253638827421089 + -95 * t170,
-        py + dy * t170 == 296127490712211 + 670 * t170,
-        pz + dz * t170 == 158251668973779 + 498 * t170,
-    )
-)
-
-t171 = Int('t171')
-solver.add(
-    And(
-        px + dx * t171 == 297603380783105 + 11 * t171,
-        py + dy * t171 == 275137048217571 + -7 * t171,
-        pz + dz * t171 == 302510571012695 + -22 * t171,
-    )
-)
-
-t172 = Int('t172')
-solver.add(
-    And(
-        px + dx * t172 == 244153076889227 + -91 * t172,
-        py + dy * t172 == 389279503840137 + 191 * t172,
-        pz + dz * t172 == 249339330263060 + -145 * t172,
-    )
-)
-
-t173 = Int('t173')
-solver.add(
-    And(
-        px + dx * t173 == 258161109760168 + -20 * t173,
-        py + dy * t173 == 348985357786464 + 92 * t173,
-        pz + dz * t173 == 238697530128240 + 33 * t173,
-    )
-)
-
-t174 = Int('t174')
-solver.add(
-    And(
-        px + dx * t174 == 152456636067995 + 206 * t174,
-        py + dy * t174 == 79288225862379 + 155 * t174,
-        pz + dz * t174 == 237063482792721 + 83 * t174,
-    )
-)
-
-t175 = Int('t175')
-solver.add(
-    And(
-        px + dx * t175 == 313609592292443 + -47 * t175,
-        py + dy * t175 == 195836729561268 + 200 * t175,
-        pz + dz * t175 == 208085299426260 + 125 * t175,
-    )
-)
-
-t176 = Int('t176')
-solver.add(
-    And(
-        px + dx * t176 == 264859523645520 + -194 * t176,
-        py + dy * t176 == 380468739503827 + 169 * t176,
-        pz + dz * t176 == 225735319294618 + 65 * t176,
-    )
-)
-
-t177 = Int('t177')
-solver.add(
-    And(
-        px + dx * t177 == 252929779231061 + 82 * t177,
-        py + dy * t177 == 351140791527735 + -131 * t177,
-        pz + dz * t177 == 284282216420763 + 12 * t177,
-    )
-)
-
-t178 = Int('t178')
-solver.add(
-    And(
-        px + dx * t178 == 320818475035442 + 32 * t178,
-        py + dy * t178 == 126733705515175 + 45 * t178,
-        pz + dz * t178 == 293005764735413 + 32 * t178,
-    )
-)
-
-t179 = Int('t179')
-solver.add(
-    And(
-        px + dx * t179 == 271640824188383 + -148 * t179,
-        py + dy * t179 == 404397875087375 + -63 * t179,
-        pz + dz * t179 == 280225620114867 + -210 * t179,
-    )
-)
-
-t180 = Int('t180')
-solver.add(
-    And(
-        px + dx * t180 == 294926613177179 + -43 * t180,
-        py + dy * t180 == 435792225326613 + -245 * t180,
-        pz + dz * t180 == 421159908513108 + -353 * t180,
-    )
-)
-
-t181 = Int('t181')
-solver.add(
-    And(
-        px + dx * t181 == 163601335284041 + 203 * t181,
-        py + dy * t181 == 69153708744543 + 233 * t181,
-        pz + dz * t181 == 235573806748843 + 82 * t181,
-    )
-)
-
-t182 = Int('t182')
-solver.add(
-    And(
-        px + dx * t182 == 323711917742135 + -34 * t182,
-        py + dy * t182 == 142584270844479 + 209 * t182,
-        pz + dz * t182 == 311897348352861 + -41 * t182,
-    )
-)
-
-t183 = Int('t183')
-solver.add(
-    And(
-        px + dx * t183 == 210398743076103 + 165 * t183,
-        py + dy * t183 == 364131519667754 + 82 * t183,
-        pz + dz * t183 == 41461274599411 + 886 * t183,
-    )
-)
-
-t184 = Int('t184')
-solver.add(
-    And(
-        px + dx * t184 == 361669985691089 + -123 * t184,
-        py + dy * t184 == 276988494443175 + 33 * t184,
-        pz + dz * t184 == 347544013623303 + -123 * t184,
-    )
-)
-
-t185 = Int('t185')
-solver.add(
-    And(
-        px + dx * t185 == 228622494255699 + -47 * t185,
-        py + dy * t185 == 447141518124271 + -347 * t185,
-        pz + dz * t185 == 191028714048703 + 602 * t185,
-    )
-)
-
-t186 = Int('t186')
-solver.add(
-    And(
-        px + dx * t186 == 230946320766439 + 60 * t186,
-        py + dy * t186 == 371732249571627 + 131 * t186,
-        pz + dz * t186 == 233745668476439 + 28 * t186,
-    )
-)
-
-t187 = Int('t187')
-solver.add(
-    And(
-        px + dx * t187 == 387047622197732 + -66 * t187,
-        py + dy * t187 == 263650615046919 + -51 * t187,
-        pz + dz * t187 == 530761078904505 + -260 * t187,
-    )
-)
-
-t188 = Int('t188')
-solver.add(
-    And(
-        px + dx * t188 == 204318774492610 + 150 * t188,
-        py + dy * t188 == 285348414109612 + -44 * t188,
-        pz + dz * t188 == 180353018703128 + 157 * t188,
-    )
-)
-
-t189 = Int('t189')
-solver.add(
-    And(
-        px + dx * t189 == 316938644707683 + -116 * t189,
-        py + dy * t189 == 318395158499001 + 50 * t189,
-        pz + dz * t189 == 441571684647865 + -451 * t189,
-    )
-)
-
-t190 = Int('t190')
-solver.add(
-    And(
-        px + dx * t190 == 404386231732889 + -221 * t190,
-        py + dy * t190 == 435091191164301 + -246 * t190,
-        pz + dz * t190 == 263801046680007 + 20 * t190,
-    )
-)
-
-t191 = Int('t191')
-solver.add(
-    And(
-        px + dx * t191 == 214388183865873 + 139 * t191,
-        py + dy * t191 == 325524779011064 + -12 * t191,
-        pz + dz * t191 == 304333898756005 + -76 * t191,
-    )
-)
-
-t192 = Int('t192')
-solver.add(
-    And(
-        px + dx * t192 == 253443075382360 + 70 * t192,
-        py + dy * t192 == 144662659438177 + 255 * t192,
-        pz + dz * t192 == 166047542561593 + 196 * t192,
-    )
-)
-
-t193 = Int('t193')
-solver.add(
-    And(
-        px + dx * t193 == 289819947955942 + -192 * t193,
-        py + dy * t193 == 327933608612441 + 255 * t193,
-        pz + dz * t193 == 306499816377535 + -286 * t193,
-    )
-)
-
-t194 = Int('t194')
-solver.add(
-    And(
-        px + dx * t194 == 348636024502875 + -35 * t194,
-        py + dy * t194 == 135428003663637 + 131 * t194,
-        pz + dz * t194 == 149678516981253 + 192 * t194,
-    )
-)
-
-t195 = Int('t195')
-solver.add(
-    And(
-        px + dx * t195 == 301517904510352 + 48 * t195,
-        py + dy * t195 == 263200578126125 + -81 * t195,
-        pz + dz * t195 == 173215853076640 + 149 * t195,
-    )
-)
-
-t196 = Int('t196')
-solver.add(
-    And(
-        px + dx * t196 == 246737341196903 + -103 * t196,
-        py + dy * t196 == 405819322564735 + 37 * t196,
-        pz + dz * t196 == 227502077298049 + 44 * t196,
-    )
-)
-
-t197 = Int('t197')
-solver.add(
-    And(
-        px + dx * t197 == 237654890585495 + -19 * t197,
-        py + dy * t197 == 426112750459429 + -137 * t197,
-        pz + dz * t197 == 256419924673156 + -175 * t197,
-    )
-)
-
-t198 = Int('t198')
-solver.add(
-    And(
-        px + dx * t198 == 254430014927285 + 32 * t198,
-        py + dy * t198 == 403866678554647 + -155 * t198,
-        pz + dz * t198 == 232142472062723 + 70 * t198,
-    )
-)
-
-t199 = Int('t199')
-solver.add(
-    And(
-        px + dx * t199 == 244272028800102 + -6 * t199,
-        py + dy * t199 == 414522292160103 + -115 * t199,
-        pz + dz * t199 == 221371725024508 + 97 * t199,
-    )
-)
-
-t200 = Int('t200')
-solver.add(
-    And(
-        px + dx * t200 == 350727625829385 + -21 * t200,
-        py + dy * t200 == 226545920886697 + -12 * t200,
-        pz + dz * t200 == 352114534069073 + -49 * t200,
-    )
-)
-
-t201 = Int('t201')
-solver.add(
-    And(
-        px + dx * t201 == 168281220814693 + 193 * t201,
-        py + dy * t201 == 285093164334117 + -64 * t201,
-        pz + dz * t201 == 41821753037391 + 324 * t201,
-    )
-)
-
-t202 = Int('t202')
-solver.add(
-    And(
-        px + dx * t202 == 237224474564528 + 22 * t202,
-        py + dy * t202 == 401040727498329 + -25 * t202,
-        pz + dz * t202 == 236160314682033 + 12 * t202,
-    )
-)
-
-t203 = Int('t203')
-solver.add(
-    And(
-        px + dx * t203 == 230723550294953 + 89 * t203,
-        py + dy * t203 == 424917873359919 + -202 * t203,
-        pz + dz * t203 == 236273160877675 + 49 * t203,
-    )
-)
-
-t204 = Int('t204')
-solver.add(
-    And(
-        px + dx * t204 == 190058048682023 + 185 * t204,
-        py + dy * t204 == 289491655863888 + 34 * t204,
-        pz + dz * t204 == 260489524234914 + 25 * t204,
-    )
-)
-
-t205 = Int('t205')
-solver.add(
-    And(
-        px + dx * t205 == 273027022338905 + -29 * t205,
-        py + dy * t205 == 435453377172003 + -240 * t205,
-        pz + dz * t205 == 291060457086219 + -104 * t205,
-    )
-)
-
-t206 = Int('t206')
-solver.add(
-    And(
-        px + dx * t206 == 323863826623077 + -41 * t206,
-        py + dy * t206 == 261288486906273 + 35 * t206,
-        pz + dz * t206 == 291093020969518 + -13 * t206,
-    )
-)
-
-t207 = Int('t207')
-solver.add(
-    And(
-        px + dx * t207 == 381311469178660 + -42 * t207,
-        py + dy * t207 == 238264875168207 + -43 * t207,
-        pz + dz * t207 == 343018349522353 + -28 * t207,
-    )
-)
-
-t208 = Int('t208')
-solver.add(
-    And(
-        px + dx * t208 == 239959422048956 + 8 * t208,
-        py + dy * t208 == 413611453698967 + -99 * t208,
-        pz + dz * t208 == 337393091048823 + -558 * t208,
-    )
-)
-
-t209 = Int('t209')
-solver.add(
-    And(
-        px + dx * t209 == 390672856277883 + -62 * t209,
-        py + dy * t209 == 254260210150227 + -49 * t209,
-        pz + dz * t209 == 323211560142793 + -13 * t209,
-    )
-)
-
-t210 = Int('t210')
-solver.add(
-    And(
-        px + dx * t210 == 220405422230777 + 115 * t210,
-        py + dy * t210 == 424143081174633 + -132 * t210,
-        pz + dz * t210 == 261123292479783 + -188 * t210,
-    )
-)
-
-t211 = Int('t211')
-solver.add(
-    And(
-        px + dx * t211 == 317814328653385 + 13 * t211,
-        py + dy * t211 == 237592338375527 + -17 * t211,
-        pz + dz * t211 == 281224961588163 + 30 * t211,
-    )
-)
-
-t212 = Int('t212')
-solver.add(
-    And(
-        px + dx * t212 == 372248877968761 + -33 * t212,
-        py + dy * t212 == 192983238830927 + 6 * t212,
-        pz + dz * t212 == 272094983188123 + 47 * t212,
-    )
-)
-
-t213 = Int('t213')
-solver.add(
-    And(
-        px + dx * t213 == 281315993876090 + -106 * t213,
-        py + dy * t213 == 397199825306607 + -91 * t213,
-        pz + dz * t213 == 213490174830593 + 128 * t213,
-    )
-)
-
-t214 = Int('t214')
-solver.add(
-    And(
-        px + dx * t214 == 256373650644817 + 24 * t214,
-        py + dy * t214 == 360540918952599 + -31 * t214,
-        pz + dz * t214 == 248632791890587 + 23 * t214,
-    )
-)
-
-t215 = Int('t215')
-solver.add(
-    And(
-        px + dx * t215 == 275575031534705 + 44 * t215,
-        py + dy * t215 == 249264387231127 + 33 * t215,
-        pz + dz * t215 == 283113672563803 + 7 * t215,
-    )
-)
-
-t216 = Int('t216')
-solver.add(
-    And(
-        px + dx * t216 == 449385877224908 + -286 * t216,
-        py + dy * t216 == 326078515360436 + -50 * t216,
-        pz + dz * t216 == 524284356662575 + -446 * t216,
-    )
-)
-
-t217 = Int('t217')
-solver.add(
-    And(
-        px + dx * t217 == 313536732056795 + -94 * t217,
-        py + dy * t217 == 386014324064559 + -127 * t217,
-        pz + dz * t217 == 459921162471213 + -463 * t217,
-    )
-)
-
-t218 = Int('t218')
-solver.add(
-    And(
-        px + dx * t218 == 213450437840781 + 144 * t218,
-        py + dy * t218 == 300692433433167 + 131 * t218,
-        pz + dz * t218 == 335613357460107 + -216 * t218,
-    )
-)
-
-t219 = Int('t219')
-solver.add(
-    And(
-        px + dx * t219 == 274859796494865 + 21 * t219,
-        py + dy * t219 == 297125782560275 + 22 * t219,
-        pz + dz * t219 == 254954260763087 + 35 * t219,
-    )
-)
-
-t220 = Int('t220')
-solver.add(
-    And(
-        px + dx * t220 == 317873726016779 + 5 * t220,
-        py + dy * t220 == 246497638238614 + -12 * t220,
-        pz + dz * t220 == 314593121231524 + -17 * t220,
-    )
-)
-
-t221 = Int('t221')
-solver.add(
-    And(
-        px + dx * t221 == 195410532618745 + 181 * t221,
-        py + dy * t221 == 308659034016263 + 34 * t221,
-        pz + dz * t221 == 165820830551043 + 222 * t221,
-    )
-)
-
-t222 = Int('t222')
-solver.add(
-    And(
-        px + dx * t222 == 276636406073385 + 69 * t222,
-        py + dy * t222 == 266538617015367 + -73 * t222,
-        pz + dz * t222 == 211930157612523 + 111 * t222,
-    )
-)
-
-t223 = Int('t223')
-solver.add(
-    And(
-        px + dx * t223 == 413449598702423 + -66 * t223,
-        py + dy * t223 == 247670250781983 + -63 * t223,
-        pz + dz * t223 == 316576712343081 + 5 * t223,
-    )
-)
-
-t224 = Int('t224')
-solver.add(
-    And(
-        px + dx * t224 == 303751369571870 + -190 * t224,
-        py + dy * t224 == 396373289798112 + -88 * t224,
-        pz + dz * t224 == 294544878432753 + -176 * t224,
-    )
-)
-
-t225 = Int('t225')
-solver.add(
-    And(
-        px + dx * t225 == 449363367354400 + -114 * t225,
-        py + dy * t225 == 126776874403421 + 75 * t225,
-        pz + dz * t225 == 281758479642638 + 37 * t225,
-    )
-)
-
-t226 = Int('t226')
-solver.add(
-    And(
-        px + dx * t226 == 306959131227953 + -87 * t226,
-        py + dy * t226 == 483517257215199 + -361 * t226,
-        pz + dz * t226 == 372762778195947 + -272 * t226,
-    )
-)
-
-t227 = Int('t227')
-solver.add(
-    And(
-        px + dx * t227 == 234965559228377 + -35 * t227,
-        py + dy * t227 == 415241411419959 + 5 * t227,
-        pz + dz * t227 == 228112989082755 + 26 * t227,
-    )
-)
-
-t228 = Int('t228')
-solver.add(
-    And(
-        px + dx * t228 == 315096640041344 + -138 * t228,
-        py + dy * t228 == 265031848270326 + 232 * t228,
-        pz + dz * t228 == 175734010646799 + 226 * t228,
-    )
-)
-
-t229 = Int('t229')
-solver.add(
-    And(
-        px + dx * t229 == 224931637234775 + 113 * t229,
-        py + dy * t229 == 426167579929617 + -217 * t229,
-        pz + dz * t229 == 113948071865798 + 385 * t229,
-    )
-)
-
-t230 = Int('t230')
-solver.add(
-    And(
-        px + dx * t230 == 255242786323001 + 35 * t230,
-        py + dy * t230 == 329174345696275 + 33 * t230,
-        pz + dz * t230 == 252480177927766 + 19 * t230,
-    )
-)
-
-t231 = Int('t231')
-solver.add(
-    And(
-        px + dx * t231 == 238829928250448 + 62 * t231,
-        py + dy * t231 == 337419222410424 + 90 * t231,
-        pz + dz * t231 == 304298097895563 + -178 * t231,
-    )
-)
-
-t232 = Int('t232')
-solver.add(
-    And(
-        px + dx * t232 == 276542932074572 + -96 * t232,
-        py + dy * t232 == 337033291374879 + 149 * t232,
-        pz + dz * t232 == 447779154818487 + -782 * t232,
-    )
-)
-
-t233 = Int('t233')
-solver.add(
-    And(
-        px + dx * t233 == 224277906155665 + 109 * t233,
-        py + dy * t233 == 322106728847627 + 172 * t233,
-        pz + dz * t233 == 328044360034623 + -285 * t233,
-    )
-)
-
-t234 = Int('t234')
-solver.add(
-    And(
-        px + dx * t234 == 342471593470058 + -132 * t234,
-        py + dy * t234 == 300168184358787 + 41 * t234,
-        pz + dz * t234 == 318733865717811 + -106 * t234,
-    )
-)
-
-t235 = Int('t235')
-solver.add(
-    And(
-        px + dx * t235 == 279397154254970 + 14 * t235,
-        py + dy * t235 == 343570156775787 + -71 * t235,
-        pz + dz * t235 == 328761990688278 + -105 * t235,
-    )
-)
-
-t236 = Int('t236')
-solver.add(
-    And(
-        px + dx * t236 == 237311503150804 + 70 * t236,
-        py + dy * t236 == 469911741188278 + -348 * t236,
-        pz + dz * t236 == 236430921483672 + 51 * t236,
-    )
-)
-
-t237 = Int('t237')
-solver.add(
-    And(
-        px + dx * t237 == 163553711268733 + 223 * t237,
-        py + dy * t237 == 262718939353865 + 43 * t237,
-        pz + dz * t237 == 314198487467041 + -56 * t237,
-    )
-)
-
-t238 = Int('t238')
-solver.add(
-    And(
-        px + dx * t238 == 273246200444207 + -140 * t238,
-        py + dy * t238 == 382207087452219 + 35 * t238,
-        pz + dz * t238 == 239932772430915 + 6 * t238,
-    )
-)
-
-t239 = Int('t239')
-solver.add(
-    And(
-        px + dx * t239 == 405722309789197 + -51 * t239,
-        py + dy * t239 == 438877185173229 + -256 * t239,
-        pz + dz * t239 == 180226720634049 + 141 * t239,
-    )
-)
-
-t240 = Int('t240')
-solver.add(
-    And(
-        px + dx * t240 == 313470221835370 + -74 * t240,
-        py + dy * t240 == 291331685030307 + 65 * t240,
-        pz + dz * t240 == 212049985689993 + 120 * t240,
-    )
-)
-
-t241 = Int('t241')
-solver.add(
-    And(
-        px + dx * t241 == 431219618065897 + -77 * t241,
-        py + dy * t241 == 132622196749649 + 43 * t241,
-        pz + dz * t241 == 518497804844082 + -189 * t241,
-    )
-)
-
-t242 = Int('t242')
-solver.add(
-    And(
-        px + dx * t242 == 452480016925861 + -113 * t242,
-        py + dy * t242 == 152909739410548 + 42 * t242,
-        pz + dz * t242 == 413444920324842 + -99 * t242,
-    )
-)
-
-t243 = Int('t243')
-solver.add(
-    And(
-        px + dx * t243 == 88829548759257 + 259 * t243,
-        py + dy * t243 == 164111232273937 + 16 * t243,
-        pz + dz * t243 == 114327617757873 + 207 * t243,
-    )
-)
-
-t244 = Int('t244')
-solver.add(
-    And(
-        px + dx * t244 == 230233245111083 + 8 * t244,
-        py + dy * t244 == 418214329529835 + -17 * t244,
-        pz + dz * t244 == 228075143090871 + 24 * t244,
-    )
-)
-
-t245 = Int('t245')
-solver.add(
-    And(
-        px + dx * t245 == 304509308039540 + 26 * t245,
-        py + dy * t245 == 238841592710834 + -12 * t245,
-        pz + dz * t245 == 317459283714085 + -16 * t245,
-    )
-)
-
-t246 = Int('t246')
-solver.add(
-    And(
-        px + dx * t246 == 242662932864281 + 17 * t246,
-        py + dy * t246 == 350618929108887 + 166 * t246,
-        pz + dz * t246 == 251155289406435 + -41 * t246,
-    )
-)
-
-t247 = Int('t247')
-solver.add(
-    And(
-        px + dx * t247 == 348524426436457 + -77 * t247,
-        py + dy * t247 == 314737872125720 + -56 * t247,
-        pz + dz * t247 == 303150209660151 + -30 * t247,
-    )
-)
-
-t248 = Int('t248')
-solver.add(
-    And(
-        px + dx * t248 == 425963677808745 + -69 * t248,
-        py + dy * t248 == 155199322587279 + 17 * t248,
-        pz + dz * t248 == 227691859476209 + 95 * t248,
-    )
-)
-
-t249 = Int('t249')
-solver.add(
-    And(
-        px + dx * t249 == 258143591224361 + 27 * t249,
-        py + dy * t249 == 370459141004643 + -73 * t249,
-        pz + dz * t249 == 458784506326683 + -518 * t249,
-    )
-)
-
-t250 = Int('t250')
-solver.add(
-    And(
-        px + dx * t250 == 276015069416403 + -30 * t250,
-        py + dy * t250 == 351412572800031 + -7 * t250,
-        pz + dz * t250 == 270368426249345 + -37 * t250,
-    )
-)
-
-t251 = Int('t251')
-solver.add(
-    And(
-        px + dx * t251 == 345732335893709 + -25 * t251,
-        py + dy * t251 == 195001246917149 + 43 * t251,
-        pz + dz * t251 == 325093740754435 + -26 * t251,
-    )
-)
-
-t252 = Int('t252')
-solver.add(
-    And(
-        px + dx * t252 == 362711363000271 + -111 * t252,
-        py + dy * t252 == 283081460148089 + 7 * t252,
-        pz + dz * t252 == 264620481268225 + 28 * t252,
-    )
-)
-
-t253 = Int('t253')
-solver.add(
-    And(
-        px + dx * t253 == 293862466025198 + -52 * t253,
-        py + dy * t253 == 319934287436242 + 36 * t253,
-        pz + dz * t253 == 184015179916795 + 190 * t253,
-    )
-)
-
-t254 = Int('t254')
-solver.add(
-    And(
-        px + dx * t254 == 300201936571798 + -212 * t254,
-        py + dy * t254 == 521140877272688 + -590 * t254,
-        pz + dz * t254 == 289783914924793 + -188 * t254,
-    )
-)
-
-t255 = Int('t255')
-solver.add(
-    And(
-        px + dx * t255 == 409384133843569 + -162 * t255,
-        py + dy * t255 == 319271940577303 + -71 * t255,
-        pz + dz * t255 == 279156613846323 + 12 * t255,
-    )
-)
-
-t256 = Int('t256')
-solver.add(
-    And(
-        px + dx * t256 == 235905846628205 + 41 * t256,
-        py + dy * t256 == 279098252472803 + 567 * t256,
-        pz + dz * t256 == 382106263847083 + -718 * t256,
-    )
-)
-
-t257 = Int('t257')
-solver.add(
-    And(
-        px + dx * t257 == 377802732051335 + -70 * t257,
-        py + dy * t257 == 221963240519457 + 18 * t257,
-        pz + dz * t257 == 147348254385723 + 194 * t257,
-    )
-)
-
-t258 = Int('t258')
-solver.add(
-    And(
-        px + dx * t258 == 223147935266293 + 70 * t258,
-        py + dy * t258 == 411221430298519 + 117 * t258,
-        pz + dz * t258 == 224752800223807 + 49 * t258,
-    )
-)
-
-t259 = Int('t259')
-solver.add(
-    And(
-        px + dx * t259 == 228409269579218 + 104 * t259,
-        py + dy * t259 == 444626185420180 + -266 * t259,
-        pz + dz * t259 == 166513325788745 + 244 * t259,
-    )
-)
-
-t260 = Int('t260')
-solver.add(
-    And(
-        px + dx * t260 == 151123821599091 + 237 * t260,
-        py + dy * t260 == 123864886643510 + 244 * t260,
-        pz + dz * t260 == 238126296605255 + 74 * t260,
-    )
-)
-
-t261 = Int('t261')
-solver.add(
-    And(
-        px + dx * t261 == 307093987146275 + -159 * t261,
-        py + dy * t261 == 379714843167696 + -56 * t261,
-        pz + dz * t261 == 266419236444753 + -48 * t261,
-    )
-)
-
-t262 = Int('t262')
-solver.add(
-    And(
-        px + dx * t262 == 208049377700500 + 144 * t262,
-        py + dy * t262 == 221098255631227 + 25 * t262,
-        pz + dz * t262 == 263970672168443 + 46 * t262,
-    )
-)
-
-t263 = Int('t263')
-solver.add(
-    And(
-        px + dx * t263 == 303054635830541 + -15 * t263,
-        py + dy * t263 == 294856337954709 + -6 * t263,
-        pz + dz * t263 == 268680935068161 + 19 * t263,
-    )
-)
-
-t264 = Int('t264')
-solver.add(
-    And(
-        px + dx * t264 == 229314585617959 + 33 * t264,
-        py + dy * t264 == 418862230435258 + -56 * t264,
-        pz + dz * t264 == 267542619146805 + -312 * t264,
-    )
-)
-
-t265 = Int('t265')
-solver.add(
-    And(
-        px + dx * t265 == 293669872879595 + 26 * t265,
-        py + dy * t265 == 367896946991017 + -156 * t265,
-        pz + dz * t265 == 201720949773263 + 128 * t265,
-    )
-)
-
-t266 = Int('t266')
-solver.add(
-    And(
-        px + dx * t266 == 255686713031885 + 26 * t266,
-        py + dy * t266 == 351903932376711 + -7 * t266,
-        pz + dz * t266 == 264378989343327 + -21 * t266,
-    )
-)
-
-t267 = Int('t267')
-solver.add(
-    And(
-        px + dx * t267 == 266607574758005 + -154 * t267,
-        py + dy * t267 == 401000634407851 + -18 * t267,
-        pz + dz * t267 == 219398762340027 + 108 * t267,
-    )
-)
-
-t268 = Int('t268')
-solver.add(
-    And(
-        px + dx * t268 == 343627019798124 + -26 * t268,
-        py + dy * t268 == 246175852136572 + -14 * t268,
-        pz + dz * t268 == 512909523569205 + -264 * t268,
-    )
-)
-
-t269 = Int('t269')
-solver.add(
-    And(
-        px + dx * t269 == 292437319488810 + -92 * t269,
-        py + dy * t269 == 266766846992357 + 267 * t269,
-        pz + dz * t269 == 303356561851098 + -147 * t269,
-    )
-)
-
-t270 = Int('t270')
-solver.add(
-    And(
-        px + dx * t270 == 303854432104229 + 20 * t270,
-        py + dy * t270 == 234294141498451 + 10 * t270,
-        pz + dz * t270 == 294533122458627 + 6 * t270,
-    )
-)
-
-t271 = Int('t271')
-solver.add(
-    And(
-        px + dx * t271 == 215546399507603 + 134 * t271,
-        py + dy * t271 == 116183320507851 + 115 * t271,
-        pz + dz * t271 == 372858484485153 + -73 * t271,
-    )
-)
-
-t272 = Int('t272')
-solver.add(
-    And(
-        px + dx * t272 == 248136881680325 + -49 * t272,
-        py + dy * t272 == 391007587950681 + 47 * t272,
-        pz + dz * t272 == 268382285064291 + -186 * t272,
-    )
-)
-
-t273 = Int('t273')
-solver.add(
-    And(
-        px + dx * t273 == 234782346846530 + -34 * t273,
-        py + dy * t273 == 494355549983967 + -779 * t273,
-        pz + dz * t273 == 247921721741640 + -171 * t273,
-    )
-)
-
-t274 = Int('t274')
-solver.add(
-    And(
-        px + dx * t274 == 363192222650684 + -18 * t274,
-        py + dy * t274 == 221986589921001 + -33 * t274,
-        pz + dz * t274 == 259353636719643 + 62 * t274,
-    )
-)
-
-t275 = Int('t275')
-solver.add(
-    And(
-        px + dx * t275 == 276155580985958 + -22 * t275,
-        py + dy * t275 == 336211596217428 + 20 * t275,
-        pz + dz * t275 == 284858638982773 + -68 * t275,
-    )
-)
-
-t276 = Int('t276')
-solver.add(
-    And(
-        px + dx * t276 == 282174699854010 + -8 * t276,
-        py + dy * t276 == 305188524637692 + 38 * t276,
-        pz + dz * t276 == 261406770410318 + 13 * t276,
-    )
-)
-
-t277 = Int('t277')
-solver.add(
-    And(
-        px + dx * t277 == 320511755321145 + 6 * t277,
-        py + dy * t277 == 244634023349335 + -18 * t277,
-        pz + dz * t277 == 257265475561443 + 57 * t277,
-    )
-)
-
-t278 = Int('t278')
-solver.add(
-    And(
-        px + dx * t278 == 66971777104821 + 288 * t278,
-        py + dy * t278 == 241669016746231 + -51 * t278,
-        pz + dz * t278 == 158787992263555 + 166 * t278,
-    )
-)
-
-t279 = Int('t279')
-solver.add(
-    And(
-        px + dx * t279 == 294368676651785 + -45 * t279,
-        py + dy * t279 == 314665166754177 + 35 * t279,
-        pz + dz * t279 == 228212823323973 + 84 * t279,
-    )
-)
-
-t280 = Int('t280')
-solver.add(
-    And(
-        px + dx * t280 == 338828518386317 + -25 * t280,
-        py + dy * t280 == 142617268061718 + 128 * t280,
-        pz + dz * t280 == 320976106719513 + -28 * t280,
-    )
-)
-
-t281 = Int('t281')
-solver.add(
-    And(
-        px + dx * t281 == 319776438858095 + -44 * t281,
-        py + dy * t281 == 306673384812765 + -26 * t281,
-        pz + dz * t281 == 385908913297893 + -183 * t281,
-    )
-)
-
-t282 = Int('t282')
-solver.add(
-    And(
-        px + dx * t282 == 358737002431245 + -99 * t282,
-        py + dy * t282 == 388182760881127 + -171 * t282,
-        pz + dz * t282 == 258313482452743 + 40 * t282,
-    )
-)
-
-t283 = Int('t283')
-solver.add(
-    And(
-        px + dx * t283 == 267323686632791 + -91 * t283,
-        py + dy * t283 == 370215962843955 + 65 * t283,
-        pz + dz * t283 == 256959554673048 + -63 * t283,
-    )
-)
-
-t284 = Int('t284')
-solver.add(
-    And(
-        px + dx * t284 == 231524131093292 + 40 * t284,
-        py + dy * t284 == 400994267551944 + 20 * t284,
-        pz + dz * t284 == 231873985843329 + 24 * t284,
-    )
-)
-
-t285 = Int('t285')
-solver.add(
-    And(
-        px + dx * t285 == 308437363113215 + -259 * t285,
-        py + dy * t285 == 368126339780840 + 60 * t285,
-        pz + dz * t285 == 293103849593885 + -212 * t285,
-    )
-)
-
-t286 = Int('t286')
-solver.add(
-    And(
-        px + dx * t286 == 190786479783439 + 193 * t286,
-        py + dy * t286 == 466089361087 + 741 * t286,
-        pz + dz * t286 == 305628756672062 + -91 * t286,
-    )
-)
-
-t287 = Int('t287')
-solver.add(
-    And(
-        px + dx * t287 == 285594319782029 + 53 * t287,
-        py + dy * t287 == 139377422186787 + 91 * t287,
-        pz + dz * t287 == 286124291710731 + 26 * t287,
-    )
-)
-
-t288 = Int('t288')
-solver.add(
-    And(
-        px + dx * t288 == 304729929268880 + 16 * t288,
-        py + dy * t288 == 343354040465328 + -128 * t288,
-        pz + dz * t288 == 292686079957347 + 6 * t288,
-    )
-)
-
-t289 = Int('t289')
-solver.add(
-    And(
-        px + dx * t289 == 157641242982995 + 194 * t289,
-        py + dy * t289 == 234477187081287 + -43 * t289,
-        pz + dz * t289 == 143569944431203 + 182 * t289,
-    )
-)
-
-t290 = Int('t290')
-solver.add(
-    And(
-        px + dx * t290 == 271635899390446 + 70 * t290,
-        py + dy * t290 == 401642527313401 + -213 * t290,
-        pz + dz * t290 == 108206266391555 + 230 * t290,
-    )
-)
-
-t291 = Int('t291')
-solver.add(
-    And(
-        px + dx * t291 == 268390077989593 + -125 * t291,
-        py + dy * t291 == 388991475585177 + 11 * t291,
-        pz + dz * t291 == 259268813203737 + -96 * t291,
-    )
-)
-
-t292 = Int('t292')
-solver.add(
-    And(
-        px + dx * t292 == 558756189530084 + -322 * t292,
-        py + dy * t292 == 451743170877306 + -272 * t292,
-        pz + dz * t292 == 472294744774308 + -233 * t292,
-    )
-)
-
-t293 = Int('t293')
-solver.add(
-    And(
-        px + dx * t293 == 220980725636071 + 124 * t293,
-        py + dy * t293 == 407309980480139 + -173 * t293,
-        pz + dz * t293 == 269989001290617 + -21 * t293,
-    )
-)
-
-t294 = Int('t294')
-solver.add(
-    And(
-        px + dx * t294 == 377742004992795 + -71 * t294,
-        py + dy * t294 == 290286641421687 + -67 * t294,
-        pz + dz * t294 == 353930686655128 + -67 * t294,
-    )
-)
-
-t295 = Int('t295')
-solver.add(
-    And(
-        px + dx * t295 == 358195621698265 + -29 * t295,
-        py + dy * t295 == 230164474119143 + -17 * t295,
-        pz + dz * t295 == 317568672131355 + -9 * t295,
-    )
-)
-
-t296 = Int('t296')
-solver.add(
-    And(
-        px + dx * t296 == 230545899424788 + 28 * t296,
-        py + dy * t296 == 422728105615127 + -99 * t296,
-        pz + dz * t296 == 213323684027524 + 161 * t296,
-    )
-)
-
-t297 = Int('t297')
-solver.add(
-    And(
-        px + dx * t297 == 283957679948759 + 53 * t297,
-        py + dy * t297 == 183838154793909 + 47 * t297,
-        pz + dz * t297 == 368886062272691 + -74 * t297,
-    )
-)
-
-t298 = Int('t298')
-solver.add(
-    And(
-        px + dx * t298 == 328390183181243 + -31 * t298,
-        py + dy * t298 == 361677503224065 + -141 * t298,
-        pz + dz * t298 == 266007888635046 + 35 * t298,
-    )
-)
-
-t299 = Int('t299')
-solver.add(
-    And(
-        px + dx * t299 == 289605297584183 + 30 * t299,
-        py + dy * t299 == 215606437887927 + 61 * t299,
-        pz + dz * t299 == 390190548172323 + -138 * t299,
-    )
-)
-
-solver.check()
-model = solver.model()
-print(model.eval(px + py + pz))
@@ -0,0 +1,74 @@
+//! Day 01
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+/// A digit found in a line of the calibration document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    value: u8,
+    /// Whether it was spelled out as a word rather than written as a digit.
+    spelled: bool,
+}
+
+fn partial_line_to_number(s: &str) -> Option<Digit> {
+    let c = s.chars().next()?;
+    const WORDS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    if let Some(value) = WORDS.iter().position(|word| s.starts_with(word)) {
+        Some(Digit {
+            value: value as u8,
+            spelled: true,
+        })
+    } else {
+        c.to_digit(10).map(|n| Digit {
+            value: n as u8,
+            spelled: false,
+        })
+    }
+}
+
+/// Every digit in the line, written or spelled, in order.
+fn line_to_digits(line: &str) -> Vec<Digit> {
+    line.char_indices()
+        .filter_map(|(i, _)| partial_line_to_number(&line[i..]))
+        .collect()
+}
+
+/// The first digit followed by the last, counting only those `counts`.
+fn calibration_value(line: &[Digit], counts: impl Fn(&Digit) -> bool) -> Result<u32> {
+    let mut numbers = line
+        .iter()
+        .filter(|d| counts(d))
+        .map(|d| u32::from(d.value));
+    let first = numbers.next().context("No numbers found in line")?;
+    let last = numbers.last().unwrap_or(first);
+    Ok(first * 10 + last)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Vec<Digit>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().map(line_to_digits).collect())
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Result<Self::Part1> {
+        lines
+            .iter()
+            .map(|line| calibration_value(line, |d| !d.spelled))
+            .sum()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Result<Self::Part2> {
+        lines
+            .iter()
+            .map(|line| calibration_value(line, |_| true))
+            .sum()
+    }
+}
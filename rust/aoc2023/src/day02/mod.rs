@@ -0,0 +1,144 @@
+//! Day 02
+
+use anyhow::Result;
+use aoc_common::Solution;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::u32 as nom_u32,
+    combinator::value,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::enum_variant_names)]
+enum Ball {
+    Blue,
+    Red,
+    Green,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Bag {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Bag {
+    fn new() -> Self {
+        Bag {
+            red: 0,
+            green: 0,
+            blue: 0,
+        }
+    }
+
+    fn contained_in_bag(&self, other: &Bag) -> bool {
+        self.red <= other.red && self.green <= other.green && self.blue <= other.blue
+    }
+
+    fn minimum_combined_bag(&self, other: &Bag) -> Bag {
+        Bag {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+impl From<Vec<(u32, Ball)>> for Bag {
+    fn from(value: Vec<(u32, Ball)>) -> Self {
+        let mut bag = Bag::new();
+        for (count, ball) in value {
+            match ball {
+                Ball::Blue => bag.blue += count,
+                Ball::Red => bag.red += count,
+                Ball::Green => bag.green += count,
+            }
+        }
+        bag
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Game {
+    game_number: u32,
+    bags: Vec<Bag>,
+}
+
+fn parse_ball(input: &str) -> IResult<&str, Ball> {
+    alt((
+        value(Ball::Blue, tag("blue")),
+        value(Ball::Red, tag("red")),
+        value(Ball::Green, tag("green")),
+    ))(input)
+}
+
+fn parse_bag(input: &str) -> IResult<&str, Bag> {
+    let (input, balls) =
+        separated_list1(tag(", "), separated_pair(nom_u32, tag(" "), parse_ball))(input)?;
+    Ok((input, Bag::from(balls)))
+}
+
+fn line_to_games(input: &str) -> Result<Game> {
+    // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    let (_, (game_number, bags)) = separated_pair(
+        preceded(tag("Game "), nom_u32),
+        tag(": "),
+        separated_list1(tag("; "), parse_bag),
+    )(input)
+    .map_err(|e| e.to_owned())?;
+    Ok(Game { game_number, bags })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(line_to_games).collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Self::Part1> {
+        let original_bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        let game_sum = games
+            .iter()
+            .filter(|g| g.bags.iter().all(|b| b.contained_in_bag(&original_bag)))
+            .map(|g| g.game_number)
+            .sum::<u32>();
+
+        Ok(game_sum)
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Self::Part2> {
+        let power_sum: u32 = games
+            .iter()
+            .map(|g| {
+                g.bags
+                    .iter()
+                    .fold(Bag::new(), |combined_bag, b| {
+                        combined_bag.minimum_combined_bag(b)
+                    })
+                    .power()
+            })
+            .sum();
+
+        Ok(power_sum)
+    }
+}
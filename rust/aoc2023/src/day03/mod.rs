@@ -1,13 +1,9 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::Result;
-use thiserror::Error;
+use aoc_common::Solution;
 
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
-
-struct Board {
+pub struct Board {
     symbols: BTreeMap<(i32, i32), (char, BTreeSet<u32>)>,
     span_numbers: BTreeMap<u32, u32>,
 }
@@ -59,57 +55,50 @@ fn parse_board(input: &str) -> Board {
     }
 }
 
-fn part1(input: &str) -> Result<String> {
-    let board = parse_board(input);
-
-    let mut seen_spans = BTreeSet::new();
-    for (_, (_, seen)) in board.symbols.iter() {
-        seen_spans.extend(seen);
-    }
+pub struct Day03;
 
-    let mut span_numbers = Vec::new();
+impl Solution for Day03 {
+    type Input<'a> = Board;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    for span_id in seen_spans.iter() {
-        span_numbers.push(board.span_numbers[span_id]);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_board(input))
     }
 
-    Ok(span_numbers.iter().sum::<u32>().to_string())
-}
+    fn part1(board: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut seen_spans = BTreeSet::new();
+        for (_, (_, seen)) in board.symbols.iter() {
+            seen_spans.extend(seen);
+        }
 
-fn part2(input: &str) -> Result<String> {
-    let board = parse_board(input);
+        let mut span_numbers = Vec::new();
 
-    let gears = board
-        .symbols
-        .iter()
-        .filter_map(|(_, (c, seen))| (*c == '*' && seen.len() == 2).then_some(seen.clone()))
-        .collect::<Vec<_>>();
+        for span_id in seen_spans.iter() {
+            span_numbers.push(board.span_numbers[span_id]);
+        }
 
-    let mut spams_sum = 0;
-    for spans in gears.iter() {
-        let span_product = spans
-            .iter()
-            .map(|span_id| board.span_numbers[span_id])
-            .product::<u32>();
-        spams_sum += span_product;
+        Ok(span_numbers.iter().sum::<u32>())
     }
 
-    Ok(spams_sum.to_string())
-}
+    fn part2(board: &Self::Input<'_>) -> Result<Self::Part2> {
+        let gears = board
+            .symbols
+            .iter()
+            .filter_map(|(_, (c, seen))| (*c == '*' && seen.len() == 2).then_some(seen.clone()))
+            .collect::<Vec<_>>();
+
+        let mut spams_sum = 0;
+        for spans in gears.iter() {
+            let span_product = spans
+                .iter()
+                .map(|span_id| board.span_numbers[span_id])
+                .product::<u32>();
+            spams_sum += span_product;
+        }
 
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
+        Ok(spams_sum)
+    }
 }
 
 #[cfg(test)]
@@ -118,19 +107,19 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "4361";
-        let actual = part1(file_data)?;
+        let file_data = include_str!("../../../data/2023/day03/example_input");
+        let expected = 4361;
+        let actual = Day03::part1(&Day03::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
+        let file_data = include_str!("../../../data/2023/day03/example_input");
         let expected = 467835;
-        let actual = part2(file_data)?;
-        assert_eq!(actual, expected.to_string());
+        let actual = Day03::part2(&Day03::parse(file_data)?)?;
+        assert_eq!(actual, expected);
         Ok(())
     }
 }
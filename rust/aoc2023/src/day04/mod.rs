@@ -0,0 +1,133 @@
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, space1, u32 as nom_u32},
+    multi::separated_list1,
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Game {
+    card_number: u32,
+    card_numbers: BTreeSet<u32>,
+    winning_numbers: BTreeSet<u32>,
+}
+
+impl Game {
+    fn new(card_number: u32, card_numbers: BTreeSet<u32>, winning_numbers: BTreeSet<u32>) -> Self {
+        Game {
+            card_number,
+            card_numbers,
+            winning_numbers,
+        }
+    }
+}
+
+fn line_to_game(input: &str) -> IResult<&str, Game> {
+    let (input, (card_number, (winning_numbers, card_numbers))) = tuple((
+        delimited(
+            tuple((tag("Card"), space1)),
+            nom_u32,
+            tuple((tag(":"), space1)),
+        ),
+        separated_pair(
+            separated_list1(space1, nom_u32),
+            tuple((space1, tag("|"), space1)),
+            separated_list1(space1, nom_u32),
+        ),
+    ))(input)?;
+
+    Ok((
+        input,
+        Game::new(
+            card_number,
+            card_numbers.iter().copied().collect::<BTreeSet<_>>(),
+            winning_numbers.iter().copied().collect::<BTreeSet<_>>(),
+        ),
+    ))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, games) =
+            separated_list1(line_ending, line_to_game)(input).map_err(|e| e.to_owned())?;
+        for (i, game) in games.iter().enumerate() {
+            if game.card_number as usize != i + 1 {
+                bail!("Card {} is out of order", game.card_number);
+            }
+        }
+        Ok(games)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total = 0;
+        for game in games {
+            let common = game
+                .card_numbers
+                .intersection(&game.winning_numbers)
+                .count() as u32;
+
+            total += if common > 0 { 2u32.pow(common - 1) } else { 0 };
+        }
+
+        Ok(total)
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut total = 0;
+        let mut extra_cards = vec![0; games.len()];
+
+        for game in games {
+            let common_count = game
+                .card_numbers
+                .intersection(&game.winning_numbers)
+                .count();
+
+            let this_card_number = game.card_number as usize - 1;
+            let num_this_card = 1 + extra_cards[this_card_number];
+
+            total += num_this_card;
+            for i in 0..common_count {
+                let extra_card_number = this_card_number + i + 1;
+                *extra_cards
+                    .get_mut(extra_card_number)
+                    .context("Won a card past the end of the table")? += num_this_card;
+            }
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day04/example_input");
+        let expected = 13;
+        let actual = Day04::part1(&Day04::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day04/example_input");
+        let expected = 30;
+        let actual = Day04::part2(&Day04::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
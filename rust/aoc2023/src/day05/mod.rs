@@ -1,24 +1,19 @@
 use std::{collections::BTreeMap, ops::Range};
 
-use anyhow::Result;
-use strum_macros::EnumString;
-use thiserror::Error;
+use anyhow::{Context, Result};
+use aoc_common::Solution;
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{line_ending, space1, u64 as nom_u64},
+    combinator::value,
     multi::separated_list1,
     sequence::{delimited, separated_pair, terminated, tuple},
     IResult,
 };
 
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString)]
-#[strum(serialize_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Resource {
     Seed,
     Soil,
@@ -150,17 +145,16 @@ impl Map {
 }
 
 fn parse_resource(input: &str) -> IResult<&str, Resource> {
-    let (input, resource_name) = alt((
-        tag("seed"),
-        tag("soil"),
-        tag("fertilizer"),
-        tag("water"),
-        tag("light"),
-        tag("temperature"),
-        tag("humidity"),
-        tag("location"),
-    ))(input)?;
-    Ok((input, resource_name.parse().unwrap()))
+    alt((
+        value(Resource::Seed, tag("seed")),
+        value(Resource::Soil, tag("soil")),
+        value(Resource::Fertilizer, tag("fertilizer")),
+        value(Resource::Water, tag("water")),
+        value(Resource::Light, tag("light")),
+        value(Resource::Temperature, tag("temperature")),
+        value(Resource::Humidity, tag("humidity")),
+        value(Resource::Location, tag("location")),
+    ))(input)
 }
 
 fn parse_input(input: &str) -> IResult<&str, Data> {
@@ -201,108 +195,86 @@ fn parse_input(input: &str) -> IResult<&str, Data> {
     Ok((input, Data { seeds, maps }))
 }
 
-fn part1(input: &str) -> Result<String> {
-    let (_, data) = parse_input(input).unwrap();
-    let seed_to_soil = data.maps.get(&(Resource::Seed, Resource::Soil)).unwrap();
-    let soil_to_fertilizer = data
-        .maps
-        .get(&(Resource::Soil, Resource::Fertilizer))
-        .unwrap();
-    let fertilizer_to_water = data
-        .maps
-        .get(&(Resource::Fertilizer, Resource::Water))
-        .unwrap();
-    let water_to_light = data.maps.get(&(Resource::Water, Resource::Light)).unwrap();
-    let light_to_temperature = data
-        .maps
-        .get(&(Resource::Light, Resource::Temperature))
-        .unwrap();
-    let temperature_to_humidity = data
-        .maps
-        .get(&(Resource::Temperature, Resource::Humidity))
-        .unwrap();
-    let humidity_to_location = data
-        .maps
-        .get(&(Resource::Humidity, Resource::Location))
-        .unwrap();
-
-    let result = data
-        .seeds
-        .iter()
-        .map(|&s| {
-            let soil = seed_to_soil.convert(s);
-            let fertilizer = soil_to_fertilizer.convert(soil);
-            let water = fertilizer_to_water.convert(fertilizer);
-            let light = water_to_light.convert(water);
-            let temperature = light_to_temperature.convert(light);
-            let humidity = temperature_to_humidity.convert(temperature);
-            humidity_to_location.convert(humidity)
-        })
-        .min()
-        .unwrap();
-    Ok(result.to_string())
+/// The seeds, and the maps to take them through in turn to get their
+/// locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    chain: Vec<Map>,
 }
 
-fn part2(input: &str) -> Result<String> {
-    let (_, data) = parse_input(input).unwrap();
-    let seed_to_soil = data.maps.get(&(Resource::Seed, Resource::Soil)).unwrap();
-    let soil_to_fertilizer = data
-        .maps
-        .get(&(Resource::Soil, Resource::Fertilizer))
-        .unwrap();
-    let fertilizer_to_water = data
-        .maps
-        .get(&(Resource::Fertilizer, Resource::Water))
-        .unwrap();
-    let water_to_light = data.maps.get(&(Resource::Water, Resource::Light)).unwrap();
-    let light_to_temperature = data
-        .maps
-        .get(&(Resource::Light, Resource::Temperature))
-        .unwrap();
-    let temperature_to_humidity = data
-        .maps
-        .get(&(Resource::Temperature, Resource::Humidity))
-        .unwrap();
-    let humidity_to_location = data
-        .maps
-        .get(&(Resource::Humidity, Resource::Location))
-        .unwrap();
-
-    let ranges: Vec<MapRange> = data
-        .seeds
-        .chunks(2)
-        .map(|chunk| {
-            let start = chunk[0];
-            let length = chunk[1];
-            MapRange(start..(start + length))
+impl TryFrom<Data> for Almanac {
+    type Error = anyhow::Error;
+
+    fn try_from(data: Data) -> Result<Self> {
+        let mut chain = Vec::new();
+        let mut resource = Resource::Seed;
+        while resource != Resource::Location {
+            let (&(_, to), map) = data
+                .maps
+                .range((resource, Resource::Seed)..=(resource, Resource::Location))
+                .next()
+                .with_context(|| format!("No map from {resource:?}"))?;
+            if chain.len() >= data.maps.len() {
+                anyhow::bail!("The maps go round in a loop");
+            }
+            chain.push(map.clone());
+            resource = to;
+        }
+        Ok(Almanac {
+            seeds: data.seeds,
+            chain,
         })
-        .collect();
-
-    let soil = seed_to_soil.convert_ranges(&ranges);
-    let fertilizer = soil_to_fertilizer.convert_ranges(&soil);
-    let water = fertilizer_to_water.convert_ranges(&fertilizer);
-    let light = water_to_light.convert_ranges(&water);
-    let temperature = light_to_temperature.convert_ranges(&light);
-    let humidity = temperature_to_humidity.convert_ranges(&temperature);
-    let location = humidity_to_location.convert_ranges(&humidity);
-
-    let lowest_location = location.iter().map(|r| r.0.start).min().unwrap();
-    Ok(lowest_location.to_string())
+    }
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, data) = parse_input(input).map_err(|e| e.to_owned())?;
+        Almanac::try_from(data)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Result<Self::Part1> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&s| {
+                almanac
+                    .chain
+                    .iter()
+                    .fold(s, |value, map| map.convert(value))
+            })
+            .min()
+            .context("No seeds")
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Result<Self::Part2> {
+        if almanac.seeds.len() % 2 != 0 {
+            anyhow::bail!("The seeds don't pair up into ranges");
+        }
+        let ranges: Vec<MapRange> = almanac
+            .seeds
+            .chunks(2)
+            .map(|chunk| {
+                let start = chunk[0];
+                let length = chunk[1];
+                MapRange(start..(start + length))
+            })
+            .collect();
+
+        let location = almanac
+            .chain
+            .iter()
+            .fold(ranges, |ranges, map| map.convert_ranges(&ranges));
+
+        location.iter().map(|r| r.0.start).min().context("No seeds")
+    }
 }
 
 #[cfg(test)]
@@ -311,18 +283,18 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "35";
-        let actual = part1(file_data)?;
+        let file_data = include_str!("../../../data/2023/day05/example_input");
+        let expected = 35;
+        let actual = Day05::part1(&Day05::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "46";
-        let actual = part2(file_data)?;
+        let file_data = include_str!("../../../data/2023/day05/example_input");
+        let expected = 46;
+        let actual = Day05::part2(&Day05::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use aoc_common::Solution;
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    character::complete::{line_ending, u32 as nom_u32},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+fn parse_races(input: &str) -> IResult<&str, Vec<(u32, u32)>> {
+    let (input, times) = preceded(
+        tuple((tag("Time:"), space1)),
+        separated_list1(space1, nom_u32),
+    )(input)?;
+    let (input, distances) = preceded(
+        tuple((line_ending, tag("Distance:"), space1)),
+        separated_list1(space1, nom_u32),
+    )(input)?;
+    Ok((input, times.into_iter().zip(distances).collect()))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<(u32, u32)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, races) = parse_races(input).map_err(|e| e.to_owned())?;
+        Ok(races)
+    }
+
+    fn part1(races: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut product = 1;
+        for &(time, record_distance) in races {
+            let mut wins = 0;
+            for time_pressed in 1..=time {
+                let speed = time_pressed;
+                let moving = time - time_pressed;
+                let distance_moved = speed * moving;
+                if distance_moved > record_distance {
+                    wins += 1;
+                }
+            }
+            product *= wins;
+        }
+        Ok(product)
+    }
+
+    fn part2(races: &Self::Input<'_>) -> Result<Self::Part2> {
+        let time: String = races.iter().map(|(t, _)| t.to_string()).collect();
+        let time: u64 = time.parse()?;
+        let distance: String = races.iter().map(|(_, d)| d.to_string()).collect();
+        let record_distance: u64 = distance.parse()?;
+
+        let mut wins = 0;
+        for time_pressed in 1..=time {
+            let speed = time_pressed;
+            let moving = time - time_pressed;
+            let distance_moved = speed * moving;
+            if distance_moved > record_distance {
+                wins += 1;
+            }
+        }
+        Ok(wins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day06/example_input");
+        let expected = 288;
+        let actual = Day06::part1(&Day06::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day06/example_input");
+        let expected = 71503;
+        let actual = Day06::part2(&Day06::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
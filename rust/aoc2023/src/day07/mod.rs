@@ -1,17 +1,13 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
+use aoc_common::Solution;
 use nom::{
     character::complete::{line_ending, one_of, space1, u32 as nom_u32},
     multi::{many1, separated_list1},
     sequence::separated_pair,
     IResult,
 };
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
@@ -43,7 +39,7 @@ enum CardValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Hand {
+pub struct Hand {
     cards: Vec<CardValue>,
     bid: u32,
 }
@@ -150,56 +146,53 @@ impl Ord for HandWithScore {
     }
 }
 
-fn part1(input: &'static str) -> Result<String> {
-    let (_, hand) = parse_hand(input).unwrap();
-    let mut hands_with_scores: Vec<_> = hand
-        .iter()
-        .map(|hand| HandWithScore(hand.clone(), hand.score()))
-        .collect();
-    hands_with_scores.sort();
-    let total: usize = hands_with_scores
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.0.bid as usize)
-        .sum();
-    Ok(total.to_string())
-}
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, hands) = parse_hand(input).map_err(|e| e.to_owned())?;
+        Ok(hands)
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut hands_with_scores: Vec<_> = hands
+            .iter()
+            .map(|hand| HandWithScore(hand.clone(), hand.score()))
+            .collect();
+        hands_with_scores.sort();
+        let total: usize = hands_with_scores
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.0.bid as usize)
+            .sum();
+        Ok(total)
+    }
 
-fn part2(input: &str) -> Result<String> {
-    let (_, mut hands) = parse_hand(input).unwrap();
-    for hand in hands.iter_mut() {
-        for card in hand.cards.iter_mut() {
-            if *card == CardValue::_J {
-                *card = CardValue::_Joker;
+    fn part2(hands: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut hands = hands.clone();
+        for hand in hands.iter_mut() {
+            for card in hand.cards.iter_mut() {
+                if *card == CardValue::_J {
+                    *card = CardValue::_Joker;
+                }
             }
         }
+        let mut hands_with_scores: Vec<_> = hands
+            .iter()
+            .map(|hand| HandWithScore(hand.clone(), hand.score()))
+            .collect();
+        hands_with_scores.sort();
+        let total: usize = hands_with_scores
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.0.bid as usize)
+            .sum();
+        Ok(total)
     }
-    let mut hands_with_scores: Vec<_> = hands
-        .iter()
-        .map(|hand| HandWithScore(hand.clone(), hand.score()))
-        .collect();
-    hands_with_scores.sort();
-    let total: usize = hands_with_scores
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.0.bid as usize)
-        .sum();
-    Ok(total.to_string())
-}
-
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
 }
 
 #[cfg(test)]
@@ -208,18 +201,18 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "6440";
-        let actual = part1(file_data)?;
+        let file_data = include_str!("../../../data/2023/day07/example_input");
+        let expected = 6440;
+        let actual = Day07::part1(&Day07::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "5905";
-        let actual = part2(file_data)?;
+        let file_data = include_str!("../../../data/2023/day07/example_input");
+        let expected = 5905;
+        let actual = Day07::part2(&Day07::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, line_ending, one_of},
+    multi::{many1, separated_list1},
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Dir {
+    L,
+    R,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node<'a> {
+    name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+pub struct Game<'a> {
+    pattern: Vec<Dir>,
+    nodes: HashMap<&'a str, Node<'a>>,
+}
+
+fn parse_node(input: &str) -> IResult<&str, Node<'_>> {
+    // AAA = (BBB, CCC)
+    let (input, (name, (left, right))) = separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    )(input)?;
+    Ok((input, Node { name, left, right }))
+}
+
+fn parse_data(input: &str) -> IResult<&str, Game<'_>> {
+    let (input, pattern) = many1(one_of("LR"))(input)?;
+    let pattern = pattern
+        .iter()
+        .map(|c| match c {
+            'L' => Dir::L,
+            'R' => Dir::R,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    let (input, _) = tuple((line_ending, line_ending))(input)?;
+    let (input, nodes) = separated_list1(line_ending, parse_node)(input)?;
+
+    Ok((
+        input,
+        Game {
+            pattern,
+            nodes: nodes.into_iter().map(|n| (n.name, n)).collect(),
+        },
+    ))
+}
+
+#[derive(Debug, Clone)]
+enum SearchResult {
+    None,
+    FoundOne(usize),
+    FoundTwo(usize, usize),
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Game<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, data) = parse_data(input).map_err(|e| e.to_owned())?;
+        for node in data.nodes.values() {
+            for next in [node.left, node.right] {
+                if !data.nodes.contains_key(next) {
+                    bail!("{} leads to unknown node {next}", node.name);
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    fn part1(data: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut pos = "AAA";
+        let mut steps = 0;
+        for dir in data.pattern.iter().cycle() {
+            steps += 1;
+            let node = data
+                .nodes
+                .get(pos)
+                .with_context(|| format!("Unknown node {pos}"))?;
+            pos = match dir {
+                Dir::L => node.left,
+                Dir::R => node.right,
+            };
+            if pos == "ZZZ" {
+                break;
+            }
+        }
+        Ok(steps)
+    }
+
+    fn part2(data: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut positions: Vec<_> = data
+            .nodes
+            .keys()
+            .copied()
+            .filter(|n| n.ends_with('A'))
+            .collect();
+        let mut steps = 0;
+
+        let mut positions_found_at_steps = vec![SearchResult::None; positions.len()];
+        let mut steps_complete = HashSet::new();
+
+        for dir in data.pattern.iter().cycle() {
+            steps += 1;
+            for (i, pos) in positions.iter_mut().enumerate() {
+                let node = &data.nodes[pos];
+                *pos = match dir {
+                    Dir::L => node.left,
+                    Dir::R => node.right,
+                };
+                if pos.ends_with('Z') {
+                    positions_found_at_steps[i] = match &positions_found_at_steps[i] {
+                        SearchResult::None => SearchResult::FoundOne(steps),
+                        SearchResult::FoundOne(original_step) => {
+                            steps_complete.insert(steps);
+                            SearchResult::FoundTwo(*original_step, steps)
+                        }
+                        z => z.clone(),
+                    };
+                }
+            }
+            if steps_complete.len() == positions.len() {
+                break;
+            }
+        }
+
+        let lcm = positions_found_at_steps.iter().fold(1, |acc, n| {
+            if let SearchResult::FoundTwo(a, b) = n {
+                num::integer::lcm(acc, *b - *a)
+            } else {
+                acc
+            }
+        });
+
+        Ok(lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day08/example_input");
+        let expected = 2;
+        let actual = Day08::part1(&Day08::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+
+        let file_data = include_str!("../../../data/2023/day08/example_input2");
+        let expected = 6;
+        let actual = Day08::part1(&Day08::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day08/example_input3");
+        let expected = 6;
+        let actual = Day08::part2(&Day08::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::{bail, Result};
+use aoc_common::Solution;
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+    input
+        .lines()
+        .map(|line| {
+            let seq = line
+                .split_ascii_whitespace()
+                .map(|s| s.parse::<i32>())
+                .collect::<Result<Vec<i32>, _>>()?;
+            if seq.is_empty() {
+                bail!("Empty sequence");
+            }
+            Ok(seq)
+        })
+        .collect()
+}
+
+fn process(sequences: &[Vec<i32>], part2: bool) -> i32 {
+    let mut total = 0;
+    for seq in sequences {
+        let mut seq = seq.clone();
+        if part2 {
+            seq.reverse();
+        }
+
+        let mut stack = Vec::new();
+        let mut p = seq.clone();
+        while !p.iter().all(|n| *n == 0) {
+            let mut new_p = Vec::new();
+            for i in 1..p.len() {
+                let a = p[i - 1];
+                let b = p[i];
+                new_p.push(b - a);
+            }
+            stack.push(p);
+            p = new_p;
+        }
+        stack.push(vec![0; stack.last().map_or(p.len(), Vec::len)]);
+
+        for n in (0..(stack.len() - 1)).rev() {
+            let this_line_length = stack[n].len() - 1;
+            let end_of_this_line = stack[n][this_line_length];
+            let end_of_last_line = stack[n + 1][this_line_length];
+            stack[n].push(end_of_this_line + end_of_last_line);
+        }
+        total += stack[0].last().copied().unwrap_or_default();
+    }
+    total
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(sequences: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(process(sequences, false))
+    }
+
+    fn part2(sequences: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(process(sequences, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day09/example_input");
+        let expected_each = [18, 28, 68];
+        for (i, line) in file_data.lines().enumerate() {
+            let expected = expected_each[i];
+            let actual = Day09::part1(&Day09::parse(line)?)?;
+            assert_eq!(actual, expected);
+        }
+        let expected = 114;
+        let actual = Day09::part1(&Day09::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day09/example_input");
+        let expected_each = [-3, 0, 5];
+        for (i, line) in file_data.lines().enumerate() {
+            let expected = expected_each[i];
+            let actual = Day09::part2(&Day09::parse(line)?)?;
+            assert_eq!(actual, expected);
+        }
+        let expected = 2;
+        let actual = Day09::part2(&Day09::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Grid, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapValue {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Starting,
+}
+
+type GameMap = Grid<Option<MapValue>>;
+
+fn parse_input(input: &str) -> Result<GameMap> {
+    Grid::parse(input, |c| {
+        Ok(match c {
+            '|' => Some(MapValue::Vertical),
+            '-' => Some(MapValue::Horizontal),
+            'L' => Some(MapValue::NorthEast),
+            'J' => Some(MapValue::NorthWest),
+            '7' => Some(MapValue::SouthWest),
+            'F' => Some(MapValue::SouthEast),
+            'S' => Some(MapValue::Starting),
+            '.' => None,
+            _ => bail!("unexpected char: {}", c),
+        })
+    })
+}
+
+fn start_pos(game_map: &GameMap) -> Result<(i64, i64)> {
+    let (x, y) = game_map
+        .find(|v| *v == Some(MapValue::Starting))
+        .context("No starting position")?;
+    Ok((x as i64, y as i64))
+}
+
+/// A loop from the start: its length in steps and the positions along it.
+type Path = (i32, Vec<(i64, i64)>);
+
+fn find_paths(game_map: &GameMap) -> Result<Vec<Path>> {
+    let start_pos = start_pos(game_map)?;
+
+    let start_pos_neighbors = HashSet::from([
+        (start_pos.0 - 1, start_pos.1),
+        (start_pos.0 + 1, start_pos.1),
+        (start_pos.0, start_pos.1 - 1),
+        (start_pos.0, start_pos.1 + 1),
+    ]);
+
+    let mut paths = vec![];
+    for &path_start_pos in &start_pos_neighbors {
+        let Some(Some(_)) = game_map.get(path_start_pos) else {
+            // This neighbour isn't a valid path start
+            continue;
+        };
+
+        let mut steps = 0;
+        let mut path = vec![start_pos];
+
+        let mut pos = path_start_pos;
+
+        loop {
+            let Some(Some(m)) = game_map.get(pos) else {
+                bail!("Pos {:?} not in map!", pos);
+            };
+
+            path.push(pos);
+            steps += 1;
+
+            if pos == start_pos {
+                break;
+            }
+
+            let next_pos_options = match m {
+                MapValue::Vertical => [(pos.0, pos.1 - 1), (pos.0, pos.1 + 1)],
+                MapValue::Horizontal => [(pos.0 - 1, pos.1), (pos.0 + 1, pos.1)],
+                MapValue::NorthEast => [(pos.0 + 1, pos.1), (pos.0, pos.1 - 1)],
+                MapValue::NorthWest => [(pos.0 - 1, pos.1), (pos.0, pos.1 - 1)],
+                MapValue::SouthWest => [(pos.0 - 1, pos.1), (pos.0, pos.1 + 1)],
+                MapValue::SouthEast => [(pos.0 + 1, pos.1), (pos.0, pos.1 + 1)],
+                MapValue::Starting => break,
+            };
+
+            let prev_pos = path[path.len() - 2];
+            let next_pos = next_pos_options
+                .into_iter()
+                .find(|&new_pos| prev_pos != new_pos)
+                .with_context(|| format!("Pipe at {pos:?} leads back on itself"))?;
+
+            pos = next_pos;
+        }
+        paths.push((steps, path));
+    }
+    Ok(paths)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum DoubleMapEntry {
+    Loop,
+    Seen,
+    Unseen,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = GameMap;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(game_map: &Self::Input<'_>) -> Result<Self::Part1> {
+        let paths = find_paths(game_map)?;
+
+        paths
+            .iter()
+            .map(|(steps, _)| steps / 2)
+            .max()
+            .context("No loop from the start")
+    }
+
+    fn part2(game_map: &Self::Input<'_>) -> Result<Self::Part2> {
+        let paths = find_paths(game_map)?;
+        let path = paths
+            .into_iter()
+            .max_by_key(|(steps, _)| *steps)
+            .context("No loop from the start")?
+            .1;
+
+        let start_pos = start_pos(game_map)?;
+
+        let path_with_start = std::iter::once(start_pos).chain(path.iter().copied());
+
+        // Each tile becomes a 2x2 block, so the gaps between pipes become
+        // tiles that can be squeezed through.  Map tile (x, y) sits at
+        // (2x + 1, 2y + 1), leaving a border of outside all the way round.
+        let mut double_map = Grid::new(
+            2 * game_map.width() + 1,
+            2 * game_map.height() + 1,
+            DoubleMapEntry::Unseen,
+        );
+        for (i, p) in path_with_start.tuple_windows::<((i64, i64), (i64, i64))>() {
+            let y_range = (2 * i.1.min(p.1) + 1)..=(2 * i.1.max(p.1) + 1);
+            let x_range = (2 * i.0.min(p.0) + 1)..=(2 * i.0.max(p.0) + 1);
+
+            for y in y_range {
+                for x in x_range.clone() {
+                    double_map[(x as usize, y as usize)] = DoubleMapEntry::Loop;
+                }
+            }
+        }
+
+        let mut section_sizes = vec![];
+        for pos in double_map.positions() {
+            if double_map[pos] != DoubleMapEntry::Unseen {
+                continue;
+            }
+            double_map[pos] = DoubleMapEntry::Seen;
+            let mut section_size = 0;
+            let mut visit_from_pos = vec![pos];
+            while let Some(pos) = visit_from_pos.pop() {
+                if pos.0 % 2 == 1 && pos.1 % 2 == 1 {
+                    section_size += 1;
+                }
+                for neighbor in double_map.neighbours8(pos).collect_vec() {
+                    if double_map[neighbor] == DoubleMapEntry::Unseen {
+                        visit_from_pos.push(neighbor);
+                        double_map[neighbor] = DoubleMapEntry::Seen;
+                    }
+                }
+            }
+            section_sizes.push(section_size);
+        }
+
+        section_sizes.sort();
+        section_sizes.pop();
+        let total_size: usize = section_sizes.iter().sum();
+
+        Ok(total_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day10/example_input");
+        let expected = 4;
+        let actual = Day10::part1(&Day10::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+
+        let file_data = include_str!("../../../data/2023/day10/example_input2");
+        let expected = 8;
+        let actual = Day10::part1(&Day10::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day10/example_input3");
+        let expected = 4;
+        let actual = Day10::part2(&Day10::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+
+        let file_data = include_str!("../../../data/2023/day10/example_input4");
+        let expected = 8;
+        let actual = Day10::part2(&Day10::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
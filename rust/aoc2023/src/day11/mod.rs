@@ -0,0 +1,103 @@
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Result};
+use aoc_common::{Grid, Solution};
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn process(image: &Grid<bool>, expand_by: i64) -> i64 {
+    let galaxies = image
+        .iter()
+        .filter(|(_, &galaxy)| galaxy)
+        .map(|((x, y), _)| (x as i64, y as i64))
+        .collect::<Vec<_>>();
+
+    let column_with_content = image
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut column)| column.any(|&galaxy| galaxy).then_some(x as i64))
+        .collect::<BTreeSet<_>>();
+    let row_with_content = image
+        .rows()
+        .enumerate()
+        .filter_map(|(y, row)| row.contains(&true).then_some(y as i64))
+        .collect::<BTreeSet<_>>();
+
+    let mut galaxies = galaxies.clone();
+
+    for (x, y) in galaxies.iter_mut() {
+        for tx in 0..*x {
+            if !column_with_content.contains(&tx) {
+                *x += expand_by - 1;
+            }
+        }
+        for ty in 0..*y {
+            if !row_with_content.contains(&ty) {
+                *y += expand_by - 1;
+            }
+        }
+    }
+
+    let mut total_distance = 0;
+    for (a, galaxy_a) in galaxies.iter().enumerate() {
+        for (b, galaxy_b) in galaxies.iter().enumerate() {
+            if a >= b {
+                continue;
+            }
+            total_distance += distance(*galaxy_a, *galaxy_b);
+        }
+    }
+
+    total_distance
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Grid<bool>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => bail!("Unexpected {ch:?} in image"),
+        })
+    }
+
+    fn part1(image: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(process(image, 2))
+    }
+
+    fn part2(image: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(process(image, 1000000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day11/example_input");
+        let expected = 374;
+        let actual = Day11::part1(&Day11::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day11/example_input");
+        let image = Day11::parse(file_data)?;
+        for (expand_by, expected) in [(10, 1030), (100, 8410)] {
+            let actual = process(&image, expand_by);
+            assert_eq!(actual, expected);
+        }
+        Ok(())
+    }
+}
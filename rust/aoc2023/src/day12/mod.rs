@@ -1,15 +1,11 @@
 use std::collections::BTreeMap;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
 use itertools::Itertools;
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     input: Vec<char>,
     contiguous_damaged: Vec<u32>,
 }
@@ -21,6 +17,9 @@ fn parse_line(input: &str) -> Result<Game> {
         .split(',')
         .map(|n| n.parse::<u32>())
         .collect::<Result<Vec<_>, _>>()?;
+    if let Some(c) = input.chars().find(|c| !matches!(c, '#' | '.' | '?')) {
+        bail!("invalid character {c} in input {input}");
+    }
     Ok(Game {
         input: input.chars().collect(),
         contiguous_damaged: damage,
@@ -192,70 +191,59 @@ fn solve(input: &[char], target: &[u32], state: State, memo: &mut BTreeMap<State
     score
 }
 
-fn part1(input: &str) -> Result<String> {
-    let mut total = 0;
-    for line in input.lines() {
-        let game = parse_line(line)?;
-        // println!("{:?}", game);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u64;
 
-        let s = solve_bf(
-            &game.input.iter().collect::<String>(),
-            &game.contiguous_damaged,
-        );
-        // let mut memo = BTreeMap::new();
-        // let s = solve_dp(
-        //     &game.input,
-        //     &game.contiguous_damaged,
-        //     State::default(),
-        //     &mut memo,
-        // );
-        total += s;
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.lines().map(parse_line).collect()
     }
-    Ok(total.to_string())
-}
 
-fn part2(input: &str) -> Result<String> {
-    let mut total = 0;
-    let mut total_memo_size = 0;
-    for line in input.lines() {
-        let game = parse_line(line)?;
+    fn part1(games: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total = 0;
+        for game in games {
+            // println!("{:?}", game);
+
+            let s = solve_bf(
+                &game.input.iter().collect::<String>(),
+                &game.contiguous_damaged,
+            );
+            // let mut memo = BTreeMap::new();
+            // let s = solve_dp(
+            //     &game.input,
+            //     &game.contiguous_damaged,
+            //     State::default(),
+            //     &mut memo,
+            // );
+            total += s;
+        }
+        Ok(total)
+    }
 
-        let input = std::iter::repeat(game.input.iter().collect::<String>())
-            .take(5)
-            .join("?");
-        let contiguous_damaged = std::iter::repeat(game.contiguous_damaged.clone())
-            .take(5)
-            .flatten()
-            .collect::<Vec<_>>();
+    fn part2(games: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut total = 0;
+        for game in games {
+            let input = std::iter::repeat_n(game.input.iter().collect::<String>(), 5)
+                .join("?");
+            let contiguous_damaged = std::iter::repeat_n(game.contiguous_damaged.clone(), 5)
+                .flatten()
+                .collect::<Vec<_>>();
 
-        let mut memo = BTreeMap::new();
-        let s = solve(
-            &input.chars().collect::<Vec<_>>(),
-            &contiguous_damaged,
-            State::default(),
-            &mut memo,
-        );
+            let mut memo = BTreeMap::new();
+            let s = solve(
+                &input.chars().collect::<Vec<_>>(),
+                &contiguous_damaged,
+                State::default(),
+                &mut memo,
+            );
 
-        total_memo_size += memo.len();
-        total += s;
+            total += s;
+        }
+        Ok(total)
     }
-    println!("total memo size: {}", total_memo_size);
-    Ok(total.to_string())
-}
-
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
 }
 
 #[cfg(test)]
@@ -265,14 +253,14 @@ mod tests {
     #[test]
     fn test_part1() -> Result<()> {
         for (input, expected) in [
-            ("???.### 1,1,3", "1"),
-            (".??..??...?##. 1,1,3", "4"),
-            ("?#?#?#?#?#?#?#? 1,3,1,6", "1"),
-            ("????.#...#... 4,1,1", "1"),
-            ("????.######..#####. 1,6,5", "4"),
-            ("?###???????? 3,2,1", "10"),
+            ("???.### 1,1,3", 1),
+            (".??..??...?##. 1,1,3", 4),
+            ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+            ("????.#...#... 4,1,1", 1),
+            ("????.######..#####. 1,6,5", 4),
+            ("?###???????? 3,2,1", 10),
         ] {
-            let actual = part1(input)?;
+            let actual = Day12::part1(&Day12::parse(input)?)?;
 
             assert_eq!(actual, expected);
         }
@@ -282,14 +270,14 @@ mod tests {
     #[test]
     fn test_part2() -> Result<()> {
         for (input, expected) in [
-            ("???.### 1,1,3", "1"),
-            (".??..??...?##. 1,1,3", "16384"),
-            ("?#?#?#?#?#?#?#? 1,3,1,6", "1"),
-            ("????.#...#... 4,1,1", "16"),
-            ("????.######..#####. 1,6,5", "2500"),
-            ("?###???????? 3,2,1", "506250"),
+            ("???.### 1,1,3", 1),
+            (".??..??...?##. 1,1,3", 16384),
+            ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+            ("????.#...#... 4,1,1", 16),
+            ("????.######..#####. 1,6,5", 2500),
+            ("?###???????? 3,2,1", 506250),
         ] {
-            let actual = part2(input)?;
+            let actual = Day12::part2(&Day12::parse(input)?)?;
 
             assert_eq!(actual, expected);
         }
@@ -307,7 +295,7 @@ mod tests {
             (".###.##....#", vec![3, 2, 1]),
         ] {
             let actual = score_input(input, &target);
-            assert_eq!(actual, true);
+            assert!(actual);
         }
         Ok(())
     }
@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{Grid, Solution};
+use itertools::Itertools;
+
+fn parse(input: &str) -> Result<Vec<Grid<bool>>> {
+    // Ug.  Why no cross-platform way to split on double newlines?
+    input
+        .replace("\r\n", "\n")
+        .split("\n\n")
+        .map(|lines| Grid::parse(lines, |ch| Ok(ch == '#')))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mirror {
+    X(usize),
+    Y(usize),
+}
+
+impl Mirror {
+    fn summary(self) -> usize {
+        match self {
+            Mirror::X(n) => n,
+            Mirror::Y(n) => 100 * n,
+        }
+    }
+}
+
+/// For each vertical mirror line (after `x` columns, so `Mirror::X(x)`), the
+/// number of cells that don't match their reflection.
+fn vertical_mirror_imperfections(grid: &Grid<bool>) -> Vec<(usize, usize)> {
+    let columns = grid.columns().map(|c| c.collect_vec()).collect_vec();
+    (1..grid.width())
+        .map(|x| {
+            let (left, right) = columns.split_at(x);
+            let imperfections = left
+                .iter()
+                .rev()
+                .zip(right)
+                .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+                .sum();
+            (x, imperfections)
+        })
+        .collect()
+}
+
+fn mirror_imperfections(grid: &Grid<bool>) -> Vec<(Mirror, usize)> {
+    let vertical = vertical_mirror_imperfections(grid)
+        .into_iter()
+        .map(|(x, n)| (Mirror::X(x), n));
+    // A horizontal mirror is a vertical one in the transposed pattern.
+    let horizontal = vertical_mirror_imperfections(&grid.transpose())
+        .into_iter()
+        .map(|(y, n)| (Mirror::Y(y), n));
+    vertical.chain(horizontal).collect()
+}
+
+/// The mirror with exactly `smudges` imperfections.
+fn find_mirror(grid: &Grid<bool>, smudges: usize) -> Result<Mirror> {
+    mirror_imperfections(grid)
+        .into_iter()
+        .filter_map(|(m, n)| (n == smudges).then_some(m))
+        .exactly_one()
+        .map_err(|e| anyhow!("Expected one mirror, found {}", e.count()))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Grid<bool>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        input
+            .iter()
+            .map(|grid| Ok(find_mirror(grid, 0)?.summary()))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        input
+            .iter()
+            .map(|grid| Ok(find_mirror(grid, 1)?.summary()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day13/example_input");
+        let expected = 405;
+        let actual = Day13::part1(&Day13::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_mirror() -> Result<()> {
+        let patterns = parse(include_str!("../../../data/2023/day13/example_input"))?;
+        let cases = [
+            (0, 0, Mirror::X(5)),
+            (1, 0, Mirror::Y(4)),
+            (0, 1, Mirror::Y(3)),
+            (1, 1, Mirror::Y(1)),
+        ];
+        for (pattern, smudges, expected) in cases {
+            let actual = find_mirror(&patterns[pattern], smudges)?;
+            assert_eq!(actual, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day13/example_input");
+        let expected = 400;
+        let actual = Day13::part2(&Day13::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
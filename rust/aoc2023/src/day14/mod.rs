@@ -0,0 +1,211 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{cycle::find_cycle, Direction, Grid, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Rock {
+    Empty,
+    Cube,
+    Rolling,
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Rock::Empty => '.',
+            Rock::Cube => '#',
+            Rock::Rolling => 'O',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Game {
+    grid: Grid<Rock>,
+}
+
+impl Game {
+    #[cfg(test)]
+    fn output(&self) -> String {
+        self.grid.to_string().trim_end().to_string()
+    }
+
+    /// The total load on the north support beams.
+    fn load(&self) -> i64 {
+        self.grid
+            .iter()
+            .filter(|(_, &rock)| rock == Rock::Rolling)
+            .map(|((_, y), _)| (self.grid.height() - y) as i64)
+            .sum()
+    }
+}
+
+fn parse(input: &str) -> Result<Game> {
+    let grid = Grid::parse(input, |c| {
+        Ok(match c {
+            '.' => Rock::Empty,
+            '#' => Rock::Cube,
+            'O' => Rock::Rolling,
+            _ => bail!("invalid character"),
+        })
+    })?;
+    Ok(Game { grid })
+}
+
+fn tilt(game: Game, dir: Direction) -> Game {
+    let mut grid = game.grid;
+
+    // Move the rocks nearest the edge being tilted towards first, so each one
+    // stops against those already moved.
+    let mut positions = grid.positions().collect::<Vec<_>>();
+    if matches!(dir, Direction::Down | Direction::Right) {
+        positions.reverse();
+    }
+
+    for pos in positions {
+        if grid[pos] != Rock::Rolling {
+            continue;
+        }
+        let mut new_pos = pos;
+        while let Some(next) = grid.offset(new_pos, dir.delta().into()) {
+            if grid[next] != Rock::Empty {
+                break;
+            }
+            new_pos = next;
+        }
+        grid[pos] = Rock::Empty;
+        grid[new_pos] = Rock::Rolling;
+    }
+    Game { grid }
+}
+
+/// One spin cycle: tilt north, then west, then south, then east.
+fn spin(mut game: Game) -> Game {
+    for d in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        game = tilt(game, d);
+    }
+    game
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Game;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(game: &Self::Input<'_>) -> Result<Self::Part1> {
+        let game = tilt(game.clone(), Direction::Up);
+
+        Ok(game.load())
+    }
+
+    fn part2(game: &Self::Input<'_>) -> Result<Self::Part2> {
+        let spins = std::iter::successors(Some(game.clone()), |game| Some(spin(game.clone())));
+        let (cycle, history) = find_cycle(spins).context("Spinning never repeats")?;
+
+        Ok(history[cycle.equivalent_step(1_000_000_000)].load())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day14/example_input");
+        let expected = 136;
+        let actual = Day14::part1(&Day14::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day14/example_input");
+        let expected = 64;
+        let actual = Day14::part2(&Day14::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_north() -> Result<()> {
+        let game = parse(include_str!("../../../data/2023/day14/example_input"))?;
+        let game = tilt(game, Direction::Up);
+
+        let expected = r#"OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#...."#;
+
+        assert_eq!(game.output(), expected);
+
+        // dbg!(game);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycles() -> Result<()> {
+        let mut game = parse(include_str!("../../../data/2023/day14/example_input"))?;
+
+        let expected = [
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+            ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O",
+            ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O",
+        ];
+
+        for expected in expected.iter() {
+            game = spin(game);
+            assert_eq!(&game.output(), expected);
+        }
+
+        // dbg!(game);
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use aoc_common::Solution;
+
+fn hash(piece: &str) -> Result<u64> {
+    let mut piece_total: u64 = 0;
+    for ch in piece.chars() {
+        let ascii_code: u8 = ch.try_into()?;
+        piece_total += ascii_code as u64;
+        piece_total *= 17;
+        piece_total %= 256;
+    }
+    Ok(piece_total)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BoxEntry<'a> {
+    label: &'a str,
+    value: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op<'a> {
+    AddToBox(BoxEntry<'a>),
+    Remove(&'a str),
+}
+
+impl<'a> TryFrom<&'a str> for Op<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(piece: &'a str) -> Result<Self, Self::Error> {
+        if let Some(piece) = piece.strip_suffix('-') {
+            Ok(Op::Remove(piece))
+        } else {
+            let Some((label, value)) = piece.split_once('=') else {
+                bail!("invalid input: {}", piece);
+            };
+            let value = value.parse::<u64>()?;
+            Ok(Op::AddToBox(BoxEntry { label, value }))
+        }
+    }
+}
+
+/// One step of the initialization sequence, as written and as an operation.
+#[derive(Debug, Clone, Copy)]
+pub struct Step<'a> {
+    text: &'a str,
+    op: Op<'a>,
+}
+
+fn parse(input: &str) -> Result<Vec<Step<'_>>> {
+    input
+        .trim()
+        .split(',')
+        .map(|text| {
+            if !text.is_ascii() {
+                bail!("invalid input: {}", text);
+            }
+            Ok(Step {
+                text,
+                op: Op::try_from(text)?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Step<'a>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(steps: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total = 0;
+
+        for step in steps {
+            let piece_total = hash(step.text)?;
+
+            total += piece_total;
+        }
+        Ok(total)
+    }
+
+    fn part2(steps: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut boxes: BTreeMap<u64, Vec<BoxEntry>> = BTreeMap::new();
+        for step in steps {
+            match step.op {
+                Op::AddToBox(box_entry) => {
+                    let hash = hash(box_entry.label)?;
+                    let b = boxes.entry(hash).or_default();
+                    let mut found = false;
+                    for existing_box_entry in b.iter_mut() {
+                        {
+                            if existing_box_entry.label == box_entry.label {
+                                existing_box_entry.value = box_entry.value;
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+                    if !found {
+                        b.push(box_entry);
+                    }
+                }
+                Op::Remove(label_to_remove) => {
+                    for (_, b) in boxes.iter_mut() {
+                        *b = b
+                            .iter()
+                            .filter(|box_entry| box_entry.label != label_to_remove)
+                            .copied()
+                            .collect::<Vec<_>>();
+                    }
+                }
+            }
+        }
+
+        let mut total = 0;
+        for (hash, b) in boxes.iter() {
+            for (i, box_entry) in b.iter().enumerate() {
+                total += (hash + 1) * (i as u64 + 1) * box_entry.value;
+            }
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day15/example_input");
+        let expected = 1320;
+        let actual = Day15::part1(&Day15::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day15/example_input");
+        let expected = 145;
+        let actual = Day15::part2(&Day15::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
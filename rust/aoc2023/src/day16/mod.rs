@@ -0,0 +1,161 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{grid::Pos, Direction, Grid, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub enum MirrorType {
+    MirrorPositive,
+    MirrorNegative,
+    SplitterUpDown,
+    SplitterLeftRight,
+}
+
+impl FromStr for MirrorType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "/" => Ok(MirrorType::MirrorPositive),
+            "\\" => Ok(MirrorType::MirrorNegative),
+            "|" => Ok(MirrorType::SplitterUpDown),
+            "-" => Ok(MirrorType::SplitterLeftRight),
+            _ => bail!("invalid mirror type: {}", s),
+        }
+    }
+}
+
+type Game = Grid<Option<MirrorType>>;
+
+fn apply_mirror(mirror: &MirrorType, dir: Direction) -> Vec<Direction> {
+    match mirror {
+        MirrorType::MirrorPositive => match dir {
+            Direction::Up => vec![Direction::Right],
+            Direction::Down => vec![Direction::Left],
+            Direction::Left => vec![Direction::Down],
+            Direction::Right => vec![Direction::Up],
+        },
+        MirrorType::MirrorNegative => match dir {
+            Direction::Up => vec![Direction::Left],
+            Direction::Down => vec![Direction::Right],
+            Direction::Left => vec![Direction::Up],
+            Direction::Right => vec![Direction::Down],
+        },
+        MirrorType::SplitterUpDown => match dir {
+            Direction::Up | Direction::Down => vec![dir],
+            Direction::Left | Direction::Right => vec![Direction::Up, Direction::Down],
+        },
+        MirrorType::SplitterLeftRight => match dir {
+            Direction::Up | Direction::Down => vec![Direction::Left, Direction::Right],
+            Direction::Left | Direction::Right => vec![dir],
+        },
+    }
+}
+
+fn find_energized(game: &Game, starting_position: (Pos, Direction)) -> BTreeSet<Pos> {
+    let mut seen = BTreeSet::new();
+    let mut energized = BTreeSet::new();
+    let mut to_do = vec![starting_position];
+
+    while let Some((pos, dir)) = to_do.pop() {
+        if !seen.insert((pos, dir)) {
+            continue;
+        }
+        energized.insert(pos);
+
+        let new_dirs = if let Some(mirror) = &game[pos] {
+            apply_mirror(mirror, dir)
+        } else {
+            vec![dir]
+        };
+
+        for d in new_dirs {
+            if let Some(new_pos) = game.offset(pos, d.delta().into()) {
+                to_do.push((new_pos, d));
+            }
+        }
+    }
+    energized
+}
+
+fn parse(input: &str) -> Result<Game> {
+    Grid::parse(input, |ch| match ch {
+        '.' => Ok(None),
+        _ => Ok(Some(ch.to_string().parse()?)),
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Game;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let game = parse(input)?;
+        if game.width() == 0 {
+            bail!("Empty grid");
+        }
+        Ok(game)
+    }
+
+    fn part1(game: &Self::Input<'_>) -> Result<Self::Part1> {
+        let starting_position = ((0, 0), Direction::Right);
+        let energized = find_energized(game, starting_position);
+
+        Ok(energized.len())
+    }
+
+    fn part2(game: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut positions_to_test = Vec::new();
+        let (max_x, max_y) = (game.width() - 1, game.height() - 1);
+        for y in 0..=max_y {
+            positions_to_test.push(((0, y), Direction::Right));
+            positions_to_test.push(((max_x, y), Direction::Left));
+        }
+        for x in 0..=max_x {
+            positions_to_test.push(((x, 0), Direction::Down));
+            positions_to_test.push(((x, max_y), Direction::Up));
+        }
+
+        let mut positions_to_energized = BTreeMap::new();
+        for starting_position in positions_to_test {
+            let energized = find_energized(game, starting_position);
+            positions_to_energized.insert(starting_position, energized);
+        }
+
+        let total = positions_to_energized
+            .values()
+            .map(|energized| energized.len())
+            .max()
+            .context("Nowhere to start")?;
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day16/example_input");
+        let expected = 46;
+        let actual = Day16::part1(&Day16::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day16/example_input");
+        let expected = 51;
+        let actual = Day16::part2(&Day16::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
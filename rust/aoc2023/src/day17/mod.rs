@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{grid::Pos, search::dijkstra, Direction, Grid, Solution};
+
+fn parse(input: &str) -> Result<Grid<i64>> {
+    Grid::parse(input, |ch| {
+        Ok(ch.to_digit(10).ok_or_else(|| anyhow!("invalid digit"))? as i64)
+    })
+}
+
+/// The direction of the last move and how many moves in a row have gone that
+/// way.
+type RecentHistory = Option<(Direction, u8)>;
+
+fn solve(
+    grid: &Grid<i64>,
+    min_move_in_direction: u8,
+    max_move_in_direction: u8,
+) -> Result<i64> {
+    let starting_pos = (0, 0);
+    let ending_pos = (grid.width() - 1, grid.height() - 1);
+
+    let successors = |&(pos, recent_history): &(Pos, RecentHistory)| {
+        let opposite_direction = recent_history.map(|(d, _)| d.opposite());
+        let mut next = vec![];
+        for d in Direction::ALL {
+            if Some(d) == opposite_direction {
+                continue;
+            }
+            match recent_history {
+                Some((prev_direction, num_steps))
+                    if d != prev_direction && num_steps < min_move_in_direction =>
+                {
+                    continue;
+                }
+                _ => {}
+            }
+            let new_num_steps = match recent_history {
+                Some((prev_direction, num_steps)) if d == prev_direction => num_steps + 1,
+                _ => 1,
+            };
+            if new_num_steps > max_move_in_direction {
+                continue;
+            }
+            let Some(neighbor) = grid.offset(pos, d.delta().into()) else {
+                continue;
+            };
+            if neighbor == ending_pos && new_num_steps < min_move_in_direction {
+                continue;
+            }
+            next.push(((neighbor, Some((d, new_num_steps))), grid[neighbor]));
+        }
+        next
+    };
+
+    dijkstra((starting_pos, None), successors, |&(pos, _)| pos == ending_pos)
+        .map(|path| path.cost)
+        .ok_or_else(|| anyhow!("no ending pos"))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Grid<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        let min_move_in_direction = 0;
+        let max_move_in_direction = 3;
+
+        solve(grid, min_move_in_direction, max_move_in_direction)
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        let min_move_in_direction = 4;
+        let max_move_in_direction = 10;
+
+        solve(grid, min_move_in_direction, max_move_in_direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day17/example_input");
+        let expected = 102;
+        let actual = Day17::part1(&Day17::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day17/example_input");
+        let expected = 94;
+        let actual = Day17::part2(&Day17::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day17/example_input2");
+        let expected = 71;
+        let actual = Day17::part2(&Day17::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Bounds2, Direction, Point2, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, i64 as nom_i64, line_ending},
+    multi::separated_list1,
+    sequence::{delimited, terminated, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+struct Instruction {
+    dir: Direction,
+    num: i64,
+    color: String,
+}
+
+fn instruction_from_color(color: &str) -> Result<Instruction> {
+    // 6 digit color -> 5 hex digit, 1 number from 0 to 3.
+    if color.len() != 6 {
+        bail!("invalid color: {}", color);
+    }
+    let (num, dir_num) = color.split_at(5);
+
+    let dir = match dir_num {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => bail!("invalid color: {}", color),
+    };
+    let num = i64::from_str_radix(num, 16)?;
+
+    Ok(Instruction {
+        dir,
+        num,
+        color: color.to_string(),
+    })
+}
+
+/// The dig plan, both as written and as decoded from the colours.
+#[derive(Debug)]
+pub struct Instructions {
+    instructions: Vec<Instruction>,
+    from_colors: Vec<Instruction>,
+}
+
+fn parse_dir(input: &str) -> IResult<&str, Direction> {
+    let (input, c) = alt((tag("U"), tag("R"), tag("D"), tag("L")))(input)?;
+    let dir = match c {
+        "U" => Direction::Up,
+        "R" => Direction::Right,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        _ => unreachable!(),
+    };
+    Ok((input, dir))
+}
+
+fn parse_color(input: &str) -> IResult<&str, String> {
+    let (input, color) = delimited(tag("(#"), alphanumeric1, tag(")"))(input)?;
+    Ok((input, color.to_string()))
+}
+
+fn parse_nom(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list1(
+        line_ending,
+        tuple((
+            terminated(parse_dir, tag(" ")),
+            terminated(nom_i64, tag(" ")),
+            parse_color,
+        )),
+    )(input)
+    .map(|(input, instructions)| {
+        (
+            input,
+            instructions
+                .into_iter()
+                .map(|(dir, num, color)| Instruction { dir, num, color })
+                .collect(),
+        )
+    })
+}
+
+fn parse(input: &str) -> Result<Instructions> {
+    let (_, instructions) = parse_nom(input).map_err(|err| anyhow::anyhow!(err.to_owned()))?;
+    let from_colors = instructions
+        .iter()
+        .map(|i| instruction_from_color(&i.color))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Instructions {
+        instructions,
+        from_colors,
+    })
+}
+
+fn is_outside(
+    grid: &BTreeMap<Point2, String>,
+    bounds: Bounds2,
+    pos: Point2,
+    known_outside: &mut BTreeSet<Point2>,
+    known_inside: &mut BTreeSet<Point2>,
+) -> bool {
+    if known_outside.contains(&pos) {
+        return true;
+    }
+    if known_inside.contains(&pos) {
+        return false;
+    }
+    let mut outside = false;
+    let mut to_visit = vec![pos];
+    let mut visited = BTreeSet::new();
+
+    while let Some(pos) = to_visit.pop() {
+        if !visited.insert(pos) {
+            continue;
+        }
+
+        if known_outside.contains(&pos) {
+            outside = true;
+            continue;
+        }
+
+        for new_pos in pos.neighbours4() {
+            if !bounds.contains(new_pos) {
+                outside = true;
+                continue;
+            }
+            if grid.get(&new_pos).is_some() {
+                continue;
+            }
+
+            to_visit.push(new_pos);
+        }
+    }
+
+    if outside {
+        for pos in visited {
+            known_outside.insert(pos);
+        }
+    } else {
+        for pos in visited {
+            known_inside.insert(pos);
+        }
+    }
+
+    outside
+}
+
+fn construct_grid(instructions: &Instructions) -> BTreeMap<Point2, String> {
+    let mut grid = BTreeMap::new();
+    let mut pos = Point2::ZERO;
+    grid.insert(pos, "white".to_string());
+    for instruction in &instructions.instructions {
+        for _ in 0..instruction.num {
+            pos += instruction.dir.delta();
+            grid.insert(pos, instruction.color.clone());
+        }
+    }
+    grid
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Instructions;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Result<Self::Part1> {
+        let grid = construct_grid(instructions);
+
+        // Leave a ring of outside around the trench
+        let mut bounds = Bounds2::from_points(grid.keys().copied()).context("Empty grid")?;
+        bounds.extend(bounds.min - Point2::new(1, 1));
+        bounds.extend(bounds.max + Point2::new(1, 1));
+
+        let mut known_outside = BTreeSet::new();
+        for p in bounds.points() {
+            if p.x == bounds.min.x
+                || p.x == bounds.max.x
+                || p.y == bounds.min.y
+                || p.y == bounds.max.y
+            {
+                known_outside.insert(p);
+            }
+        }
+        let mut known_inside = BTreeSet::new();
+        let mut total = 0;
+        for p in bounds.points() {
+            if grid.contains_key(&p) {
+                total += 1;
+                continue;
+            }
+            if !is_outside(&grid, bounds, p, &mut known_outside, &mut known_inside) {
+                total += 1;
+            }
+        }
+        Ok(total)
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut points = vec![Point2::ZERO];
+
+        let mut pos = Point2::ZERO;
+        let mut b = 0i64;
+        for instruction in &instructions.from_colors {
+            pos += instruction.dir.delta() * instruction.num;
+            b += instruction.num;
+            points.push(pos);
+        }
+        let mut total = 0i64;
+        for i in 0..points.len() {
+            let x0 = points[i].x;
+            let y1 = points[(i + 1) % points.len()].y;
+            let x2 = points[(i + 2) % points.len()].x;
+            let area = y1 * (x0 - x2);
+            total += area;
+        }
+
+        total = total.abs() / 2;
+
+        let i = total - b / 2 + 1;
+
+        Ok(i + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day18/example_input");
+        let expected = 62;
+        let actual = Day18::part1(&Day18::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day18/example_input");
+        // let expected = 62;
+        let expected = 952408144115;
+        let actual = Day18::part2(&Day18::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
+
+mod parsing;
+
+use parsing::parse;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition<'a> {
+    attribute: &'a str,
+    operator: Operator,
+    value: i64,
+    outcome: Outcome<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Workflow<'a> {
+    conditions: Vec<Condition<'a>>,
+    default_outcome: Outcome<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operator {
+    GreaterThan,
+    LessThan,
+}
+
+/// A part's rating for each of its attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part<'a> {
+    pub ratings: BTreeMap<&'a str, i64>,
+}
+
+/// The ratings from `min` to `max` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Interval {
+    pub fn new(min: i64, max: i64) -> Interval {
+        Interval { min, max }
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.max - self.min + 1).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The ratings below `value`, and those from `value` up.
+    fn split(&self, value: i64) -> (Interval, Interval) {
+        (
+            Interval::new(self.min, self.max.min(value - 1)),
+            Interval::new(self.min.max(value), self.max),
+        )
+    }
+}
+
+/// A range of ratings for every attribute: all the parts in it go through
+/// the workflows the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<'a> {
+    pub ranges: BTreeMap<&'a str, Interval>,
+}
+
+impl Region<'_> {
+    /// How many distinct parts the region holds.
+    pub fn size(&self) -> i64 {
+        self.ranges.values().map(Interval::len).product()
+    }
+}
+
+impl fmt::Display for Region<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (attribute, range)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{attribute}={}..={}", range.min, range.max)?;
+        }
+        Ok(())
+    }
+}
+
+impl Condition<'_> {
+    fn matches(&self, rating: i64) -> bool {
+        match self.operator {
+            Operator::GreaterThan => rating > self.value,
+            Operator::LessThan => rating < self.value,
+        }
+    }
+
+    /// The part of `range` that matches, and the part that doesn't.
+    fn split(&self, range: Interval) -> (Interval, Interval) {
+        match self.operator {
+            Operator::GreaterThan => {
+                let (below, above) = range.split(self.value + 1);
+                (above, below)
+            }
+            Operator::LessThan => range.split(self.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<'a> {
+    workflows: BTreeMap<&'a str, Workflow<'a>>,
+    parts: Vec<Part<'a>>,
+}
+
+impl<'a> Game<'a> {
+    const START: &'static str = "in";
+
+    fn workflow(&self, name: &str) -> Result<&Workflow<'a>> {
+        self.workflows
+            .get(name)
+            .with_context(|| format!("No workflow called {name}"))
+    }
+
+    /// Every attribute named by a part or a rule.
+    pub fn attributes(&self) -> BTreeSet<&'a str> {
+        let rated = self.parts.iter().flat_map(|part| part.ratings.keys());
+        let tested = self
+            .workflows
+            .values()
+            .flat_map(|workflow| workflow.conditions.iter().map(|c| &c.attribute));
+        rated.chain(tested).copied().collect()
+    }
+
+    /// Runs `part` through the workflows from `in`.
+    pub fn accepts(&self, part: &Part) -> Result<bool> {
+        let mut seen = BTreeSet::new();
+        let mut outcome = Outcome::Workflow(Self::START);
+        while let Outcome::Workflow(name) = outcome {
+            if !seen.insert(name) {
+                bail!("Workflow {name} loops back on itself");
+            }
+            let workflow = self.workflow(name)?;
+            outcome = workflow.default_outcome;
+            for condition in &workflow.conditions {
+                let rating = part
+                    .ratings
+                    .get(condition.attribute)
+                    .with_context(|| format!("Part has no {} rating", condition.attribute))?;
+                if condition.matches(*rating) {
+                    outcome = condition.outcome;
+                    break;
+                }
+            }
+        }
+        Ok(outcome == Outcome::Accept)
+    }
+
+    /// Splits `region` into the disjoint regions whose parts are accepted.
+    pub fn accepted_regions(&self, region: Region<'a>) -> Result<Vec<Region<'a>>> {
+        let mut accepted = vec![];
+        let mut queue = vec![(Self::START, region, 0)];
+        while let Some((name, mut region, depth)) = queue.pop() {
+            if depth > self.workflows.len() {
+                bail!("Workflow {name} loops back on itself");
+            }
+            let workflow = self.workflow(name)?;
+            let mut outcomes = vec![];
+            for condition in &workflow.conditions {
+                let range = *region
+                    .ranges
+                    .get(condition.attribute)
+                    .with_context(|| format!("No range given for {}", condition.attribute))?;
+                let (matched, unmatched) = condition.split(range);
+                let mut matched_region = region.clone();
+                matched_region.ranges.insert(condition.attribute, matched);
+                outcomes.push((matched_region, condition.outcome));
+                region.ranges.insert(condition.attribute, unmatched);
+            }
+            outcomes.push((region, workflow.default_outcome));
+
+            for (region, outcome) in outcomes {
+                if region.ranges.values().any(Interval::is_empty) {
+                    continue;
+                }
+                match outcome {
+                    Outcome::Accept => accepted.push(region),
+                    Outcome::Reject => {}
+                    Outcome::Workflow(name) => queue.push((name, region, depth + 1)),
+                }
+            }
+        }
+        Ok(accepted)
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Game<'a>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, game) = parse(input).map_err(|e| e.to_owned())?;
+        Ok(game)
+    }
+
+    fn part1(game: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut total = 0;
+        for part in &game.parts {
+            if game.accepts(part)? {
+                total += part.ratings.values().sum::<i64>();
+            }
+        }
+        Ok(total)
+    }
+
+    fn part2(game: &Self::Input<'_>) -> Result<Self::Part2> {
+        let everything = Region {
+            ranges: game
+                .attributes()
+                .into_iter()
+                .map(|attribute| (attribute, Interval::new(1, 4000)))
+                .collect(),
+        };
+        let accepted = game.accepted_regions(everything)?;
+        Ok(accepted.iter().map(Region::size).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day19/example_input");
+        let expected = 19114;
+        let actual = Day19::part1(&Day19::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day19/example_input");
+        let expected = 167409079868000;
+        let actual = Day19::part2(&Day19::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_attributes() -> Result<()> {
+        let game = Day19::parse(
+            "in{colour<3:A,size>10:big,R}\nbig{colour>4:R,A}\n\n{colour=1,size=20}\n{colour=5,size=20}",
+        )?;
+        assert_eq!(
+            game.attributes().into_iter().collect::<Vec<_>>(),
+            ["colour", "size"]
+        );
+        assert_eq!(Day19::part1(&game)?, 21);
+
+        let everything = Region {
+            ranges: BTreeMap::from([
+                ("colour", Interval::new(1, 6)),
+                ("size", Interval::new(1, 20)),
+            ]),
+        };
+        let accepted = game.accepted_regions(everything)?;
+        let described: Vec<String> = accepted.iter().map(Region::to_string).collect();
+        assert_eq!(
+            described,
+            ["colour=1..=2, size=1..=20", "colour=3..=4, size=11..=20"]
+        );
+        assert_eq!(accepted.iter().map(Region::size).sum::<i64>(), 60);
+
+        let missing = Region {
+            ranges: BTreeMap::from([("colour", Interval::new(1, 6))]),
+        };
+        assert!(game.accepted_regions(missing).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_loops() -> Result<()> {
+        let game = Day19::parse("in{x<5:a,R}\na{x<3:in,A}\n\n{x=1}")?;
+        assert!(game.accepts(&game.parts[0]).is_err());
+        Ok(())
+    }
+}
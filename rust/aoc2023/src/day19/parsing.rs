@@ -0,0 +1,94 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, i64 as nom_i64, line_ending},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair, tuple},
+    IResult, Parser,
+};
+
+use super::{Condition, Game, Operator, Outcome, Part, Workflow};
+
+fn parse_outcome(input: &str) -> IResult<&str, Outcome<'_>> {
+    let (input, outcome) = alt((
+        tag("A").map(|_| Outcome::Accept),
+        tag("R").map(|_| Outcome::Reject),
+        alpha1.map(Outcome::Workflow),
+    ))(input)?;
+    Ok((input, outcome))
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, (&str, Workflow<'_>)> {
+    let (input, name) = alpha1(input)?;
+    let (input, (conditions, default_outcome)) = delimited(
+        tag("{"),
+        separated_pair(
+            separated_list1(
+                tag(","),
+                tuple((
+                    alpha1,
+                    alt((
+                        char('>').map(|_| Operator::GreaterThan),
+                        char('<').map(|_| Operator::LessThan),
+                    )),
+                    nom_i64,
+                    preceded(char(':'), parse_outcome),
+                ))
+                .map(|(attribute, operator, value, outcome)| Condition {
+                    attribute,
+                    operator,
+                    value,
+                    outcome,
+                }),
+            ),
+            tag(","),
+            parse_outcome,
+        ),
+        tag("}"),
+    )(input)?;
+    Ok((
+        input,
+        (
+            name,
+            Workflow {
+                conditions,
+                default_outcome,
+            },
+        ),
+    ))
+}
+
+fn parse_workflows(input: &str) -> IResult<&str, Vec<(&str, Workflow<'_>)>> {
+    let (input, workflows) = separated_list1(line_ending, parse_workflow)(input)?;
+    Ok((input, workflows))
+}
+
+/// A part with any attributes, like `{x=787,m=2655}`.
+fn parse_part(input: &str) -> IResult<&str, Part<'_>> {
+    let (input, ratings) = delimited(
+        tag("{"),
+        separated_list1(tag(","), separated_pair(alpha1, tag("="), nom_i64)),
+        tag("}"),
+    )(input)?;
+    Ok((
+        input,
+        Part {
+            ratings: ratings.into_iter().collect(),
+        },
+    ))
+}
+
+fn parse_parts(input: &str) -> IResult<&str, Vec<Part<'_>>> {
+    separated_list1(line_ending, parse_part)(input)
+}
+
+pub(crate) fn parse(input: &str) -> IResult<&str, Game<'_>> {
+    let (input, (workflows, parts)) = all_consuming(separated_pair(
+        parse_workflows,
+        tuple((line_ending, line_ending)),
+        parse_parts,
+    ))(input.trim_end())?;
+    let workflows = workflows.into_iter().collect();
+    Ok((input, Game { workflows, parts }))
+}
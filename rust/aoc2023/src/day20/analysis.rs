@@ -0,0 +1,213 @@
+//! Working out when a module first gets a low pulse, without pressing the
+//! button the trillions of times it takes.
+//!
+//! The module has to be fed by a single conjunction, which sends low only
+//! once every one of its inputs has last sent high.  Each of those inputs is
+//! driven by its own part of the circuit that shares nothing with the others
+//! but the broadcaster, so each part can be run on its own until its state
+//! repeats, giving the presses on which it sends high.  The first press on
+//! which every part sends high is the answer.  That takes a part that sends
+//! high during a press to still be high when the others' pulses arrive,
+//! which holds for circuits like the puzzle's where every part fires at the
+//! same point in the press.
+
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::cycle::{coincide, find_cycle_by_key, Cycle};
+use itertools::Itertools;
+
+use super::{Config, Module, Simulator};
+
+/// One of the parts of the circuit feeding the conjunction in front of the
+/// module.
+#[derive(Debug, Clone)]
+pub(crate) struct Feeder<'a> {
+    /// The module that sends the part's pulses to the conjunction.
+    output: Module<'a>,
+    /// Every module in the part, `output` included.
+    modules: BTreeSet<Module<'a>>,
+    /// How the part's state repeats from one press to the next.
+    cycle: Cycle,
+    /// Whether `output` sends high on each press, from the first, until the
+    /// state repeats.
+    firing: Vec<bool>,
+}
+
+impl Feeder<'_> {
+    fn fires(&self, press: usize) -> bool {
+        self.firing[self.cycle.equivalent_step(press - 1)]
+    }
+
+    /// The presses on which the part sends high, once it's in its cycle.
+    fn phases(&self) -> Vec<Cycle> {
+        let Cycle { start, period } = self.cycle;
+        (start..start + period)
+            .filter(|&step| self.firing[step])
+            .map(|step| Cycle::new(step + 1, period))
+            .collect()
+    }
+}
+
+/// Every module that can send a pulse to `module`, short of the broadcaster.
+fn ancestors<'a>(config: &Config<'a>, module: Module<'a>) -> BTreeSet<Module<'a>> {
+    let mut seen = BTreeSet::new();
+    let mut stack = vec![module];
+    while let Some(module) = stack.pop() {
+        for &input in config.inputs(module) {
+            if !matches!(input, Module::Button | Module::Broadcaster) && seen.insert(input) {
+                stack.push(input);
+            }
+        }
+    }
+    seen
+}
+
+/// Splits the circuit feeding `target` into its independent parts, and runs
+/// each until it repeats.
+pub(crate) fn feeders<'a>(config: &Config<'a>, target: Module<'a>) -> Result<Vec<Feeder<'a>>> {
+    let hub = match config.inputs(target) {
+        [hub @ Module::Conjunction(_)] => *hub,
+        inputs => bail!(
+            "{} is fed by [{}], not a single conjunction",
+            target.name(),
+            inputs.iter().map(Module::name).join(", ")
+        ),
+    };
+
+    let mut feeders: Vec<Feeder> = vec![];
+    for &output in config.inputs(hub) {
+        let mut modules = ancestors(config, output);
+        modules.insert(output);
+        if modules.contains(&hub) || modules.contains(&target) {
+            bail!("{} feeds back into {}", hub.name(), output.name());
+        }
+        for other in &feeders {
+            if let Some(shared) = modules.intersection(&other.modules).next() {
+                bail!(
+                    "The parts ending in {} and {} share {}",
+                    other.output.name(),
+                    output.name(),
+                    shared.name()
+                );
+            }
+        }
+
+        let mut simulator = Simulator::within(config, &modules);
+        let presses = std::iter::from_fn(|| {
+            let state = (simulator.flip_flops.clone(), simulator.last_pulse.clone());
+            let fired = simulator
+                .press()
+                .any(|pulse| pulse.from == output && pulse.high);
+            Some((state, fired))
+        });
+        let (cycle, history) = find_cycle_by_key(presses, |(state, _)| state.clone())
+            .context("Pressing the button never stops")?;
+        feeders.push(Feeder {
+            output,
+            modules,
+            cycle,
+            firing: history.into_iter().map(|(_, fired)| fired).collect(),
+        });
+    }
+    if feeders.is_empty() {
+        bail!("{} has no inputs", hub.name());
+    }
+    Ok(feeders)
+}
+
+/// The first press of the button that sends `target` a low pulse.
+pub(crate) fn first_low_pulse(config: &Config<'_>, target: Module<'_>) -> Result<u64> {
+    let feeders = feeders(config, target)?;
+
+    // Before every part has settled into its cycle, just look.
+    let settled = feeders
+        .iter()
+        .map(|feeder| feeder.cycle.start)
+        .max()
+        .unwrap_or(0);
+    if let Some(press) = (1..=settled).find(|&press| feeders.iter().all(|f| f.fires(press))) {
+        return Ok(press as u64);
+    }
+
+    // After that, try each way of picking a phase from each part's cycle.
+    feeders
+        .iter()
+        .map(|feeder| feeder.phases().into_iter())
+        .multi_cartesian_product()
+        .filter_map(|phases| coincide(&phases))
+        .min()
+        .map(|press| press as u64)
+        .with_context(|| format!("{} never receives a low pulse", target.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day20::Day20;
+    use aoc_common::Solution;
+
+    /// Two binary counters, of four and three bits.  `ia` sends high when
+    /// the first reaches 9, 11, 13 or 15 (mod 16), and `ib` when the second
+    /// reaches 5 or 7 (mod 8).
+    const COUNTERS: &str = "\
+broadcaster -> aa, ba
+%aa -> ab, ca
+%ab -> ac
+%ac -> ad
+%ad -> ca
+&ca -> ia
+&ia -> hub
+%ba -> bb, cb
+%bb -> bc
+%bc -> cb
+&cb -> ib
+&ib -> hub
+&hub -> rx";
+
+    fn brute_force(config: &Config<'_>, target: Module<'_>) -> u64 {
+        let mut simulator = Simulator::new(config);
+        loop {
+            let press = simulator.presses + 1;
+            if simulator
+                .press()
+                .any(|pulse| pulse.to == target && !pulse.high)
+            {
+                return press;
+            }
+        }
+    }
+
+    #[test]
+    fn test_counters() -> Result<()> {
+        let config = Day20::parse(COUNTERS)?;
+        let rx = config.module("rx")?;
+        let feeders = feeders(&config, rx)?;
+        assert_eq!(
+            feeders.iter().map(|f| f.output.name()).collect_vec(),
+            ["ia", "ib"]
+        );
+        assert_eq!(feeders[1].modules.len(), 5);
+        assert_eq!(feeders[0].phases().len(), 4);
+        assert_eq!(first_low_pulse(&config, rx)?, 13);
+        assert_eq!(brute_force(&config, rx), 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_wiring() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input2");
+        let config = Day20::parse(file_data)?;
+        let con = config.module("con")?;
+        assert!(feeders(&config, con)
+            .unwrap_err()
+            .to_string()
+            .contains("not a single conjunction"));
+
+        let shared = COUNTERS.replace("%bc -> cb", "%bc -> cb, ca");
+        let config = Day20::parse(&shared)?;
+        let error = feeders(&config, config.module("rx")?).unwrap_err();
+        assert_eq!(error.to_string(), "The parts ending in ia and ib share ba");
+        Ok(())
+    }
+}
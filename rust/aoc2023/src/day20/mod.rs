@@ -0,0 +1,352 @@
+mod analysis;
+mod parsing;
+
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt,
+};
+
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+
+use parsing::parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Module<'a> {
+    Button,
+    Broadcaster,
+    FlipFlop(&'a str),
+    Conjunction(&'a str),
+    Untyped(&'a str),
+}
+
+impl<'a> Module<'a> {
+    fn name(&self) -> &'a str {
+        match self {
+            Module::Button => "button",
+            Module::Broadcaster => "broadcaster",
+            Module::FlipFlop(name) | Module::Conjunction(name) | Module::Untyped(name) => name,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Config<'a> {
+    modules: BTreeMap<Module<'a>, Vec<Module<'a>>>,
+    module_name_to_module: BTreeMap<&'a str, Module<'a>>,
+    reverse_modules: BTreeMap<Module<'a>, Vec<Module<'a>>>,
+}
+
+impl<'a> Config<'a> {
+    fn module(&self, name: &str) -> Result<Module<'a>> {
+        self.module_name_to_module
+            .get(name)
+            .copied()
+            .with_context(|| format!("No {name} module"))
+    }
+
+    fn inputs(&self, module: Module<'a>) -> &[Module<'a>] {
+        self.reverse_modules.get(&module).map_or(&[], Vec::as_slice)
+    }
+
+    /// The module graph in Graphviz DOT, with flip-flops as boxes and
+    /// conjunctions as diamonds.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph modules {\n");
+        for module in self.modules.keys() {
+            let (label, shape) = match module {
+                Module::Button => continue,
+                Module::Broadcaster => ("broadcaster".to_string(), "doublecircle"),
+                Module::FlipFlop(name) => (format!("%{name}"), "box"),
+                Module::Conjunction(name) => (format!("&{name}"), "diamond"),
+                Module::Untyped(name) => (name.to_string(), "ellipse"),
+            };
+            dot += &format!(
+                "    {} [label=\"{label}\", shape={shape}];\n",
+                module.name()
+            );
+        }
+        for (module, outputs) in &self.modules {
+            if *module == Module::Button {
+                continue;
+            }
+            for output in outputs {
+                dot += &format!("    {} -> {};\n", module.name(), output.name());
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse<'a> {
+    from: Module<'a>,
+    to: Module<'a>,
+    high: bool,
+}
+
+/// The pulse as the puzzle writes it, e.g. `a -high-> b`.
+impl fmt::Display for Pulse<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -{}-> {}",
+            self.from.name(),
+            if self.high { "high" } else { "low" },
+            self.to.name()
+        )
+    }
+}
+
+/// The modules' state as the button is pressed over and over.
+#[derive(Debug, Clone)]
+pub struct Simulator<'c, 'a> {
+    config: &'c Config<'a>,
+    /// When set, any other module ignores the pulses it's sent, so that part
+    /// of the circuit can be run on its own.
+    within: Option<BTreeSet<Module<'a>>>,
+    queue: VecDeque<Pulse<'a>>,
+
+    /// Whether each flip-flop is on.
+    flip_flops: BTreeMap<Module<'a>, bool>,
+    /// The last pulse each module sent, which is what the conjunctions it
+    /// feeds remember of it.
+    last_pulse: BTreeMap<Module<'a>, bool>,
+
+    presses: u64,
+    low_pulses: u64,
+    high_pulses: u64,
+}
+
+impl<'c, 'a> Simulator<'c, 'a> {
+    pub fn new(config: &'c Config<'a>) -> Simulator<'c, 'a> {
+        Simulator {
+            config,
+            within: None,
+            queue: VecDeque::new(),
+            flip_flops: BTreeMap::new(),
+            last_pulse: BTreeMap::new(),
+            presses: 0,
+            low_pulses: 0,
+            high_pulses: 0,
+        }
+    }
+
+    /// A simulator in which only `modules` (and the broadcaster) act on the
+    /// pulses they're sent.
+    fn within(config: &'c Config<'a>, modules: &BTreeSet<Module<'a>>) -> Simulator<'c, 'a> {
+        let mut within = modules.clone();
+        within.insert(Module::Broadcaster);
+        Simulator {
+            within: Some(within),
+            ..Simulator::new(config)
+        }
+    }
+
+    /// Pushes the button, returning the pulses it sets off in the order
+    /// they're delivered.  Each pulse is only delivered as the iterator
+    /// reaches it; whatever is left when the iterator is dropped is
+    /// delivered then.
+    pub fn press(&mut self) -> Pulses<'_, 'c, 'a> {
+        self.presses += 1;
+        self.queue.push_back(Pulse {
+            from: Module::Button,
+            to: Module::Broadcaster,
+            high: false,
+        });
+        Pulses { simulator: self }
+    }
+
+    /// Pushes the button `presses` times.
+    pub fn run(&mut self, presses: u64) {
+        for _ in 0..presses {
+            self.press();
+        }
+    }
+
+    fn deliver(&mut self, pulse: Pulse<'a>) {
+        if pulse.high {
+            self.high_pulses += 1;
+        } else {
+            self.low_pulses += 1;
+        }
+        self.last_pulse.insert(pulse.from, pulse.high);
+
+        let module = pulse.to;
+        if self
+            .within
+            .as_ref()
+            .is_some_and(|within| !within.contains(&module))
+        {
+            return;
+        }
+        let send = match module {
+            Module::Button | Module::Broadcaster => false,
+            Module::FlipFlop(_) => {
+                if pulse.high {
+                    return;
+                }
+                let on = self.flip_flops.entry(module).or_default();
+                *on = !*on;
+                *on
+            }
+            Module::Conjunction(_) => self
+                .config
+                .inputs(module)
+                .iter()
+                .any(|input| !self.last_pulse.get(input).copied().unwrap_or_default()),
+            Module::Untyped(_) => return,
+        };
+        for &to in &self.config.modules[&module] {
+            self.queue.push_back(Pulse {
+                from: module,
+                to,
+                high: send,
+            });
+        }
+    }
+}
+
+/// The pulses set off by one push of the button.
+pub struct Pulses<'s, 'c, 'a> {
+    simulator: &'s mut Simulator<'c, 'a>,
+}
+
+impl<'a> Iterator for Pulses<'_, '_, 'a> {
+    type Item = Pulse<'a>;
+
+    fn next(&mut self) -> Option<Pulse<'a>> {
+        let pulse = self.simulator.queue.pop_front()?;
+        self.simulator.deliver(pulse);
+        Some(pulse)
+    }
+}
+
+impl Drop for Pulses<'_, '_, '_> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Config<'a>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let (_, config) = parse(input.trim_end()).map_err(|e| e.to_owned())?;
+        Ok(config)
+    }
+
+    fn part1(config: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut simulator = Simulator::new(config);
+        simulator.run(1000);
+        Ok(simulator.low_pulses * simulator.high_pulses)
+    }
+
+    fn part2(config: &Self::Input<'_>) -> Result<Self::Part2> {
+        analysis::first_low_pulse(config, config.module("rx")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(config: &Config<'_>, presses: u64) -> String {
+        let mut simulator = Simulator::new(config);
+        let mut pulses = vec![];
+        for _ in 0..presses {
+            pulses.extend(simulator.press().map(|pulse| pulse.to_string()));
+        }
+        pulses.join("\n")
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input");
+        let expected = 32000000;
+        let actual = Day20::part1(&Day20::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input");
+        let (_, config) = parse(file_data)?;
+        let expected = include_str!("example_output.txt");
+        assert_eq!(trace(&config, 1), expected);
+
+        let mut simulator = Simulator::new(&config);
+        simulator.run(1);
+        assert_eq!(simulator.low_pulses * simulator.high_pulses, 32);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace_ex2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input2");
+        let (_, config) = parse(file_data)?;
+
+        let cases = [
+            (1, include_str!("example_output2_1.txt")),
+            (2, include_str!("example_output2_2.txt")),
+            (3, include_str!("example_output2_3.txt")),
+            (4, include_str!("example_output2_4.txt")),
+        ];
+
+        for (num_buttons, expected) in cases {
+            assert_eq!(trace(&config, num_buttons), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1_ex2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input2");
+        let actual = Day20::part1(&Day20::parse(file_data)?)?;
+        assert_eq!(actual, 11687500);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partial_press() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input");
+        let (_, config) = parse(file_data)?;
+        let mut simulator = Simulator::new(&config);
+        // Stopping part-way through a press still delivers the rest.
+        assert_eq!(simulator.press().take(2).count(), 2);
+        assert_eq!(simulator.low_pulses + simulator.high_pulses, 12);
+        assert_eq!(trace(&config, 2).lines().count(), 24);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_dot() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day20/example_input2");
+        let (_, config) = parse(file_data)?;
+        assert_eq!(
+            config.to_dot(),
+            r#"digraph modules {
+    broadcaster [label="broadcaster", shape=doublecircle];
+    a [label="%a", shape=box];
+    b [label="%b", shape=box];
+    con [label="&con", shape=diamond];
+    inv [label="&inv", shape=diamond];
+    output [label="output", shape=ellipse];
+    broadcaster -> a;
+    a -> inv;
+    a -> con;
+    b -> con;
+    con -> output;
+    inv -> b;
+}
+"#
+        );
+        Ok(())
+    }
+}
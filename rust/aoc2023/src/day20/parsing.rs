@@ -10,10 +10,10 @@ use nom::{
     IResult, Parser,
 };
 
-use crate::Config;
-use crate::Module;
+use super::Config;
+use super::Module;
 
-fn parse_module(input: &'static str) -> IResult<&str, Module> {
+fn parse_module(input: &str) -> IResult<&str, Module<'_>> {
     alt((
         tag("broadcaster").map(|_| Module::Broadcaster),
         preceded(tag("%"), alpha1).map(Module::FlipFlop),
@@ -21,7 +21,7 @@ fn parse_module(input: &'static str) -> IResult<&str, Module> {
     ))(input)
 }
 
-pub(crate) fn parse(input: &'static str) -> IResult<&str, Config> {
+pub(crate) fn parse(input: &str) -> IResult<&str, Config<'_>> {
     let (input, modules) = all_consuming(separated_list1(
         line_ending,
         separated_pair(
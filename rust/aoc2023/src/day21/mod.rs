@@ -0,0 +1,262 @@
+use std::collections::BTreeSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{grid::Pos, Grid, Solution};
+use num::integer::lcm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Tile {
+    Plot,
+    Rock,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    starting_position: Pos,
+}
+
+fn parse(input: &str) -> Result<Map> {
+    let chars: Grid<char> = input.parse()?;
+    let starting_position = chars.find(|&c| c == 'S').context("No starting position")?;
+    let tiles = Grid::parse(input, |c| match c {
+        '.' | 'S' => Ok(Tile::Plot),
+        '#' => Ok(Tile::Rock),
+        _ => bail!("invalid character {c:?}"),
+    })?;
+    Ok(Map {
+        tiles,
+        starting_position,
+    })
+}
+
+fn solve_one_map(start: Pos, num_steps: usize, map: &Map) -> usize {
+    let mut positions = BTreeSet::from([start]);
+    for _ in 0..num_steps {
+        let mut new_positions = BTreeSet::new();
+        for position in positions {
+            for neighbour in map.tiles.neighbours4(position) {
+                if map.tiles[neighbour] == Tile::Plot {
+                    new_positions.insert(neighbour);
+                }
+            }
+        }
+        positions = new_positions;
+    }
+
+    positions.len()
+}
+
+fn reachable_plots(map: &Map, num_steps: usize) -> usize {
+    solve_one_map(map.starting_position, num_steps, map)
+}
+
+/// Step counts up to this are counted directly rather than extrapolated.
+const DIRECT_STEPS: usize = 1000;
+
+/// How many periods' worth of step counts to sample when looking for the
+/// plot counts to settle into a quadratic.
+const SAMPLED_PERIODS: usize = 16;
+
+/// How many plots are first reached after each number of steps, up to
+/// `max_steps`, on the map tiled out in every direction.
+fn distance_counts(map: &Map, max_steps: usize) -> Vec<usize> {
+    let (width, height) = (map.tiles.width(), map.tiles.height());
+    // Enough copies of the map either side that nothing within `max_steps`
+    // of the start is cut off.
+    let copies_x = max_steps / width + 1;
+    let copies_y = max_steps / height + 1;
+    let tiled_width = (2 * copies_x + 1) * width;
+    let tiled_height = (2 * copies_y + 1) * height;
+    let (start_x, start_y) = map.starting_position;
+    let start = (start_x + copies_x * width, start_y + copies_y * height);
+
+    let mut seen = vec![false; tiled_width * tiled_height];
+    seen[start.1 * tiled_width + start.0] = true;
+    let mut frontier = vec![start];
+    let mut counts = vec![];
+    while counts.len() <= max_steps && !frontier.is_empty() {
+        counts.push(frontier.len());
+        let mut next = vec![];
+        for (x, y) in frontier {
+            let neighbours = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (x, y) in neighbours {
+                if x >= tiled_width || y >= tiled_height || seen[y * tiled_width + x] {
+                    continue;
+                }
+                if map.tiles[(x % width, y % height)] == Tile::Plot {
+                    seen[y * tiled_width + x] = true;
+                    next.push((x, y));
+                }
+            }
+        }
+        frontier = next;
+    }
+    counts
+}
+
+/// The plots reachable in exactly `num_steps`, given `distance_counts`
+/// covering at least that many steps: those first reached in as many steps
+/// or fewer, an even number of steps earlier.
+fn plots_after(counts: &[usize], num_steps: usize) -> usize {
+    counts
+        .iter()
+        .take(num_steps + 1)
+        .skip(num_steps % 2)
+        .step_by(2)
+        .sum()
+}
+
+/// The earliest point from which `samples` follow a quadratic to the end,
+/// with that quadratic's first three terms.  At least five terms have to
+/// agree, so that a quadratic isn't just fitted to three of them.
+fn fit_quadratic(samples: &[i64]) -> Option<(usize, [i64; 3])> {
+    let third_differences: Vec<i64> = samples
+        .windows(4)
+        .map(|w| w[3] - 3 * w[2] + 3 * w[1] - w[0])
+        .collect();
+    let settled = third_differences
+        .iter()
+        .rposition(|&d| d != 0)
+        .map_or(0, |i| i + 1);
+    (samples.len() >= settled + 5).then(|| {
+        (
+            settled,
+            [samples[settled], samples[settled + 1], samples[settled + 2]],
+        )
+    })
+}
+
+/// Counts plots after `num_steps` by sampling every `period` steps and
+/// extrapolating, which works once the counts grow as a quadratic in the
+/// number of periods.
+fn extrapolate(map: &Map, num_steps: usize, period: usize) -> Result<usize> {
+    let remainder = num_steps % period;
+    let max_steps = remainder + SAMPLED_PERIODS * period;
+    let counts = distance_counts(map, max_steps);
+    let samples: Vec<i64> = (0..=SAMPLED_PERIODS)
+        .map(|k| plots_after(&counts, remainder + k * period) as i64)
+        .collect();
+    let (settled, [a, b, c]) = fit_quadratic(&samples).with_context(|| {
+        format!(
+            "Plot counts every {period} steps don't settle into a quadratic by step {max_steps}"
+        )
+    })?;
+
+    let k = num_steps / period;
+    if k < settled {
+        return Ok(samples[k] as usize);
+    }
+    let n = (k - settled) as i128;
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let plots = a + n * (b - a) + n * (n - 1) / 2 * (c - 2 * b + a);
+    plots
+        .try_into()
+        .with_context(|| format!("{plots} plots is out of range"))
+}
+
+/// Counts plots on the map tiled out infinitely.  However the map is laid
+/// out, the number of steps to each copy of a plot eventually grows by the
+/// same amount per copy, so the counts every period of the tiling grow
+/// quadratically once far enough out.  How far that is depends on the map,
+/// so the counts are checked for it rather than assumed.
+fn reachable_plots_infinite(map: &Map, num_steps: usize) -> Result<usize> {
+    if num_steps <= DIRECT_STEPS {
+        return Ok(plots_after(&distance_counts(map, num_steps), num_steps));
+    }
+
+    let period = lcm(map.tiles.width(), map.tiles.height());
+    let mut reasons = vec![];
+    // With an odd period, the plots reached flip between two patterns every
+    // period, so try every other period as well.
+    for period in [period, 2 * period] {
+        match extrapolate(map, num_steps, period) {
+            Ok(plots) => return Ok(plots),
+            Err(e) => reasons.push(e.to_string()),
+        }
+    }
+    bail!(
+        "Can't extrapolate to {num_steps} steps: {}",
+        reasons.join("; ")
+    )
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(reachable_plots(map, 64))
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Self::Part2> {
+        reachable_plots_infinite(map, 26501365)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let map = parse(include_str!("../../../data/2023/day21/example_input"))?;
+        let expected = 16;
+        let actual = reachable_plots(&map, 6);
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infinite() -> Result<()> {
+        let map = parse(include_str!("../../../data/2023/day21/example_input"))?;
+        for (num_steps, expected) in [
+            (6, 16),
+            (10, 50),
+            (50, 1594),
+            (100, 6536),
+            (500, 167004),
+            (1000, 668697),
+            (5000, 16733044),
+        ] {
+            assert_eq!(reachable_plots_infinite(&map, num_steps)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_extrapolate_against_counting() -> Result<()> {
+        let map = parse(include_str!("../../../data/2023/day21/example_input"))?;
+        let counts = distance_counts(&map, 800);
+        for num_steps in (500..=800).step_by(13) {
+            let expected = plots_after(&counts, num_steps);
+            assert_eq!(extrapolate(&map, num_steps, 22)?, expected, "{num_steps}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_enclosed() -> Result<()> {
+        // The start is walled in with two other plots in a row, so the count
+        // flips between 2 and 1 forever: never a quadratic every 35 steps
+        // (an odd number), but a constant every 70.
+        let walled = ".......\n.#####.\n.#S..#.\n.#####.\n.......";
+        let map = parse(walled)?;
+        assert!(extrapolate(&map, 10_001, 35).is_err());
+        assert_eq!(reachable_plots_infinite(&map, 10_000)?, 2);
+        assert_eq!(reachable_plots_infinite(&map, 10_001)?, 1);
+        Ok(())
+    }
+}
@@ -3,13 +3,9 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Solution;
 use itertools::Itertools;
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 struct Pos3 {
@@ -24,10 +20,10 @@ impl FromStr for Pos3 {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let pos: [i32; 3] = s
             .split(',')
-            .map(|s| s.parse::<i32>().unwrap())
-            .collect::<Vec<_>>()
+            .map(|s| s.parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()?
             .try_into()
-            .unwrap();
+            .map_err(|_| anyhow!("expected x,y,z, not {s}"))?;
         Ok(Pos3 {
             x: pos[0],
             y: pos[1],
@@ -37,7 +33,7 @@ impl FromStr for Pos3 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
-struct Block(Pos3, Pos3);
+pub struct Block(Pos3, Pos3);
 
 impl Block {
     fn overlap_xy(&self, other: &Self) -> bool {
@@ -64,26 +60,8 @@ fn parse(input: &str) -> Result<Vec<Block>> {
         .collect()
 }
 
-fn part1(input: &str) -> Result<String> {
-    let blocks = parse(input)?;
-
-    let mut blocks: Vec<(usize, Block)> = blocks.into_iter().enumerate().collect();
-
-    let (_, supported_by) = flatten(&mut blocks);
-
-    let mut total = 0;
-    for (i, _) in &blocks {
-        let i_set = BTreeSet::from([SupportedBy::Block(*i)]);
-        if !supported_by.values().any(|s| s == &i_set) {
-            total += 1;
-        }
-    }
-
-    Ok(total.to_string())
-}
-
 fn flatten(blocks: &mut [(usize, Block)]) -> (usize, BTreeMap<usize, BTreeSet<SupportedBy>>) {
-    let max_z = blocks.iter().map(|(_, b)| b.0.z).max().unwrap();
+    let max_z = blocks.iter().map(|(_, b)| b.0.z).max().unwrap_or(0);
 
     let mut num_fell = 0;
 
@@ -136,47 +114,58 @@ fn flatten(blocks: &mut [(usize, Block)]) -> (usize, BTreeMap<usize, BTreeSet<Su
     (num_fell, supported_by)
 }
 
-fn part2(input: &str) -> Result<String> {
-    let blocks = parse(input)?;
+pub struct Day22;
 
-    let mut blocks: Vec<(usize, Block)> = blocks.into_iter().enumerate().collect();
+impl Solution for Day22 {
+    type Input<'a> = Vec<Block>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let (_, supported_by) = flatten(&mut blocks);
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
 
-    let mut total = 0;
-    for &b in &blocks {
-        let mut fallen = BTreeSet::from([SupportedBy::Block(b.0)]);
+    fn part1(blocks: &Self::Input<'_>) -> Result<Self::Part1> {
+        let mut blocks: Vec<(usize, Block)> = blocks.iter().copied().enumerate().collect();
 
-        loop {
-            let mut local_total = 0;
-            for (j, supported_by) in &supported_by {
-                if supported_by.is_subset(&fallen) && fallen.insert(SupportedBy::Block(*j)) {
-                    local_total += 1;
-                }
-            }
-            total += local_total;
-            if local_total == 0 {
-                break;
+        let (_, supported_by) = flatten(&mut blocks);
+
+        let mut total = 0;
+        for (i, _) in &blocks {
+            let i_set = BTreeSet::from([SupportedBy::Block(*i)]);
+            if !supported_by.values().any(|s| s == &i_set) {
+                total += 1;
             }
         }
+
+        Ok(total)
     }
 
-    Ok(total.to_string())
-}
+    fn part2(blocks: &Self::Input<'_>) -> Result<Self::Part2> {
+        let mut blocks: Vec<(usize, Block)> = blocks.iter().copied().enumerate().collect();
 
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
+        let (_, supported_by) = flatten(&mut blocks);
+
+        let mut total = 0;
+        for &b in &blocks {
+            let mut fallen = BTreeSet::from([SupportedBy::Block(b.0)]);
+
+            loop {
+                let mut local_total = 0;
+                for (j, supported_by) in &supported_by {
+                    if supported_by.is_subset(&fallen) && fallen.insert(SupportedBy::Block(*j)) {
+                        local_total += 1;
+                    }
+                }
+                total += local_total;
+                if local_total == 0 {
+                    break;
+                }
+            }
+        }
+
+        Ok(total)
+    }
 }
 
 #[cfg(test)]
@@ -185,18 +174,18 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "5";
-        let actual = part1(file_data)?;
+        let file_data = include_str!("../../../data/2023/day22/example_input");
+        let expected = 5;
+        let actual = Day22::part1(&Day22::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "7";
-        let actual = part2(file_data)?;
+        let file_data = include_str!("../../../data/2023/day22/example_input");
+        let expected = 7;
+        let actual = Day22::part2(&Day22::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
@@ -1,14 +1,10 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::Result;
-use thiserror::Error;
+use aoc_common::Solution;
 
 mod parsing;
 
-#[derive(Error, Debug)]
-#[error("unimplemented")]
-struct Unimplemented;
-
 type Pos = (i32, i32);
 
 trait Map {
@@ -18,19 +14,15 @@ trait Map {
     fn get_target_position(&self) -> Pos;
 }
 
-fn part1(input: &str, use_dfs: bool) -> Result<String> {
-    let map = parsing::parse(input)?;
-
-    let longest_path = if use_dfs {
+fn longest_hike<M: Map>(map: &M, use_dfs: bool) -> usize {
+    if use_dfs {
         solve_dfs(map, true)
     } else {
         part1_bfs(map)
-    };
-
-    Ok(longest_path.to_string())
+    }
 }
 
-fn part1_bfs<M: Map>(map: M) -> usize {
+fn part1_bfs<M: Map>(map: &M) -> usize {
     let (start_position, target_position) =
         (map.get_starting_position(), map.get_target_position());
 
@@ -55,15 +47,7 @@ fn part1_bfs<M: Map>(map: M) -> usize {
     longest_path
 }
 
-fn part2(input: &str) -> Result<String> {
-    let map = parsing::parse(input)?;
-
-    let longest_path = solve_dfs(map, false);
-
-    Ok(longest_path.to_string())
-}
-
-fn solve_dfs<M: Map>(map: M, follow_slopes: bool) -> usize {
+fn solve_dfs<M: Map>(map: &M, follow_slopes: bool) -> usize {
     let (start_position, target_position) =
         (map.get_starting_position(), map.get_target_position());
 
@@ -170,19 +154,24 @@ impl DfsHelper {
     }
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("input.txt");
-    let part1_result = match part1(input, true) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part1: {}", part1_result);
-    let part2_result = match part2(input) {
-        Err(ref err) if err.is::<Unimplemented>() => "unimplemented".to_string(),
-        result => result?,
-    };
-    println!("part2: {}", part2_result);
-    Ok(())
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = parsing::Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::parse(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(longest_hike(map, true))
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(solve_dfs(map, false))
+    }
 }
 
 #[cfg(test)]
@@ -191,27 +180,27 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "94";
-        let actual = part1(file_data, true)?;
+        let file_data = include_str!("../../../data/2023/day23/example_input");
+        let expected = 94;
+        let actual = longest_hike(&Day23::parse(file_data)?, true);
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part1_bfs() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "94";
-        let actual = part1(file_data, false)?;
+        let file_data = include_str!("../../../data/2023/day23/example_input");
+        let expected = 94;
+        let actual = longest_hike(&Day23::parse(file_data)?, false);
         assert_eq!(actual, expected);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        let file_data = include_str!("example_input.txt");
-        let expected = "154";
-        let actual = part2(file_data)?;
+        let file_data = include_str!("../../../data/2023/day23/example_input");
+        let expected = 154;
+        let actual = Day23::part2(&Day23::parse(file_data)?)?;
         assert_eq!(actual, expected);
         Ok(())
     }
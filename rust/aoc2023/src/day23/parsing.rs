@@ -1,8 +1,7 @@
-use std::collections::BTreeMap;
+use anyhow::{bail, Context, Result};
+use aoc_common::Grid;
 
-use anyhow::{bail, Result};
-
-use crate::Pos;
+use super::Pos;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum Direction {
@@ -30,18 +29,18 @@ enum Tile {
 }
 
 #[derive(Debug)]
-pub(crate) struct Map {
-    map: BTreeMap<Pos, Tile>,
+pub struct Map {
+    map: Grid<Tile>,
     starting_position: Pos,
     target_position: Pos,
 }
 
-impl crate::Map for Map {
+impl super::Map for Map {
     fn traversable_tiles(&self) -> Vec<Pos> {
         let mut result = vec![];
-        for (p, t) in &self.map {
+        for ((x, y), t) in self.map.iter() {
             if !matches!(t, Tile::Forest) {
-                result.push(*p);
+                result.push((x as i32, y as i32));
             }
         }
         result
@@ -57,7 +56,7 @@ impl crate::Map for Map {
         ] {
             let delta = d.delta();
             let neighbor = (pos.0 + delta.0, pos.1 + delta.1);
-            match self.map.get(&neighbor) {
+            match self.map.get((neighbor.0 as i64, neighbor.1 as i64)) {
                 Some(Tile::Path) => neighbors.push(neighbor),
                 Some(Tile::Slope(slope)) if !follow_slopes || *slope == d => {
                     neighbors.push(neighbor)
@@ -78,31 +77,25 @@ impl crate::Map for Map {
 }
 
 pub(crate) fn parse(input: &str) -> Result<Map> {
-    let mut map = BTreeMap::new();
-    let mut starting_position = (0, 0);
-    let mut target_position = (0, 0);
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let tile = match c {
-                '.' => Tile::Path,
-                '#' => Tile::Forest,
-                '>' => Tile::Slope(Direction::Right),
-                '<' => Tile::Slope(Direction::Left),
-                '^' => Tile::Slope(Direction::Up),
-                'v' => Tile::Slope(Direction::Down),
-                _ => bail!("invalid character"),
-            };
-            if c == '.' {
-                if y == 0 {
-                    starting_position = (x as i32, y as i32);
-                }
-
-                target_position = (x as i32, y as i32);
-            }
-            map.insert((x as i32, y as i32), tile);
-        }
-    }
+    let map = Grid::parse(input, |c| {
+        Ok(match c {
+            '.' => Tile::Path,
+            '#' => Tile::Forest,
+            '>' => Tile::Slope(Direction::Right),
+            '<' => Tile::Slope(Direction::Left),
+            '^' => Tile::Slope(Direction::Up),
+            'v' => Tile::Slope(Direction::Down),
+            _ => bail!("invalid character"),
+        })
+    })?;
+    let path_in_row = |y: usize| {
+        map.row(y)
+            .iter()
+            .position(|t| matches!(t, Tile::Path))
+            .map(|x| (x as i32, y as i32))
+    };
+    let starting_position = path_in_row(0).context("No path on the top row")?;
+    let target_position = path_in_row(map.height() - 1).context("No path on the bottom row")?;
     Ok(Map {
         map,
         starting_position,
@@ -0,0 +1,290 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Solution;
+use itertools::Itertools;
+use num::{BigRational, Signed, ToPrimitive, Zero};
+
+mod parsing;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Pos {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hailstone {
+    pos: Pos,
+    dir: Pos,
+}
+
+impl Hailstone {
+    fn intersect(&self, other: &Hailstone) -> Option<Pos> {
+        let det = other.dir.x * self.dir.y - other.dir.y * self.dir.x;
+        if det == 0.0 {
+            return None;
+        }
+
+        let dx = other.pos.x - self.pos.x;
+        let dy = other.pos.y - self.pos.y;
+
+        let u = (dy * other.dir.x - dx * other.dir.y) / det;
+        let v = (dy * self.dir.x - dx * self.dir.y) / det;
+
+        (u >= 0.0 && v >= 0.0).then_some(Pos {
+            x: self.pos.x + self.dir.x * u,
+            y: self.pos.y + self.dir.y * u,
+            z: self.pos.z + self.dir.z * u,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsedInput {
+    hailstones: Vec<Hailstone>,
+}
+
+fn count_intersections(hailstones: &[Hailstone], r: RangeInclusive<f64>) -> usize {
+    let mut total = 0;
+    for (hs1, hs2) in hailstones.iter().tuple_combinations() {
+        if let Some(pos) = hs1.intersect(hs2) {
+            if r.contains(&pos.x) && r.contains(&pos.y) {
+                total += 1;
+            }
+        }
+    }
+    total
+}
+
+/// Solves the square system whose augmented matrix is `rows` by Gaussian
+/// elimination, or `None` if it's singular.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = &row[col] / &pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *value -= &factor * pivot_value;
+            }
+        }
+    }
+    Some((0..n).map(|r| &rows[r][n] / &rows[r][r]).collect())
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+impl Hailstone {
+    fn pos_i128(&self) -> [i128; 3] {
+        [self.pos.x as i128, self.pos.y as i128, self.pos.z as i128]
+    }
+
+    fn dir_i128(&self) -> [i128; 3] {
+        [self.dir.x as i128, self.dir.y as i128, self.dir.z as i128]
+    }
+}
+
+/// The three linear equations in the rock's position `P` and velocity `V`
+/// that come from it hitting both hailstones `a` and `b`.
+///
+/// Hitting hailstone `i` means `P + t V = p_i + t v_i` for some `t`, so
+/// `P - p_i` and `V - v_i` are parallel: `(P - p_i) × (V - v_i) = 0`.
+/// Expanding that, the only non-linear term is `P × V`, which is the same
+/// for every hailstone, so subtracting the equations for `a` and `b` leaves
+///
+/// ```text
+/// P × (v_b - v_a) + (p_b - p_a) × V = p_b × v_b - p_a × v_a
+/// ```
+///
+/// Each row is the coefficients of `Px, Py, Pz, Vx, Vy, Vz` and then the
+/// right-hand side.
+fn rock_equations(a: &Hailstone, b: &Hailstone) -> [[i128; 7]; 3] {
+    let [wx, wy, wz] = sub(b.dir_i128(), a.dir_i128());
+    let [dx, dy, dz] = sub(b.pos_i128(), a.pos_i128());
+    let rhs = sub(
+        cross(b.pos_i128(), b.dir_i128()),
+        cross(a.pos_i128(), a.dir_i128()),
+    );
+    [
+        [0, wz, -wy, 0, -dz, dy, rhs[0]],
+        [-wz, 0, wx, dz, 0, -dx, rhs[1]],
+        [wy, -wx, 0, -dy, dx, 0, rhs[2]],
+    ]
+}
+
+/// When a rock thrown from `pos` with velocity `dir` hits `hailstone`, if it
+/// ever does: the `t` with `pos + t dir = p + t v` in every coordinate.
+fn hit_time(
+    pos: &[BigRational; 3],
+    dir: &[BigRational; 3],
+    hailstone: &Hailstone,
+) -> Option<BigRational> {
+    let mut time = None;
+    for ((rock_p, rock_v), (p, v)) in pos
+        .iter()
+        .zip(dir)
+        .zip(hailstone.pos_i128().into_iter().zip(hailstone.dir_i128()))
+    {
+        let gap = BigRational::from_integer(p.into()) - rock_p;
+        let closing = rock_v - BigRational::from_integer(v.into());
+        if closing.is_zero() {
+            // Moving together, so they have to be level the whole time.
+            if !gap.is_zero() {
+                return None;
+            }
+        } else {
+            let t = gap / closing;
+            if time.as_ref().is_some_and(|time| *time != t) {
+                return None;
+            }
+            time = Some(t);
+        }
+    }
+    Some(time.unwrap_or_else(BigRational::zero))
+}
+
+/// Where to stand and which way to throw a rock so that it hits every
+/// hailstone: `(position, velocity)`.
+///
+/// Any three hailstones pin the rock down (two pairs give six equations in
+/// six unknowns), as long as their velocities are different enough for the
+/// system to be non-singular; try triples until one is.  That rock then has
+/// to hit all the others too, each at a whole, non-negative time.
+fn throw_rock(hailstones: &[Hailstone]) -> Result<([BigRational; 3], [BigRational; 3])> {
+    for (a, b, c) in hailstones.iter().tuple_combinations() {
+        let rows = rock_equations(a, b)
+            .into_iter()
+            .chain(rock_equations(a, c))
+            .map(|row| {
+                row.iter()
+                    .map(|&n| BigRational::from_integer(n.into()))
+                    .collect()
+            })
+            .collect();
+        if let Some(solution) = solve_linear(rows) {
+            let [px, py, pz, vx, vy, vz] = solution.try_into().unwrap();
+            let (pos, dir) = ([px, py, pz], [vx, vy, vz]);
+            for (i, hailstone) in hailstones.iter().enumerate() {
+                match hit_time(&pos, &dir, hailstone) {
+                    Some(t) if t.is_integer() && !t.is_negative() => {}
+                    Some(t) => bail!("The rock hits hailstone {} at time {t}", i + 1),
+                    None => bail!(
+                        "No rock hits every hailstone: hailstone {} is missed",
+                        i + 1
+                    ),
+                }
+            }
+            return Ok((pos, dir));
+        }
+    }
+    bail!("No three hailstones determine the rock's path")
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = ParsedInput;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        // The example uses a much smaller test area than the real input.
+        let is_example = input.hailstones.iter().all(|h| h.pos.x.abs() < 1000.0);
+        let test_area = if is_example {
+            7.0..=27.0
+        } else {
+            200000000000000.0..=400000000000000.0
+        };
+        Ok(count_intersections(&input.hailstones, test_area))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        let ([px, py, pz], _) = throw_rock(&input.hailstones)?;
+        let total = px + py + pz;
+        if !total.is_integer() {
+            bail!("The rock doesn't start at whole-number coordinates: {total}");
+        }
+        total
+            .to_integer()
+            .to_i64()
+            .context("The answer doesn't fit in an i64")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day24/example_input");
+        let expected = 2;
+        let input = Day24::parse(file_data)?;
+        let actual = count_intersections(&input.hailstones, 7.0..=27.0);
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_throw_rock() -> Result<()> {
+        let input = parsing::parse(include_str!("../../../data/2023/day24/example_input"))?;
+        let (pos, dir) = throw_rock(&input.hailstones)?;
+        let integers = |v: [BigRational; 3]| v.map(|n| n.to_integer().to_i64());
+        assert_eq!(integers(pos), [24, 13, 10].map(Some));
+        assert_eq!(integers(dir), [-3, 1, 2].map(Some));
+        Ok(())
+    }
+
+    #[test]
+    fn test_throw_rock_misses() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day24/example_input");
+        // Nudge the last hailstone off the rock's path.
+        let mut input = parsing::parse(file_data)?;
+        input.hailstones.last_mut().unwrap().pos.x += 1.0;
+        assert!(throw_rock(&input.hailstones).is_err());
+        // Or leave it where the rock was a nanosecond before it was thrown.
+        let mut input = parsing::parse(file_data)?;
+        let hailstone = input.hailstones.last_mut().unwrap();
+        hailstone.pos = Pos {
+            x: 27.0,
+            y: 12.0,
+            z: 8.0,
+        };
+        hailstone.dir = Pos {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        assert!(throw_rock(&input.hailstones).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day24/example_input");
+        let actual = Day24::part2(&Day24::parse(file_data)?)?;
+        assert_eq!(actual, 47);
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use nom::{
     IResult,
 };
 
-use crate::{Hailstone, ParsedInput, Pos};
+use super::{Hailstone, ParsedInput, Pos};
 
 fn parse_pos(input: &str) -> IResult<&str, Pos> {
     let (input, (x, y, z)) = tuple((
@@ -33,7 +33,7 @@ fn parse_hailstone(input: &str) -> IResult<&str, Hailstone> {
     Ok((input, Hailstone { pos, dir }))
 }
 
-pub(crate) fn parse(input: &'static str) -> Result<ParsedInput> {
-    let (_, hailstones) = all_consuming(separated_list1(line_ending, parse_hailstone))(input)?;
+pub(crate) fn parse(input: &str) -> Result<ParsedInput> {
+    let (_, hailstones) = all_consuming(separated_list1(line_ending, parse_hailstone))(input).map_err(|e| e.to_owned())?;
     Ok(ParsedInput { hailstones })
 }
@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use rustworkx_core::{connectivity::stoer_wagner_min_cut, petgraph::graph::UnGraph};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter::once,
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{Solution, Unimplemented};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = BTreeMap<&'a str, Vec<&'a str>>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (n, components) = l.split_once(": ").context("missing colon")?;
+                let components = components.split(' ').collect();
+                Ok((n, components))
+            })
+            .collect()
+    }
+
+    fn part1(components: &Self::Input<'_>) -> Result<Self::Part1> {
+        let name_to_id: BTreeMap<&str, u32> = components
+            .iter()
+            .flat_map(|c| once(*c.0).chain(c.1.iter().copied()))
+            .unique()
+            .enumerate()
+            .map(|(i, n)| (n, i as u32))
+            .collect();
+
+        let edges: BTreeSet<(u32, u32)> = components
+            .iter()
+            .flat_map(|(k, v)| v.iter().map(move |v| (k, v)))
+            .map(|(k, v)| {
+                let (k, v) = (name_to_id[k], name_to_id[v]);
+                (k.min(v), v.max(k))
+            })
+            .collect();
+
+        let graph: UnGraph<(), ()> = UnGraph::from_edges(edges);
+
+        let min_cut_res: Result<_> = stoer_wagner_min_cut(&graph, |_| Ok(1));
+        let min_cut = min_cut_res?.context("no min cut found")?;
+
+        let result = (name_to_id.len() - min_cut.1.len()) * min_cut.1.len();
+
+        Ok(result)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Self::Part2> {
+        bail!(Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/2023/day25/example_input");
+        let expected = 54;
+        let actual = Day25::part1(&Day25::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::{bail, Result};
+use aoc_common::{Solution, Unimplemented};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = PART1_TYPE;
+    type Part2 = PART2_TYPE;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1> {
+        let _ = input;
+        bail!(Unimplemented)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2> {
+        let _ = input;
+        bail!(Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let file_data = include_str!("../../../data/YYYY/dayNN/example_input");
+        let expected = PART1;
+        let actual = DayNN::part1(&DayNN::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let file_data = include_str!("../../../data/YYYY/dayNN/example_input");
+        let expected = PART2;
+        let actual = DayNN::part2(&DayNN::parse(file_data)?)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}